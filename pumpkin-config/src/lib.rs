@@ -16,6 +16,8 @@ pub mod resource_pack;
 
 pub use chat::ChatConfig;
 pub use commands::CommandsConfig;
+pub use mob::MobConfig;
+pub use movement::MovementConfig;
 pub use networking::auth::AuthenticationConfig;
 pub use networking::compression::CompressionConfig;
 pub use networking::lan_broadcast::LANBroadcastConfig;
@@ -27,6 +29,8 @@ mod commands;
 
 mod chat;
 pub mod chunk;
+mod mob;
+mod movement;
 pub mod op;
 mod player_data;
 mod pvp;
@@ -95,6 +99,8 @@ pub struct AdvancedConfiguration {
     pub commands: CommandsConfig,
     pub chat: ChatConfig,
     pub pvp: PVPConfig,
+    pub movement: MovementConfig,
+    pub mob: MobConfig,
     pub server_links: ServerLinksConfig,
     pub player_data: PlayerDataConfig,
     pub fun: FunConfig,
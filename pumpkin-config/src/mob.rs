@@ -0,0 +1,26 @@
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct MobConfig {
+    /// Mobs only reevaluate their AI goals every this many ticks, staggered by entity id so the
+    /// work spreads evenly across ticks. Running goals still tick every tick when they need to.
+    /// Vanilla uses 2; higher values reduce AI load with many mobs.
+    pub goal_tick_interval: NonZeroU32,
+    /// Mobs further than this many blocks away from every player skip their AI, i.e. goals,
+    /// navigation and looking around, like Spigot's entity activation range. They still move,
+    /// burn and despawn, and mobs with a target keep chasing it. Keep it above the follow range
+    /// of hostile mobs, or they don't notice players at its edge. 0 keeps every mob active.
+    pub activation_range: u32,
+}
+
+impl Default for MobConfig {
+    fn default() -> Self {
+        Self {
+            goal_tick_interval: NonZeroU32::new(2).unwrap(),
+            activation_range: 48,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct MovementConfig {
+    /// Whether the server checks the movement of players instead of trusting the client.
+    /// Players moving too fast or through blocks are teleported back.
+    pub server_authoritative: bool,
+    /// The maximum distance in blocks a player may move in one tick, on top of their velocity.
+    pub max_movement_per_tick: f64,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            server_authoritative: false,
+            max_movement_per_tick: 10.0,
+        }
+    }
+}
@@ -8,6 +8,18 @@ use std::fs;
 struct Attributes {
     id: u8,
     default_value: f64,
+    #[serde(default = "min_value")]
+    min_value: f64,
+    #[serde(default = "max_value")]
+    max_value: f64,
+}
+
+const fn min_value() -> f64 {
+    f64::MIN
+}
+
+const fn max_value() -> f64 {
+    f64::MAX
 }
 
 pub(crate) fn build() -> TokenStream {
@@ -18,6 +30,8 @@ pub(crate) fn build() -> TokenStream {
             .expect("Failed to parse attributes.json");
 
     let mut consts = TokenStream::new();
+    let mut name_to_type = TokenStream::new();
+    let mut all = TokenStream::new();
 
     let mut data_component_vec = attributes.iter().collect::<Vec<_>>();
     data_component_vec.sort_by_key(|(_, i)| i.id);
@@ -27,20 +41,32 @@ pub(crate) fn build() -> TokenStream {
 
         let id = raw_value.id;
         let default_value = raw_value.default_value;
+        let min_value = raw_value.min_value;
+        let max_value = raw_value.max_value;
+        let minecraft_name = format!("minecraft:{raw_name}");
         consts.extend(quote! {
             pub const #pascal_case: Self = Self {
+                minecraft_name: #minecraft_name,
                 id: #id,
                 default_value: #default_value,
+                min_value: #min_value,
+                max_value: #max_value,
             };
         });
+
+        name_to_type.extend(quote! { #minecraft_name => Some(&Self::#pascal_case), });
+        all.extend(quote! { &Self::#pascal_case, });
     }
 
     quote! {
         use std::hash::Hash;
         #[derive(Clone, Debug)]
         pub struct Attributes {
+            pub minecraft_name: &'static str,
             pub id: u8,
             pub default_value: f64,
+            min_value: f64,
+            max_value: f64,
         }
         impl PartialEq for Attributes {
             fn eq(&self, other: &Self) -> bool {
@@ -55,6 +81,34 @@ pub(crate) fn build() -> TokenStream {
         }
         impl Attributes {
             #consts
+
+            /// The smallest value this attribute can have, like vanilla's `ClampedEntityAttribute`.
+            pub const fn get_min(&self) -> f64 {
+                self.min_value
+            }
+
+            /// The largest value this attribute can have, like vanilla's `ClampedEntityAttribute`.
+            pub const fn get_max(&self) -> f64 {
+                self.max_value
+            }
+
+            /// Restricts the value to this attribute's range.
+            pub const fn clamp(&self, value: f64) -> f64 {
+                value.clamp(self.min_value, self.max_value)
+            }
+
+            pub const fn all() -> &'static [&'static Self] {
+                &[
+                    #all
+                ]
+            }
+
+            pub fn from_minecraft_name(name: &str) -> Option<&'static Self> {
+                match name {
+                    #name_to_type
+                    _ => None
+                }
+            }
         }
     }
 }
@@ -913,6 +913,7 @@ pub(crate) fn build() -> TokenStream {
         use pumpkin_util::math::experience::Experience;
         use pumpkin_util::math::vector3::Vector3;
         use std::collections::BTreeMap;
+        use std::sync::LazyLock;
         use phf;
 
 
@@ -977,6 +978,16 @@ pub(crate) fn build() -> TokenStream {
             false
         }
 
+        // Both lookups of `BlockState::from_id_with_block` in one, it is called for every block an entity collides with
+        static BLOCK_AND_STATE_FROM_STATE_ID: LazyLock<Box<[(&'static Block, &'static BlockState)]>> =
+            LazyLock::new(|| {
+                Block::STATE_FROM_STATE_ID
+                    .iter()
+                    .enumerate()
+                    .map(|(id, state)| (Block::from_state_id(id as u16), *state))
+                    .collect()
+            });
+
         impl BlockState {
             const STATE_ID_TO_BEDROCK: &[u16] = &[
                 #block_state_to_bedrock_t
@@ -992,9 +1003,7 @@ pub(crate) fn build() -> TokenStream {
             #[doc = r" Get a block state from a state id and the corresponding block."]
             #[inline]
             pub fn from_id_with_block(id: u16) -> (&'static Block, &'static Self) {
-                let block = Block::from_state_id(id);
-                let state: &Self = Block::STATE_FROM_STATE_ID[id as usize];
-                (block, state)
+                BLOCK_AND_STATE_FROM_STATE_ID[id as usize]
             }
 
             pub fn to_be_network_id(id: u16) -> u16 {
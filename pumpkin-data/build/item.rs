@@ -230,13 +230,129 @@ impl ToTokens for ItemComponents {
                 Span::call_site(),
             );
 
+            let on_consume_effects =
+                consumable
+                    .on_consume_effects
+                    .iter()
+                    .map(|effect| match effect {
+                        ConsumeEffect::ApplyEffects {
+                            effects,
+                            probability,
+                        } => {
+                            let effects = effects.iter().map(|effect| {
+                                let id = LitStr::new(
+                                    effect.id.strip_prefix("minecraft:").unwrap_or(&effect.id),
+                                    Span::call_site(),
+                                );
+                                let amplifier =
+                                    LitInt::new(&effect.amplifier.to_string(), Span::call_site());
+                                let duration =
+                                    LitInt::new(&effect.duration.to_string(), Span::call_site());
+                                let ambient = LitBool::new(effect.ambient, Span::call_site());
+                                let show_particles =
+                                    LitBool::new(effect.show_particles, Span::call_site());
+                                let show_icon = LitBool::new(
+                                    effect.show_icon.unwrap_or(effect.show_particles),
+                                    Span::call_site(),
+                                );
+                                quote! {
+                                    ConsumeStatusEffect {
+                                        id: #id,
+                                        amplifier: #amplifier,
+                                        duration: #duration,
+                                        ambient: #ambient,
+                                        show_particles: #show_particles,
+                                        show_icon: #show_icon,
+                                    }
+                                }
+                            });
+                            let probability =
+                                LitFloat::new(&format!("{probability:.1}"), Span::call_site());
+                            quote! {
+                                ConsumeEffect::ApplyEffects {
+                                    effects: &[#(#effects),*],
+                                    probability: #probability,
+                                }
+                            }
+                        }
+                        ConsumeEffect::RemoveEffects { effects } => {
+                            let effects = effects.get_values().into_iter().map(|effect| {
+                                let TagType::Item(name) = effect else {
+                                    panic!("Effect tags are not supported in remove_effects");
+                                };
+                                let name = LitStr::new(
+                                    name.strip_prefix("minecraft:").unwrap_or(&name),
+                                    Span::call_site(),
+                                );
+                                quote! { #name }
+                            });
+                            quote! { ConsumeEffect::RemoveEffects(&[#(#effects),*]) }
+                        }
+                        ConsumeEffect::ClearAllEffects => quote! { ConsumeEffect::ClearAllEffects },
+                        ConsumeEffect::TeleportRandomly { diameter } => {
+                            let diameter =
+                                LitFloat::new(&format!("{diameter:.1}"), Span::call_site());
+                            quote! { ConsumeEffect::TeleportRandomly { diameter: #diameter } }
+                        }
+                        ConsumeEffect::PlaySound { sound } => {
+                            let sound = LitStr::new(sound, Span::call_site());
+                            quote! { ConsumeEffect::PlaySound(#sound) }
+                        }
+                    });
+
             tokens.extend(quote! { (Consumable, &ConsumableImpl {
                 consume_seconds: #consume_seconds,
+                on_consume_effects: &[#(#on_consume_effects),*],
             }), });
         };
 
-        if self.blocks_attacks.is_some() {
-            tokens.extend(quote! { (BlocksAttacks, &BlocksAttacksImpl), });
+        if let Some(blocks_attacks) = &self.blocks_attacks {
+            let block_delay_seconds = LitFloat::new(
+                &format!("{:.2}", blocks_attacks.block_delay_seconds),
+                Span::call_site(),
+            );
+            let disable_cooldown_scale = LitFloat::new(
+                &format!("{:.2}", blocks_attacks.disable_cooldown_scale),
+                Span::call_site(),
+            );
+            // TODO: Support multiple damage reductions and their damage type filters
+            let reduction = blocks_attacks
+                .damage_reductions
+                .as_ref()
+                .and_then(|reductions| reductions.first())
+                .cloned()
+                .unwrap_or_default();
+            let horizontal_blocking_angle = LitFloat::new(
+                &format!("{:.2}", reduction.horizontal_blocking_angle),
+                Span::call_site(),
+            );
+            let damage_reduction_base =
+                LitFloat::new(&format!("{:.2}", reduction.base), Span::call_site());
+            let damage_reduction_factor =
+                LitFloat::new(&format!("{:.2}", reduction.factor), Span::call_site());
+            let optional_str = |value: &Option<String>| {
+                value
+                    .as_ref()
+                    .map(|s| {
+                        let s = LitStr::new(s, Span::call_site());
+                        quote! { Some(#s) }
+                    })
+                    .unwrap_or(quote! { None })
+            };
+            let bypassed_by = optional_str(&blocks_attacks.bypassed_by);
+            let block_sound = optional_str(&blocks_attacks.block_sound);
+            let disabled_sound = optional_str(&blocks_attacks.disabled_sound);
+
+            tokens.extend(quote! { (BlocksAttacks, &BlocksAttacksImpl {
+                block_delay_seconds: #block_delay_seconds,
+                disable_cooldown_scale: #disable_cooldown_scale,
+                horizontal_blocking_angle: #horizontal_blocking_angle,
+                damage_reduction_base: #damage_reduction_base,
+                damage_reduction_factor: #damage_reduction_factor,
+                bypassed_by: #bypassed_by,
+                block_sound: #block_sound,
+                disabled_sound: #disabled_sound,
+            }), });
         };
 
         if self.death_protection.is_some() {
@@ -401,6 +517,48 @@ fn _true() -> bool {
 #[derive(Deserialize, Clone, Debug)]
 pub struct Consumable {
     consume_seconds: Option<f32>, // TODO
+    #[serde(default)]
+    on_consume_effects: Vec<ConsumeEffect>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum ConsumeEffect {
+    #[serde(rename = "minecraft:apply_effects")]
+    ApplyEffects {
+        effects: Vec<ConsumeStatusEffect>,
+        #[serde(default = "_one")]
+        probability: f32,
+    },
+    #[serde(rename = "minecraft:remove_effects")]
+    RemoveEffects { effects: RegistryEntryList },
+    #[serde(rename = "minecraft:clear_all_effects")]
+    ClearAllEffects,
+    #[serde(rename = "minecraft:teleport_randomly")]
+    TeleportRandomly {
+        #[serde(default = "_sixteen")]
+        diameter: f32,
+    },
+    #[serde(rename = "minecraft:play_sound")]
+    PlaySound { sound: String },
+}
+
+fn _sixteen() -> f32 {
+    16.0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ConsumeStatusEffect {
+    id: String,
+    #[serde(default)]
+    amplifier: u8,
+    #[serde(default)]
+    duration: i32,
+    #[serde(default)]
+    ambient: bool,
+    #[serde(default = "_true")]
+    show_particles: bool,
+    show_icon: Option<bool>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -408,9 +566,43 @@ pub struct DeathProtection {
     // TODO
 }
 
+fn _one() -> f32 {
+    1.0
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct BlocksAttacks {
-    // TODO
+    #[serde(default)]
+    block_delay_seconds: f32,
+    #[serde(default = "_one")]
+    disable_cooldown_scale: f32,
+    damage_reductions: Option<Vec<DamageReduction>>,
+    bypassed_by: Option<String>,
+    block_sound: Option<String>,
+    disabled_sound: Option<String>,
+}
+
+fn _default_blocking_angle() -> f32 {
+    90.0
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DamageReduction {
+    #[serde(default = "_default_blocking_angle")]
+    horizontal_blocking_angle: f32,
+    base: f32,
+    factor: f32,
+}
+
+impl Default for DamageReduction {
+    /// Fully blocks damage coming from the front
+    fn default() -> Self {
+        Self {
+            horizontal_blocking_angle: _default_blocking_angle(),
+            base: 0.0,
+            factor: 1.0,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -78,6 +78,12 @@ impl BlockState {
         self.state_flags & IS_SOLID_BLOCK != 0
     }
 
+    /// Whether an entity with its eyes inside the block suffocates, `shouldSuffocate()` in Java.
+    /// Transparent full blocks like glass, ice or leaves don't, soul sand does.
+    pub const fn suffocates(&self) -> bool {
+        self.is_solid_block()
+    }
+
     pub const fn has_random_ticks(&self) -> bool {
         self.state_flags & HAS_RANDOM_TICKS != 0
     }
@@ -43,6 +43,7 @@ pub fn read_data(id: DataComponent, data: &NbtTag) -> Option<Box<dyn DataCompone
         MaxStackSize => Some(MaxStackSizeImpl::read_data(data)?.to_dyn()),
         Enchantments => Some(EnchantmentsImpl::read_data(data)?.to_dyn()),
         Damage => Some(DamageImpl::read_data(data)?.to_dyn()),
+        BucketEntityData => Some(BucketEntityDataImpl::read_data(data)?.to_dyn()),
         _ => todo!(),
     }
 }
@@ -207,6 +208,77 @@ fn get_i32_hash(val: i32) -> u32 {
     digest.finalize() as u32
 }
 
+fn get_nbt_hash(val: &NbtTag) -> u32 {
+    let mut digest = Digest::new(Crc32Iscsi);
+    match val {
+        NbtTag::End => digest.update(&[1u8]),
+        NbtTag::Byte(byte) => {
+            digest.update(&[6u8]);
+            digest.update(&byte.to_le_bytes());
+        }
+        NbtTag::Short(short) => {
+            digest.update(&[7u8]);
+            digest.update(&short.to_le_bytes());
+        }
+        NbtTag::Int(int) => return get_i32_hash(*int),
+        NbtTag::Long(long) => {
+            digest.update(&[9u8]);
+            digest.update(&long.to_le_bytes());
+        }
+        NbtTag::Float(float) => {
+            digest.update(&[10u8]);
+            digest.update(&float.to_le_bytes());
+        }
+        NbtTag::Double(double) => {
+            digest.update(&[11u8]);
+            digest.update(&double.to_le_bytes());
+        }
+        NbtTag::String(string) => return get_str_hash(string),
+        NbtTag::ByteArray(bytes) => {
+            digest.update(&[14u8]);
+            digest.update(bytes);
+            digest.update(&[15u8]);
+        }
+        NbtTag::IntArray(ints) => {
+            digest.update(&[16u8]);
+            for int in ints {
+                digest.update(&int.to_le_bytes());
+            }
+            digest.update(&[17u8]);
+        }
+        NbtTag::LongArray(longs) => {
+            digest.update(&[18u8]);
+            for long in longs {
+                digest.update(&long.to_le_bytes());
+            }
+            digest.update(&[19u8]);
+        }
+        NbtTag::List(list) => {
+            digest.update(&[4u8]);
+            for tag in list {
+                digest.update(&get_nbt_hash(tag).to_le_bytes());
+            }
+            digest.update(&[5u8]);
+        }
+        NbtTag::Compound(compound) => {
+            // Entries are hashed in a stable order, sorted by their hashes
+            let mut entries: Vec<_> = compound
+                .child_tags
+                .iter()
+                .map(|(key, tag)| (get_str_hash(key), get_nbt_hash(tag)))
+                .collect();
+            entries.sort_unstable();
+            digest.update(&[2u8]);
+            for (key, tag) in entries {
+                digest.update(&key.to_le_bytes());
+                digest.update(&tag.to_le_bytes());
+            }
+            digest.update(&[3u8]);
+        }
+    }
+    digest.finalize() as u32
+}
+
 #[test]
 fn test_hash() {
     assert_eq!(get_str_hash("minecraft:sharpness"), 2734053906u32);
@@ -307,10 +379,44 @@ impl Hash for FoodImpl {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConsumableImpl {
     pub consume_seconds: f32,
+    /// Applied in order once the item has been consumed
+    pub on_consume_effects: &'static [ConsumeEffect],
     // TODO: more
 }
 
+/// An effect of consuming an item, like vanilla's `ConsumeEffect`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConsumeEffect {
+    /// Applies all the effects with the given probability
+    ApplyEffects {
+        effects: &'static [ConsumeStatusEffect],
+        probability: f32,
+    },
+    /// Removes the status effects with these names, e.g. `poison`
+    RemoveEffects(&'static [&'static str]),
+    ClearAllEffects,
+    /// Teleports to a random safe spot within `diameter` blocks
+    TeleportRandomly {
+        diameter: f32,
+    },
+    PlaySound(&'static str),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsumeStatusEffect {
+    /// The status effect name, e.g. `regeneration`
+    pub id: &'static str,
+    pub amplifier: u8,
+    pub duration: i32,
+    pub ambient: bool,
+    pub show_particles: bool,
+    pub show_icon: bool,
+}
+
 impl ConsumableImpl {
+    /// Used when an item doesn't specify how long it takes to consume it
+    pub const DEFAULT_CONSUME_SECONDS: f32 = 1.6;
+
     pub fn consume_ticks(&self) -> i32 {
         (self.consume_seconds * 20.0) as i32
     }
@@ -580,12 +686,54 @@ pub struct DeathProtectionImpl;
 impl DataComponentImpl for DeathProtectionImpl {
     default_impl!(DeathProtection);
 }
-#[derive(Clone, Debug, Hash, PartialEq)]
-pub struct BlocksAttacksImpl;
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlocksAttacksImpl {
+    /// How long the item has to be used before it starts blocking
+    pub block_delay_seconds: f32,
+    /// Multiplier for how long the item is disabled when hit by an attack that disables blocking
+    pub disable_cooldown_scale: f32,
+    /// Attacks are only blocked if they come from within this angle (in degrees) of the look direction
+    pub horizontal_blocking_angle: f32,
+    pub damage_reduction_base: f32,
+    pub damage_reduction_factor: f32,
+    /// Damage type tag of attacks that can't be blocked
+    pub bypassed_by: Option<&'static str>,
+    pub block_sound: Option<&'static str>,
+    pub disabled_sound: Option<&'static str>,
+}
+
+impl BlocksAttacksImpl {
+    pub fn block_delay_ticks(&self) -> i32 {
+        (self.block_delay_seconds * 20.0) as i32
+    }
+
+    /// Returns how much of `damage` is blocked for an attack coming from `angle` radians
+    /// away from the look direction.
+    pub fn resolve_blocked_damage(&self, damage: f32, angle: f64) -> f32 {
+        if angle > f64::from(self.horizontal_blocking_angle).to_radians() {
+            return 0.0;
+        }
+        self.damage_reduction_factor
+            .mul_add(damage, self.damage_reduction_base)
+            .clamp(0.0, damage)
+    }
+}
 
 impl DataComponentImpl for BlocksAttacksImpl {
     default_impl!(BlocksAttacks);
 }
+impl Hash for BlocksAttacksImpl {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.block_delay_seconds.to_bits().hash(state);
+        self.disable_cooldown_scale.to_bits().hash(state);
+        self.horizontal_blocking_angle.to_bits().hash(state);
+        self.damage_reduction_base.to_bits().hash(state);
+        self.damage_reduction_factor.to_bits().hash(state);
+        self.bypassed_by.hash(state);
+        self.block_sound.hash(state);
+        self.disabled_sound.hash(state);
+    }
+}
 #[derive(Clone, Debug, Hash, PartialEq)]
 pub struct StoredEnchantmentsImpl;
 #[derive(Clone, Debug, Hash, PartialEq)]
@@ -618,8 +766,25 @@ pub struct TrimImpl;
 pub struct DebugStickStateImpl;
 #[derive(Clone, Debug, Hash, PartialEq)]
 pub struct EntityDataImpl;
-#[derive(Clone, Debug, Hash, PartialEq)]
-pub struct BucketEntityDataImpl;
+/// The data of a mob caught in a bucket
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketEntityDataImpl {
+    pub nbt: NbtCompound,
+}
+impl BucketEntityDataImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        data.extract_compound().map(|nbt| Self { nbt: nbt.clone() })
+    }
+}
+impl DataComponentImpl for BucketEntityDataImpl {
+    fn write_data(&self) -> NbtTag {
+        NbtTag::Compound(self.nbt.clone())
+    }
+    fn get_hash(&self) -> i32 {
+        get_nbt_hash(&NbtTag::Compound(self.nbt.clone())) as i32
+    }
+    default_impl!(BucketEntityData);
+}
 #[derive(Clone, Debug, Hash, PartialEq)]
 pub struct BlockEntityDataImpl;
 #[derive(Clone, Debug, Hash, PartialEq)]
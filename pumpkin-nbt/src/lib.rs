@@ -16,10 +16,12 @@ pub mod compound;
 pub mod deserializer;
 pub mod nbt_compress;
 pub mod serializer;
+pub mod snbt;
 pub mod tag;
 
 pub use deserializer::{from_bytes, from_bytes_unnamed};
 pub use serializer::{to_bytes, to_bytes_named, to_bytes_unnamed};
+pub use snbt::from_snbt;
 
 // This NBT crate is inspired from CrabNBT
 
@@ -55,6 +57,8 @@ pub enum Error {
     NegativeLength(i32),
     #[error("Length too large: {0}")]
     LargeLength(usize),
+    #[error("Invalid SNBT at position {0}: {1}")]
+    InvalidSnbt(usize, String),
 }
 
 impl ser::Error for Error {
@@ -0,0 +1,298 @@
+//! Parser for stringified NBT (SNBT), the text format used by commands like `/summon` and `/data`.
+
+use crate::{Error, compound::NbtCompound, tag::NbtTag};
+
+/// Parses an SNBT compound like `{Health: 20.0f, Tags: ["a", "b"]}`.
+///
+/// The whole input must be consumed; trailing characters are a syntax error.
+pub fn from_snbt(input: &str) -> Result<NbtCompound, Error> {
+    let mut reader = SnbtReader { input, cursor: 0 };
+    reader.skip_whitespace();
+    let compound = reader.read_compound()?;
+    reader.skip_whitespace();
+    if reader.peek().is_some() {
+        return Err(reader.error("Unexpected trailing data"));
+    }
+    Ok(compound)
+}
+
+struct SnbtReader<'a> {
+    input: &'a str,
+    cursor: usize,
+}
+
+impl SnbtReader<'_> {
+    fn error(&self, message: &str) -> Error {
+        Error::InvalidSnbt(self.cursor, message.to_string())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.cursor..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.peek()?;
+        self.cursor += char.len_utf8();
+        Some(char)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.next() == Some(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{expected}'")))
+        }
+    }
+
+    /// Consumes a `,` separator, returning whether another element follows.
+    fn has_element_separator(&mut self, end: char) -> Result<bool, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(',') => {
+                self.next();
+                Ok(true)
+            }
+            Some(char) if char == end => Ok(false),
+            _ => Err(self.error(&format!("Expected ',' or '{end}'"))),
+        }
+    }
+
+    fn read_compound(&mut self) -> Result<NbtCompound, Error> {
+        self.expect('{')?;
+        let mut compound = NbtCompound::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.next();
+            return Ok(compound);
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.read_string()?;
+            if key.is_empty() {
+                return Err(self.error("Expected key"));
+            }
+            self.expect(':')?;
+            let value = self.read_value()?;
+            compound.put(&key, value);
+            if !self.has_element_separator('}')? {
+                break;
+            }
+        }
+        self.expect('}')?;
+        Ok(compound)
+    }
+
+    fn read_value(&mut self) -> Result<NbtTag, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => Ok(NbtTag::Compound(self.read_compound()?)),
+            Some('[') => self.read_list_or_array(),
+            Some('"' | '\'') => Ok(NbtTag::String(self.read_quoted_string()?)),
+            Some(_) => {
+                let token = self.read_unquoted_string();
+                if token.is_empty() {
+                    return Err(self.error("Expected value"));
+                }
+                Ok(parse_unquoted(token))
+            }
+            None => Err(self.error("Expected value")),
+        }
+    }
+
+    fn read_list_or_array(&mut self) -> Result<NbtTag, Error> {
+        self.expect('[')?;
+        let rest = &self.input[self.cursor..];
+        let array_type = match rest.as_bytes() {
+            [prefix @ (b'B' | b'I' | b'L'), b';', ..] => Some(*prefix),
+            _ => None,
+        };
+        if let Some(array_type) = array_type {
+            self.cursor += 2;
+            let elements = self.read_elements()?;
+            let mismatch = || self.error("Array element has the wrong type");
+            return match array_type {
+                b'B' => elements
+                    .into_iter()
+                    .map(|tag| match tag {
+                        NbtTag::Byte(value) => Ok(value as u8),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|bytes| NbtTag::ByteArray(bytes.into_boxed_slice())),
+                b'I' => elements
+                    .into_iter()
+                    .map(|tag| match tag {
+                        NbtTag::Int(value) => Ok(value),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(NbtTag::IntArray),
+                _ => elements
+                    .into_iter()
+                    .map(|tag| match tag {
+                        NbtTag::Long(value) => Ok(value),
+                        _ => Err(mismatch()),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(NbtTag::LongArray),
+            };
+        }
+
+        let elements = self.read_elements()?;
+        if let Some(first) = elements.first() {
+            let type_id = first.get_type_id();
+            if elements.iter().any(|tag| tag.get_type_id() != type_id) {
+                return Err(self.error("List elements must all have the same type"));
+            }
+        }
+        Ok(NbtTag::List(elements))
+    }
+
+    /// Reads comma separated values up to and including the closing `]`.
+    fn read_elements(&mut self) -> Result<Vec<NbtTag>, Error> {
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.next();
+            return Ok(elements);
+        }
+        loop {
+            elements.push(self.read_value()?);
+            if !self.has_element_separator(']')? {
+                break;
+            }
+        }
+        self.expect(']')?;
+        Ok(elements)
+    }
+
+    fn read_string(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some('"' | '\'') => self.read_quoted_string(),
+            _ => Ok(self.read_unquoted_string().to_string()),
+        }
+    }
+
+    fn read_quoted_string(&mut self) -> Result<String, Error> {
+        let Some(quote) = self.next() else {
+            return Err(self.error("Expected quote"));
+        };
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('\\') => match self.next() {
+                    Some(escaped @ ('\\' | '"' | '\'')) => string.push(escaped),
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    _ => return Err(self.error("Invalid escape sequence")),
+                },
+                Some(char) if char == quote => return Ok(string),
+                Some(char) => string.push(char),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+    }
+
+    fn read_unquoted_string(&mut self) -> &str {
+        let start = self.cursor;
+        while self.peek().is_some_and(|char| {
+            char.is_ascii_alphanumeric() || matches!(char, '_' | '-' | '.' | '+')
+        }) {
+            self.next();
+        }
+        &self.input[start..self.cursor]
+    }
+}
+
+/// Turns an unquoted token into a typed number or boolean, falling back to a string.
+fn parse_unquoted(token: &str) -> NbtTag {
+    if token.eq_ignore_ascii_case("true") {
+        return NbtTag::Byte(1);
+    }
+    if token.eq_ignore_ascii_case("false") {
+        return NbtTag::Byte(0);
+    }
+
+    let (number, suffix) = token.split_at(token.len() - 1);
+    let typed = match suffix {
+        "b" | "B" => number.parse().ok().map(NbtTag::Byte),
+        "s" | "S" => number.parse().ok().map(NbtTag::Short),
+        "l" | "L" => number.parse().ok().map(NbtTag::Long),
+        "f" | "F" => number.parse().ok().map(NbtTag::Float),
+        "d" | "D" => number.parse().ok().map(NbtTag::Double),
+        _ => None,
+    };
+    typed
+        .or_else(|| token.parse().ok().map(NbtTag::Int))
+        .or_else(|| {
+            // Untyped decimals are doubles, but only when they look like one (not "Infinity")
+            let looks_decimal = token.contains('.')
+                && token
+                    .chars()
+                    .all(|char| char.is_ascii_digit() || matches!(char, '.' | '-' | '+'));
+            looks_decimal
+                .then(|| token.parse().ok().map(NbtTag::Double))
+                .flatten()
+        })
+        .unwrap_or_else(|| NbtTag::String(token.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::from_snbt;
+    use crate::tag::NbtTag;
+
+    #[test]
+    fn parses_typed_numbers_and_strings() {
+        let compound = from_snbt(
+            r#"{a: 1b, b: 2s, c: 3, d: 4L, e: 2.0f, f: 1.5, g: "quoted \"x\"", h: plain}"#,
+        )
+        .unwrap();
+        assert_eq!(compound.get_byte("a"), Some(1));
+        assert_eq!(compound.get_short("b"), Some(2));
+        assert_eq!(compound.get_int("c"), Some(3));
+        assert_eq!(compound.get_long("d"), Some(4));
+        assert_eq!(compound.get_float("e"), Some(2.0));
+        assert_eq!(compound.get_double("f"), Some(1.5));
+        assert_eq!(compound.get_string("g"), Some("quoted \"x\""));
+        assert_eq!(compound.get_string("h"), Some("plain"));
+    }
+
+    #[test]
+    fn parses_nested_lists_and_arrays() {
+        let compound =
+            from_snbt("{Pos: [[1, 2], [3]], Inner: {Flag: true}, Ids: [I; 1, 2]}").unwrap();
+        assert_eq!(
+            compound.get_list("Pos"),
+            Some(
+                &[
+                    NbtTag::List(vec![NbtTag::Int(1), NbtTag::Int(2)]),
+                    NbtTag::List(vec![NbtTag::Int(3)]),
+                ][..]
+            )
+        );
+        assert_eq!(
+            compound
+                .get_compound("Inner")
+                .and_then(|inner| inner.get_byte("Flag")),
+            Some(1)
+        );
+        assert_eq!(compound.get("Ids"), Some(&NbtTag::IntArray(vec![1, 2])));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(from_snbt("{a: 1").is_err());
+        assert!(from_snbt("{a 1}").is_err());
+        assert!(from_snbt("{a: [1, 2b]}").is_err());
+        assert!(from_snbt(r#"{a: "unterminated}"#).is_err());
+        assert!(from_snbt("{a: 1} trailing").is_err());
+    }
+}
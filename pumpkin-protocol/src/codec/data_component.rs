@@ -2,10 +2,11 @@ use crate::codec::var_int::VarInt;
 use pumpkin_data::Enchantment;
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::data_component_impl::{
-    DamageImpl, DataComponentImpl, EnchantmentsImpl, MaxStackSizeImpl, get,
+    BucketEntityDataImpl, DamageImpl, DataComponentImpl, EnchantmentsImpl, MaxStackSizeImpl, get,
 };
 use serde::de;
 use serde::de::SeqAccess;
+use serde::ser;
 use serde::ser::SerializeStruct;
 use std::borrow::Cow;
 
@@ -79,6 +80,23 @@ impl DataComponentCodec<Self> for EnchantmentsImpl {
     }
 }
 
+impl DataComponentCodec<Self> for BucketEntityDataImpl {
+    fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error> {
+        // Network NBT, without a name for the root compound
+        let mut bytes = Vec::new();
+        pumpkin_nbt::serializer::to_bytes_unnamed(&self.nbt, &mut bytes)
+            .map_err(|err| ser::Error::custom(err.to_string()))?;
+        for byte in bytes {
+            seq.serialize_field::<u8>("", &byte)?;
+        }
+        Ok(())
+    }
+    fn deserialize<'a, A: SeqAccess<'a>>(_seq: &mut A) -> Result<Self, A::Error> {
+        // TODO: Read NBT from the network
+        Err(de::Error::custom("BucketEntityData can't be read yet"))
+    }
+}
+
 pub fn deserialize<'a, A: SeqAccess<'a>>(
     id: DataComponent,
     seq: &mut A,
@@ -87,6 +105,7 @@ pub fn deserialize<'a, A: SeqAccess<'a>>(
         DataComponent::MaxStackSize => Ok(MaxStackSizeImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Enchantments => Ok(EnchantmentsImpl::deserialize(seq)?.to_dyn()),
         DataComponent::Damage => Ok(DamageImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::BucketEntityData => Ok(BucketEntityDataImpl::deserialize(seq)?.to_dyn()),
         _ => todo!("{} not yet implemented", id.to_name()),
     }
 }
@@ -99,6 +118,7 @@ pub fn serialize<T: SerializeStruct>(
         DataComponent::MaxStackSize => get::<MaxStackSizeImpl>(value).serialize(seq),
         DataComponent::Enchantments => get::<EnchantmentsImpl>(value).serialize(seq),
         DataComponent::Damage => get::<DamageImpl>(value).serialize(seq),
+        DataComponent::BucketEntityData => get::<BucketEntityDataImpl>(value).serialize(seq),
         _ => todo!("{} not yet implemented", id.to_name()),
     }
 }
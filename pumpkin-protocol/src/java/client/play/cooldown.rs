@@ -0,0 +1,25 @@
+use pumpkin_data::packet::clientbound::PLAY_COOLDOWN;
+use pumpkin_macros::packet;
+use pumpkin_util::resource_location::ResourceLocation;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Puts all items of a cooldown group on cooldown, e.g. a shield disabled by an axe.
+#[derive(Serialize)]
+#[packet(PLAY_COOLDOWN)]
+pub struct CCooldown {
+    /// Usually the item's identifier, unless it has a custom `use_cooldown` group.
+    pub cooldown_group: ResourceLocation,
+    /// The cooldown in ticks, 0 removes the cooldown.
+    pub cooldown_ticks: VarInt,
+}
+
+impl CCooldown {
+    pub fn new(cooldown_group: ResourceLocation, cooldown_ticks: VarInt) -> Self {
+        Self {
+            cooldown_group,
+            cooldown_ticks,
+        }
+    }
+}
@@ -37,6 +37,10 @@ impl<T> Metadata<T> {
             value,
         }
     }
+
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
 }
 
 pub enum MetaDataType {
@@ -17,6 +17,7 @@ mod combat_death;
 mod command_suggestions;
 mod commands;
 mod cookie_request;
+mod cooldown;
 mod damage_event;
 mod disconnect;
 mod disguised_chat_message;
@@ -80,6 +81,7 @@ mod ticking_state;
 mod ticking_step;
 mod transfer;
 mod unload_chunk;
+mod update_attributes;
 mod update_entity_pos;
 mod update_entity_pos_rot;
 mod update_entity_rot;
@@ -107,6 +109,7 @@ pub use combat_death::*;
 pub use command_suggestions::*;
 pub use commands::*;
 pub use cookie_request::*;
+pub use cooldown::*;
 pub use damage_event::*;
 pub use disconnect::*;
 pub use disguised_chat_message::*;
@@ -170,6 +173,7 @@ pub use ticking_state::*;
 pub use ticking_step::*;
 pub use transfer::*;
 pub use unload_chunk::*;
+pub use update_attributes::*;
 pub use update_entity_pos::*;
 pub use update_entity_pos_rot::*;
 pub use update_entity_rot::*;
@@ -38,9 +38,9 @@ impl CSpawnEntity {
             entity_uuid,
             r#type,
             position,
-            pitch: (pitch * 256.0 / 360.0).floor() as u8,
-            yaw: (yaw * 256.0 / 360.0).floor() as u8,
-            head_yaw: (head_yaw * 256.0 / 360.0).floor() as u8,
+            pitch: to_angle(pitch),
+            yaw: to_angle(yaw),
+            head_yaw: to_angle(head_yaw),
             data,
             velocity: Vector3::new(
                 (velocity.x.clamp(-3.9, 3.9) * 8000.0) as i16,
@@ -50,3 +50,35 @@ impl CSpawnEntity {
         }
     }
 }
+
+/// Converts degrees into a protocol angle, wrapping negative angles around instead of clamping them to 0
+fn to_angle(degrees: f32) -> u8 {
+    (degrees * 256.0 / 360.0).floor() as i32 as u8
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::CSpawnEntity;
+    use crate::VarInt;
+
+    #[test]
+    fn spawn_packet_carries_velocity_and_rotation() {
+        let packet = CSpawnEntity::new(
+            VarInt(1),
+            uuid::Uuid::nil(),
+            VarInt(0),
+            Vector3::new(0.0, 64.0, 0.0),
+            -45.0,
+            -90.0,
+            90.0,
+            VarInt(0),
+            Vector3::new(0.1, 0.2, -0.1),
+        );
+        assert_eq!(packet.velocity, Vector3::new(800, 1600, -800));
+        assert_eq!(packet.pitch, 224);
+        assert_eq!(packet.yaw, 192);
+        assert_eq!(packet.head_yaw, 64);
+    }
+}
@@ -0,0 +1,37 @@
+use pumpkin_data::packet::clientbound::PLAY_UPDATE_ATTRIBUTES;
+use pumpkin_macros::packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+#[derive(Serialize)]
+#[packet(PLAY_UPDATE_ATTRIBUTES)]
+pub struct CUpdateAttributes {
+    pub entity_id: VarInt,
+    pub attributes: Vec<AttributeProperty>,
+}
+
+impl CUpdateAttributes {
+    pub fn new(entity_id: VarInt, attributes: Vec<AttributeProperty>) -> Self {
+        Self {
+            entity_id,
+            attributes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AttributeProperty {
+    /// The attribute's registry id
+    pub id: VarInt,
+    pub base: f64,
+    pub modifiers: Vec<AttributeModifierProperty>,
+}
+
+#[derive(Serialize)]
+pub struct AttributeModifierProperty {
+    pub id: String,
+    pub amount: f64,
+    /// 0 adds, 1 multiplies the base and 2 multiplies the total
+    pub operation: u8,
+}
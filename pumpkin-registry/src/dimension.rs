@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +25,27 @@ pub struct Dimension {
     ultrawarm: bool,
 }
 
+impl Dimension {
+    pub(crate) const fn min_y(&self) -> i32 {
+        self.min_y
+    }
+
+    pub(crate) const fn logical_height(&self) -> i32 {
+        self.logical_height
+    }
+
+    pub(crate) const fn monster_spawn_block_light_limit(&self) -> i32 {
+        self.monster_spawn_block_light_limit
+    }
+
+    pub(crate) fn monster_spawn_light_level(&self) -> RangeInclusive<i32> {
+        match &self.monster_spawn_light_level {
+            MonsterSpawnLightLevel::Int(level) => *level..=*level,
+            MonsterSpawnLightLevel::Tagged(range) => range.min_inclusive..=range.max_inclusive,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default, Debug)]
 pub enum DimensionEffects {
     #[serde(rename = "minecraft:overworld")]
@@ -1,3 +1,4 @@
+use std::ops::RangeInclusive;
 use std::sync::LazyLock;
 
 use banner_pattern::BannerPattern;
@@ -91,6 +92,37 @@ impl VanillaDimensionType {
         }
     }
 
+    fn dimension(self) -> &'static Dimension {
+        let name = match self {
+            Self::Overworld => "overworld",
+            Self::OverworldCaves => "overworld_caves",
+            Self::TheEnd => "the_end",
+            Self::TheNether => "the_nether",
+        };
+        &SYNCED_REGISTRIES.dimension_type[name]
+    }
+
+    /// The highest block Y portals and chorus fruit may take entities to, exclusive. The nether
+    /// stops below its bedrock roof.
+    pub fn logical_top_y(self) -> i32 {
+        let dimension = self.dimension();
+        dimension.min_y() + dimension.logical_height()
+    }
+
+    /// The most block light monsters spawn in, at 15 block light doesn't matter
+    pub fn monster_spawn_block_light_limit(self) -> u8 {
+        self.dimension()
+            .monster_spawn_block_light_limit()
+            .clamp(0, 15) as u8
+    }
+
+    /// The light levels monsters may spawn in, one of them is picked for every spawn attempt
+    pub fn monster_spawn_light_level(self) -> RangeInclusive<u8> {
+        let range = self.dimension().monster_spawn_light_level();
+        let clamp = |level: i32| level.clamp(0, 15) as u8;
+        clamp(*range.start())..=clamp(*range.end())
+    }
+
     pub fn from_resource_location_string(resource_location: &str) -> Option<Self> {
         match resource_location {
             "minecraft:overworld" => Some(Self::Overworld),
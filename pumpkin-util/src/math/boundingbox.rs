@@ -195,8 +195,18 @@ impl BoundingBox {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EntityDimensions {
     pub width: f32,
     pub height: f32,
 }
+
+impl EntityDimensions {
+    #[must_use]
+    pub fn scaled(self, scale: f32) -> Self {
+        Self {
+            width: self.width * scale,
+            height: self.height * scale,
+        }
+    }
+}
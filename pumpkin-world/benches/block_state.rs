@@ -0,0 +1,31 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use pumpkin_data::{Block, BlockState};
+use std::hint::black_box;
+
+// The lookup `Entity::check_block_collision` does for every block the entity touches
+fn bench_block_and_state_lookup(c: &mut Criterion) {
+    // Only air has raw id 0, so the first other id mapping to it is past the last state
+    let state_ids: Vec<u16> = (0..=u16::MAX)
+        .take_while(|id| *id == 0 || Block::get_raw_id_from_state_id(*id) != 0)
+        .collect();
+
+    c.bench_function("block_and_state_flat_table", |b| {
+        b.iter(|| {
+            for id in &state_ids {
+                black_box(BlockState::from_id_with_block(black_box(*id)));
+            }
+        })
+    });
+
+    c.bench_function("block_and_state_separate_lookups", |b| {
+        b.iter(|| {
+            for id in &state_ids {
+                let id = black_box(*id);
+                black_box((Block::from_state_id(id), BlockState::from_id(id)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_block_and_state_lookup);
+criterion_main!(benches);
@@ -0,0 +1,97 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock},
+};
+
+use dashmap::DashMap;
+use pumpkin_data::Block;
+
+use crate::BlockStateId;
+
+type Properties = Box<[(String, String)]>;
+
+/// Maps every property combination of a block to its state id.
+struct StateIndex {
+    default_properties: Properties,
+    states: HashMap<Properties, BlockStateId>,
+}
+
+impl StateIndex {
+    fn new(block: &Block) -> Option<Self> {
+        let default_properties = block.properties(block.default_state.id)?.to_props();
+        let states = block
+            .states
+            .iter()
+            .filter_map(|state| Some((block.properties(state.id)?.to_props(), state.id)))
+            .collect();
+        Some(Self {
+            default_properties,
+            states,
+        })
+    }
+}
+
+/// Built lazily per block id, `None` for blocks without properties.
+static STATE_INDICES: LazyLock<DashMap<u16, Option<Arc<StateIndex>>>> = LazyLock::new(DashMap::new);
+
+/// Finds the state of `block` with the given properties. Properties which are not given keep their
+/// value from the default state.
+///
+/// Returns [`None`] if a property does not exist on the block or the value is invalid.
+#[must_use]
+pub fn get_state_id(block: &Block, properties: &[(&str, &str)]) -> Option<BlockStateId> {
+    let index = STATE_INDICES
+        .entry(block.id)
+        .or_insert_with(|| StateIndex::new(block).map(Arc::new))
+        .clone();
+    let Some(index) = index else {
+        return properties.is_empty().then_some(block.default_state.id);
+    };
+
+    let mut props = index.default_properties.clone();
+    for (key, value) in properties {
+        let (_, prop_value) = props.iter_mut().find(|(name, _)| name == key)?;
+        *prop_value = (*value).to_string();
+    }
+    // Unknown values simply have no matching state
+    index.states.get(&props).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::Block;
+
+    use super::get_state_id;
+
+    #[test]
+    fn state_id_from_properties() {
+        let block = &Block::OAK_LOG;
+        let state_id = get_state_id(block, &[("axis", "x")]).unwrap();
+        let props = block.properties(state_id).unwrap().to_props();
+        assert_eq!(&*props, &[("axis".to_string(), "x".to_string())]);
+
+        assert_eq!(get_state_id(block, &[]), Some(block.default_state.id));
+        assert_eq!(get_state_id(block, &[("axis", "w")]), None);
+        assert_eq!(get_state_id(block, &[("facing", "x")]), None);
+
+        let stone = &Block::STONE;
+        assert_eq!(get_state_id(stone, &[]), Some(stone.default_state.id));
+        assert_eq!(get_state_id(stone, &[("axis", "x")]), None);
+    }
+
+    #[test]
+    fn unspecified_properties_keep_default_values() {
+        let block = &Block::OAK_STAIRS;
+        let default_props = block.properties(block.default_state.id).unwrap().to_props();
+
+        let state_id = get_state_id(block, &[("facing", "south")]).unwrap();
+        let props = block.properties(state_id).unwrap().to_props();
+        for ((name, value), (_, default_value)) in props.iter().zip(default_props.iter()) {
+            if name == "facing" {
+                assert_eq!(value, "south");
+            } else {
+                assert_eq!(value, default_value);
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    any::Any,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicI64, Ordering},
+    },
+};
+
+use async_trait::async_trait;
+use pumpkin_data::data_component_impl::JukeboxPlayableImpl;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::math::position::BlockPos;
+use tokio::sync::Mutex;
+
+use crate::{
+    inventory::{Clearable, Inventory},
+    item::ItemStack,
+    world::SimpleWorld,
+};
+
+use super::BlockEntity;
+
+#[derive(Debug)]
+pub struct JukeboxBlockEntity {
+    pub position: BlockPos,
+    /// The music disc currently inserted, `ItemStack::EMPTY` if there is none.
+    pub record: Arc<Mutex<ItemStack>>,
+    pub ticks_since_song_started: AtomicI64,
+    pub dirty: AtomicBool,
+}
+
+const RECORD_ITEM: &str = "RecordItem";
+const TICKS_SINCE_SONG_STARTED: &str = "ticks_since_song_started";
+
+impl JukeboxBlockEntity {
+    pub const ID: &'static str = "minecraft:jukebox";
+
+    pub fn new(position: BlockPos) -> Self {
+        Self {
+            position,
+            record: Arc::new(Mutex::new(ItemStack::EMPTY.clone())),
+            ticks_since_song_started: AtomicI64::new(0),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// Inserts a new record, restarting the song. Returns the previous record.
+    pub async fn set_record(&self, record: ItemStack) -> ItemStack {
+        let old = std::mem::replace(&mut *self.record.lock().await, record);
+        self.ticks_since_song_started.store(0, Ordering::Relaxed);
+        self.dirty.store(true, Ordering::Relaxed);
+        old
+    }
+
+    /// Removes the current record, stopping the song.
+    pub async fn take_record(&self) -> ItemStack {
+        self.set_record(ItemStack::EMPTY.clone()).await
+    }
+
+    /// The song of the inserted record, e.g. `minecraft:cat`.
+    pub async fn get_song(&self) -> Option<&'static str> {
+        self.record
+            .lock()
+            .await
+            .get_data_component::<JukeboxPlayableImpl>()
+            .map(|playable| playable.song)
+    }
+
+    pub async fn is_playing(&self) -> bool {
+        self.get_song().await.is_some()
+    }
+}
+
+#[async_trait]
+impl BlockEntity for JukeboxBlockEntity {
+    fn resource_location(&self) -> &'static str {
+        Self::ID
+    }
+
+    fn get_position(&self) -> BlockPos {
+        self.position
+    }
+
+    fn from_nbt(nbt: &NbtCompound, position: BlockPos) -> Self
+    where
+        Self: Sized,
+    {
+        let record = nbt
+            .get_compound(RECORD_ITEM)
+            .and_then(ItemStack::read_item_stack)
+            .unwrap_or_else(|| ItemStack::EMPTY.clone());
+        Self {
+            position,
+            record: Arc::new(Mutex::new(record)),
+            ticks_since_song_started: AtomicI64::new(
+                nbt.get_long(TICKS_SINCE_SONG_STARTED).unwrap_or(0),
+            ),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        let record = self.record.lock().await;
+        if !record.is_empty() {
+            let mut item = NbtCompound::new();
+            record.write_item_stack(&mut item);
+            nbt.put_component(RECORD_ITEM, item);
+            nbt.put_long(
+                TICKS_SINCE_SONG_STARTED,
+                self.ticks_since_song_started.load(Ordering::Relaxed),
+            );
+        }
+    }
+
+    async fn tick(&self, _world: Arc<dyn SimpleWorld>) {
+        // TODO: Stop the song once its length has passed
+        if self.is_playing().await {
+            self.ticks_since_song_started
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn get_inventory(self: Arc<Self>) -> Option<Arc<dyn Inventory>> {
+        Some(self)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty.load(Ordering::Relaxed)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl Inventory for JukeboxBlockEntity {
+    fn size(&self) -> usize {
+        1
+    }
+
+    async fn is_empty(&self) -> bool {
+        self.record.lock().await.is_empty()
+    }
+
+    async fn get_stack(&self, _slot: usize) -> Arc<Mutex<ItemStack>> {
+        self.record.clone()
+    }
+
+    async fn remove_stack(&self, _slot: usize) -> ItemStack {
+        self.take_record().await
+    }
+
+    async fn remove_stack_specific(&self, _slot: usize, amount: u8) -> ItemStack {
+        if amount == 0 {
+            return ItemStack::EMPTY.clone();
+        }
+        self.take_record().await
+    }
+
+    fn get_max_count_per_stack(&self) -> u8 {
+        1
+    }
+
+    async fn set_stack(&self, _slot: usize, stack: ItemStack) {
+        self.set_record(stack).await;
+    }
+
+    // TODO: Hoppers should be able to insert and extract records, which requires starting and
+    // stopping the music from here
+    fn is_valid_slot_for(&self, _slot: usize, _stack: &ItemStack) -> bool {
+        false
+    }
+
+    fn can_transfer_to(
+        &self,
+        _hopper_inventory: &dyn Inventory,
+        _slot: usize,
+        _stack: &ItemStack,
+    ) -> bool {
+        false
+    }
+
+    fn mark_dirty(&self) {
+        self.dirty.store(true, Ordering::Relaxed);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl Clearable for JukeboxBlockEntity {
+    async fn clear(&self) {
+        *self.record.lock().await = ItemStack::EMPTY.clone();
+    }
+}
@@ -1,3 +1,4 @@
+pub mod block_registry;
 pub mod entities;
 pub mod state;
 pub mod viewer;
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use futures::future::join_all;
 use pumpkin_data::{Block, chunk::ChunkStatus, fluid::Fluid};
-use pumpkin_nbt::{compound::NbtCompound, from_bytes, nbt_long_array};
+use pumpkin_nbt::{compound::NbtCompound, from_bytes, nbt_long_array, tag::NbtTag};
 use uuid::Uuid;
 
 use crate::{
@@ -301,21 +301,16 @@ impl ChunkEntityData {
         }
         let mut map = HashMap::new();
         for entity_nbt in chunk_entity_data.entities {
-            // TODO: This is wrong, we should use an int array, but our NBT lib for some reason does not work with int arrays and
-            // Just gives me a list when putting in a int array
-            let uuid = match entity_nbt.get_list("UUID") {
-                Some(uuid) => uuid,
-                None => {
-                    log::warn!("TODO: use int arrays for UUID");
-                    continue;
-                }
+            // A single broken entity should not take the rest of the chunk with it
+            let Some(uuid) = entity_uuid_from_nbt(&entity_nbt) else {
+                log::warn!(
+                    "Skipping entity {:?} without a valid UUID in entity chunk {},{}",
+                    entity_nbt.get_string("id"),
+                    position.x,
+                    position.y
+                );
+                continue;
             };
-            let uuid = Uuid::from_u128(
-                (uuid.first().unwrap().extract_int().unwrap() as u128) << 96
-                    | (uuid.get(1).unwrap().extract_int().unwrap() as u128) << 64
-                    | (uuid.get(2).unwrap().extract_int().unwrap() as u128) << 32
-                    | (uuid.get(3).unwrap().extract_int().unwrap() as u128),
-            );
             map.insert(uuid, entity_nbt);
         }
 
@@ -338,6 +333,42 @@ impl ChunkEntityData {
             .map_err(ChunkSerializingError::ErrorSerializingChunk)?;
         Ok(result.into())
     }
+
+    /// Moves the saved data of an entity which walked into the chunk `to`.
+    ///
+    /// Returns `false` if this chunk had no data for the entity.
+    pub fn transfer_entity(&mut self, to: &mut Self, uuid: &Uuid) -> bool {
+        let Some(nbt) = self.data.remove(uuid) else {
+            return false;
+        };
+        to.data.insert(*uuid, nbt);
+        self.mark_dirty(true);
+        to.mark_dirty(true);
+        true
+    }
+}
+
+/// Reads the `UUID` of a saved entity, stored as four ints (most significant first).
+///
+/// Our NBT deserializer hands int arrays back as lists, so both forms are accepted.
+fn entity_uuid_from_nbt(nbt: &NbtCompound) -> Option<Uuid> {
+    let parts: Vec<i32> = match nbt.get("UUID")? {
+        NbtTag::IntArray(parts) => parts.clone(),
+        NbtTag::List(parts) => parts
+            .iter()
+            .map(NbtTag::extract_int)
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+    let [a, b, c, d] = parts[..] else {
+        return None;
+    };
+    Some(Uuid::from_u128(
+        u128::from(a as u32) << 96
+            | u128::from(b as u32) << 64
+            | u128::from(c as u32) << 32
+            | u128::from(d as u32),
+    ))
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -487,3 +518,80 @@ struct EntityNbt {
     position: [i32; 2],
     entities: Vec<NbtCompound>,
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+    use pumpkin_util::math::vector2::Vector2;
+    use uuid::Uuid;
+
+    use crate::chunk::ChunkEntityData;
+
+    fn entity_nbt(id: &str, uuid: Uuid) -> NbtCompound {
+        let uuid = uuid.as_u128();
+        let mut nbt = NbtCompound::new();
+        nbt.put_string("id", id.to_string());
+        nbt.put(
+            "UUID",
+            NbtTag::IntArray(vec![
+                (uuid >> 96) as i32,
+                (uuid >> 64) as i32,
+                (uuid >> 32) as i32,
+                uuid as i32,
+            ]),
+        );
+        nbt
+    }
+
+    #[test]
+    fn entity_chunk_round_trip() {
+        let position = Vector2::new(3, -2);
+        let zombie = Uuid::from_u128(0xFFFF_FFFF_8000_0000_0000_0001_0000_0002);
+        let creeper = Uuid::new_v4();
+        let mut data = HashMap::new();
+        data.insert(zombie, entity_nbt("minecraft:zombie", zombie));
+        data.insert(creeper, entity_nbt("minecraft:creeper", creeper));
+        let chunk = ChunkEntityData {
+            chunk_position: position,
+            data,
+            dirty: true,
+        };
+
+        let bytes = chunk.internal_to_bytes().unwrap();
+        let loaded = ChunkEntityData::internal_from_bytes(&bytes, position).unwrap();
+
+        assert_eq!(loaded.data.len(), 2);
+        assert_eq!(
+            loaded.data[&zombie].get_string("id"),
+            Some("minecraft:zombie")
+        );
+        assert_eq!(
+            loaded.data[&creeper].get_string("id"),
+            Some("minecraft:creeper")
+        );
+    }
+
+    #[test]
+    fn malformed_entity_does_not_abort_chunk() {
+        let position = Vector2::new(0, 0);
+        let valid = Uuid::new_v4();
+        let mut data = HashMap::new();
+        data.insert(valid, entity_nbt("minecraft:zombie", valid));
+        let mut broken = NbtCompound::new();
+        broken.put_string("id", "minecraft:zombie".to_string());
+        broken.put("UUID", NbtTag::IntArray(vec![1, 2]));
+        data.insert(Uuid::new_v4(), broken);
+        let chunk = ChunkEntityData {
+            chunk_position: position,
+            data,
+            dirty: true,
+        };
+
+        let bytes = chunk.internal_to_bytes().unwrap();
+        let loaded = ChunkEntityData::internal_from_bytes(&bytes, position).unwrap();
+
+        assert_eq!(loaded.data.keys().collect::<Vec<_>>(), vec![&valid]);
+    }
+}
@@ -139,6 +139,26 @@ pub struct ChunkLight {
     pub block_light: Box<[LightContainer]>,
 }
 
+impl ChunkLight {
+    /// The `(sky, block)` light levels, `relative_y` counts from the bottom of the chunk.
+    /// Returns `None` above the chunk's sections.
+    pub fn get_light_levels(
+        &self,
+        relative_x: usize,
+        relative_y: usize,
+        relative_z: usize,
+    ) -> Option<(u8, u8)> {
+        let section = relative_y / LightContainer::DIM;
+        let y = relative_y % LightContainer::DIM;
+        let sky = self.sky_light.get(section)?.get(relative_x, y, relative_z);
+        let block = self
+            .block_light
+            .get(section)?
+            .get(relative_x, y, relative_z);
+        Some((sky, block))
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ChunkHeightmapType {
     WorldSurface = 0,
@@ -1,8 +1,8 @@
 use pumpkin_data::data_component::DataComponent;
-use pumpkin_data::data_component::DataComponent::Enchantments;
+use pumpkin_data::data_component::DataComponent::{Damage, Enchantments};
 use pumpkin_data::data_component_impl::{
-    BlocksAttacksImpl, ConsumableImpl, DataComponentImpl, EnchantmentsImpl, IDSet,
-    MaxStackSizeImpl, ToolImpl, get, get_mut, read_data,
+    BlocksAttacksImpl, ConsumableImpl, DamageImpl, DataComponentImpl, EnchantmentsImpl, FoodImpl,
+    IDSet, MaxDamageImpl, MaxStackSizeImpl, ToolImpl, get, get_mut, read_data,
 };
 use pumpkin_data::item::Item;
 use pumpkin_data::recipes::RecipeResultStruct;
@@ -108,6 +108,9 @@ impl ItemStack {
         if let Some(value) = self.get_data_component::<ConsumableImpl>() {
             return value.consume_ticks();
         }
+        if self.get_data_component::<FoodImpl>().is_some() {
+            return (ConsumableImpl::DEFAULT_CONSUME_SECONDS * 20.0) as i32;
+        }
         if self.get_data_component::<BlocksAttacksImpl>().is_some() {
             return 72000;
         }
@@ -196,6 +199,50 @@ impl ItemStack {
             ));
         }
     }
+
+    /// Returns the level of the given enchantment on this stack, or `0` if it isn't present.
+    pub fn get_enchantment_level(&self, enchantment: &'static Enchantment) -> i32 {
+        self.get_data_component::<EnchantmentsImpl>()
+            .and_then(|data| {
+                data.enchantment
+                    .iter()
+                    .find(|(enc, _)| *enc == enchantment)
+                    .map(|(_, level)| *level)
+            })
+            .unwrap_or(0)
+    }
+
+    /// Wears down a damageable item by `amount` durability. Returns whether the item broke, which
+    /// empties the stack.
+    pub fn damage_item(&mut self, amount: i32) -> bool {
+        // TODO: Unbreaking
+        let Some(max_damage) = self
+            .get_data_component::<MaxDamageImpl>()
+            .map(|data| data.max_damage)
+        else {
+            return false;
+        };
+        if amount <= 0 {
+            return false;
+        }
+        let damage = self
+            .get_data_component::<DamageImpl>()
+            .map_or(0, |data| data.damage)
+            + amount;
+        if let Some(data) = self.get_data_component_mut::<DamageImpl>() {
+            data.damage = damage;
+        } else {
+            self.patch.retain(|(id, _)| id != &Damage);
+            self.patch
+                .push((Damage, Some(DamageImpl { damage }.to_dyn())));
+        }
+        if damage >= max_damage {
+            self.decrement(1);
+            return true;
+        }
+        false
+    }
+
     pub fn are_items_and_components_equal(&self, other: &Self) -> bool {
         if self.item != other.item || self.patch.len() != other.patch.len() {
             return false;
@@ -858,6 +858,25 @@ impl Level {
 
         RawBlockState(id)
     }
+
+    /// The `(sky, block)` light levels at the position, full sky light outside the world's height.
+    pub async fn get_light_levels(self: &Arc<Self>, position: &BlockPos) -> (u8, u8) {
+        let (chunk_coordinate, relative) = position.chunk_and_chunk_relative_position();
+        let chunk = self.get_chunk(chunk_coordinate).await;
+        let chunk = chunk.read().await;
+
+        usize::try_from(relative.y - chunk.section.min_y)
+            .ok()
+            .and_then(|relative_y| {
+                chunk.light_engine.get_light_levels(
+                    relative.x as usize,
+                    relative_y,
+                    relative.z as usize,
+                )
+            })
+            .unwrap_or((15, 0))
+    }
+
     pub async fn get_rough_biome(self: &Arc<Self>, position: &BlockPos) -> &'static Biome {
         let (chunk_coordinate, relative) = position.chunk_and_chunk_relative_position();
         let chunk = self.get_chunk(chunk_coordinate).await;
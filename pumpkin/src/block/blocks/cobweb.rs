@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::vector3::Vector3;
+
+use crate::block::{BlockBehaviour, OnEntityCollisionArgs};
+
+#[pumpkin_block("minecraft:cobweb")]
+pub struct CobwebBlock;
+
+impl CobwebBlock {
+    /// How much the movement of entities inside a cobweb is scaled
+    pub const MOVEMENT_MULTIPLIER: Vector3<f64> = Vector3::new(0.25, 0.05, 0.25);
+}
+
+#[async_trait]
+impl BlockBehaviour for CobwebBlock {
+    // TODO: Entities with the weaving effect are slowed less
+    async fn on_entity_collision(&self, args: OnEntityCollisionArgs<'_>) {
+        if let Some(living) = args.entity.get_living_entity() {
+            living.fall_distance.store(0.0);
+        }
+        args.entity
+            .get_entity()
+            .slow_movement(Self::MOVEMENT_MULTIPLIER);
+    }
+}
@@ -14,6 +14,9 @@ pub struct EndPortalBlock;
 #[async_trait]
 impl BlockBehaviour for EndPortalBlock {
     async fn on_entity_collision(&self, args: OnEntityCollisionArgs<'_>) {
+        if !args.entity.can_use_portals() {
+            return;
+        }
         let world = if args.world.dimension_type == VanillaDimensionType::TheEnd {
             args.server
                 .get_world_from_dimension(VanillaDimensionType::Overworld)
@@ -207,7 +207,7 @@ impl BlockBehaviour for FireBlock {
                     .store(ticks + rnd_ticks, Ordering::Relaxed);
             }
             if base_entity.fire_ticks.load(Ordering::Relaxed) >= 0 {
-                base_entity.set_on_fire_for(8.0);
+                base_entity.set_on_fire_for(8.0).await;
             }
         }
     }
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use std::sync::atomic::Ordering;
+
+use crate::block::{BlockBehaviour, OnEntityCollisionArgs};
+
+#[pumpkin_block("minecraft:honey_block")]
+pub struct HoneyBlock;
+
+impl HoneyBlock {
+    /// Entities sliding down a honey block fall at most this fast
+    pub const SLIDE_VELOCITY: f64 = -0.05;
+
+    /// Whether an entity is sliding down the side of the honey block at `pos`, like vanilla's `HoneyBlock.isSliding`
+    #[must_use]
+    pub fn is_sliding(
+        pos: BlockPos,
+        entity_pos: Vector3<f64>,
+        velocity: Vector3<f64>,
+        width: f64,
+        on_ground: bool,
+        sneaking: bool,
+    ) -> bool {
+        if on_ground || sneaking {
+            return false;
+        }
+        // Above the top of the block, the entity lands on it instead
+        if entity_pos.y > f64::from(pos.0.y) + 0.9375 - 1.0e-7 {
+            return false;
+        }
+        if velocity.y >= -0.08 {
+            return false;
+        }
+        let center = pos.to_centered_f64();
+        let max_distance = 0.4375 + width / 2.0;
+        (center.x - entity_pos.x).abs() + 1.0e-7 > max_distance
+            || (center.z - entity_pos.z).abs() + 1.0e-7 > max_distance
+    }
+
+    /// Caps the fall of a sliding entity to [`Self::SLIDE_VELOCITY`], slowing it down horizontally by the same amount
+    #[must_use]
+    pub fn sliding_velocity(velocity: Vector3<f64>) -> Vector3<f64> {
+        if velocity.y < -0.13 {
+            let scale = Self::SLIDE_VELOCITY / velocity.y;
+            Vector3::new(velocity.x * scale, Self::SLIDE_VELOCITY, velocity.z * scale)
+        } else {
+            Vector3::new(velocity.x, Self::SLIDE_VELOCITY, velocity.z)
+        }
+    }
+}
+
+#[async_trait]
+impl BlockBehaviour for HoneyBlock {
+    async fn on_entity_collision(&self, args: OnEntityCollisionArgs<'_>) {
+        let entity = args.entity.get_entity();
+        if !Self::is_sliding(
+            *args.position,
+            entity.pos.load(),
+            entity.velocity.load(),
+            f64::from(entity.width()),
+            entity.on_ground.load(Ordering::Relaxed),
+            entity.sneaking.load(Ordering::Relaxed),
+        ) {
+            return;
+        }
+
+        entity
+            .velocity
+            .store(Self::sliding_velocity(entity.velocity.load()));
+        if let Some(living) = args.entity.get_living_entity() {
+            living.fall_distance.store(0.0);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::block::registry::BlockActionResult;
-use crate::block::{BlockBehaviour, BrokenArgs, UseWithItemArgs};
+use crate::block::{BlockBehaviour, BrokenArgs, NormalUseArgs, PlacedArgs, UseWithItemArgs};
 use crate::world::World;
 use async_trait::async_trait;
 use pumpkin_data::data_component_impl::JukeboxPlayableImpl;
@@ -13,6 +13,8 @@ use pumpkin_data::{
 use pumpkin_macros::pumpkin_block;
 use pumpkin_registry::SYNCED_REGISTRIES;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_world::block::entities::BlockEntity;
+use pumpkin_world::block::entities::jukebox::JukeboxBlockEntity;
 use pumpkin_world::world::BlockFlags;
 
 #[pumpkin_block("minecraft:jukebox")]
@@ -37,62 +39,120 @@ impl JukeboxBlock {
             .await;
     }
 
+    async fn get_block_entity(world: &World, position: &BlockPos) -> Option<Arc<dyn BlockEntity>> {
+        world
+            .get_block_entity(position)
+            .await
+            .filter(|entity| entity.resource_location() == JukeboxBlockEntity::ID)
+    }
+
+    /// Returns the index of the song in the `jukebox_song` registry, which the client expects
+    /// as world event data, e.g. `minecraft:cat`.
+    #[must_use]
+    pub fn get_song_index(song: &str) -> Option<i32> {
+        let song = song.strip_prefix("minecraft:").unwrap_or(song);
+        let index = SYNCED_REGISTRIES.jukebox_song.get_index_of(song);
+        if index.is_none() {
+            log::error!("Jukebox playable song {song} not registered!");
+        }
+        index.map(|index| index as i32)
+    }
+
     async fn stop_music(&self, block: &Block, position: &BlockPos, world: &Arc<World>) {
         self.set_record(false, block, position, world).await;
         world
             .sync_world_event(WorldEvent::JukeboxStopsPlaying, *position, 0)
             .await;
     }
+
+    /// Stops the music and drops the current record above the jukebox.
+    async fn eject_record(&self, block: &Block, position: &BlockPos, world: &Arc<World>) {
+        if let Some(block_entity) = Self::get_block_entity(world, position).await
+            && let Some(jukebox) = block_entity.as_any().downcast_ref::<JukeboxBlockEntity>()
+        {
+            let record = jukebox.take_record().await;
+            if !record.is_empty() {
+                world.drop_stack(&position.up(), record).await;
+            }
+        }
+        self.stop_music(block, position, world).await;
+    }
 }
 
 #[async_trait]
 impl BlockBehaviour for JukeboxBlock {
+    async fn normal_use(&self, args: NormalUseArgs<'_>) -> BlockActionResult {
+        if !self.has_record(args.block, args.position, args.world).await {
+            return BlockActionResult::Pass;
+        }
+        self.eject_record(args.block, args.position, args.world)
+            .await;
+        BlockActionResult::Success
+    }
+
     async fn use_with_item(&self, args: UseWithItemArgs<'_>) -> BlockActionResult {
-        let world = &args.player.living_entity.entity.world;
+        let world = args.world;
 
-        // if the jukebox already has a record, stop playing
+        // if the jukebox already has a record, eject it instead
         if self.has_record(args.block, args.position, world).await {
-            self.stop_music(args.block, args.position, world).await;
-            return BlockActionResult::Success;
+            return BlockActionResult::PassToDefaultBlockAction;
         }
 
-        let jukebox_playable = args
-            .item_stack
-            .lock()
-            .await
+        let mut item_stack = args.item_stack.lock().await;
+        let Some(song) = item_stack
             .get_data_component::<JukeboxPlayableImpl>()
-            .map(|i| i.song);
-
-        let Some(jukebox_playable) = jukebox_playable else {
-            return BlockActionResult::Pass;
+            .map(|playable| playable.song)
+        else {
+            return BlockActionResult::PassToDefaultBlockAction;
         };
 
-        let Some(song) = jukebox_playable.split(':').nth(1) else {
+        let Some(jukebox_song) = Self::get_song_index(song) else {
             return BlockActionResult::Pass;
         };
 
-        let Some(jukebox_song) = SYNCED_REGISTRIES.jukebox_song.get_index_of(song) else {
-            log::error!("Jukebox playable song not registered!");
+        // Jukeboxes placed before they had a block entity need one now
+        let block_entity =
+            if let Some(block_entity) = Self::get_block_entity(world, args.position).await {
+                block_entity
+            } else {
+                let block_entity: Arc<dyn BlockEntity> =
+                    Arc::new(JukeboxBlockEntity::new(*args.position));
+                world.add_block_entity(block_entity.clone()).await;
+                block_entity
+            };
+        let Some(jukebox) = block_entity.as_any().downcast_ref::<JukeboxBlockEntity>() else {
             return BlockActionResult::Pass;
         };
 
-        //TODO: Update block nbt
+        let record = item_stack.split_unless_creative(args.player.gamemode.load(), 1);
+        drop(item_stack);
+        jukebox.set_record(record).await;
 
         self.set_record(true, args.block, args.position, world)
             .await;
+        // Make sure nothing else is still playing here before starting the new song
+        world
+            .sync_world_event(WorldEvent::JukeboxStopsPlaying, *args.position, 0)
+            .await;
         world
             .sync_world_event(
                 WorldEvent::JukeboxStartsPlaying,
                 *args.position,
-                jukebox_song as i32,
+                jukebox_song,
             )
             .await;
 
         BlockActionResult::Success
     }
 
+    async fn placed(&self, args: PlacedArgs<'_>) {
+        args.world
+            .add_block_entity(Arc::new(JukeboxBlockEntity::new(*args.position)))
+            .await;
+    }
+
     async fn broken(&self, args: BrokenArgs<'_>) {
-        // For now just stop the music at this position
+        // The record itself is dropped by the block entity when it gets removed
         args.world
             .sync_world_event(WorldEvent::JukeboxStopsPlaying, *args.position, 0)
             .await;
@@ -12,6 +12,7 @@ pub mod carved_pumpkin;
 pub mod chain;
 pub mod chests;
 pub mod chiseled_bookshelf;
+pub mod cobweb;
 pub mod command;
 pub mod composter;
 pub mod crafting_table;
@@ -30,6 +31,7 @@ pub mod furnace;
 pub mod glass_panes;
 pub mod glazed_terracotta;
 pub mod grindstone;
+pub mod honey;
 pub mod iron_bars;
 pub mod jukebox;
 pub mod ladder;
@@ -39,6 +41,7 @@ pub mod nether_portal;
 pub mod note;
 pub mod piston;
 pub mod plant;
+pub mod powder_snow;
 pub mod pumpkin;
 pub mod redstone;
 pub mod signs;
@@ -58,6 +58,9 @@ impl BlockBehaviour for NetherPortalBlock {
     }
 
     async fn on_entity_collision(&self, args: OnEntityCollisionArgs<'_>) {
+        if !args.entity.can_use_portals() {
+            return;
+        }
         let target_world = if args.world.dimension_type == VanillaDimensionType::TheNether {
             args.server
                 .get_world_from_dimension(VanillaDimensionType::Overworld)
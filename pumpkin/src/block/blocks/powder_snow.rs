@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use pumpkin_data::Block;
+use pumpkin_macros::pumpkin_block;
+use pumpkin_util::math::vector3::Vector3;
+use std::sync::atomic::Ordering;
+
+use crate::block::{BlockBehaviour, OnEntityCollisionArgs};
+
+#[pumpkin_block("minecraft:powder_snow")]
+pub struct PowderSnowBlock;
+
+impl PowderSnowBlock {
+    /// How much the movement of entities sinking in powder snow is scaled
+    pub const MOVEMENT_MULTIPLIER: Vector3<f64> = Vector3::new(0.9, 1.5, 0.9);
+}
+
+#[async_trait]
+impl BlockBehaviour for PowderSnowBlock {
+    async fn on_entity_collision(&self, args: OnEntityCollisionArgs<'_>) {
+        let entity = args.entity.get_entity();
+        // Living entities standing on top of powder snow (e.g. with leather boots) aren't slowed
+        if args.entity.get_living_entity().is_none()
+            || args.world.get_block(&entity.block_pos.load()).await == &Block::POWDER_SNOW
+        {
+            if let Some(living) = args.entity.get_living_entity() {
+                living.fall_distance.store(0.0);
+            }
+            entity.slow_movement(Self::MOVEMENT_MULTIPLIER);
+        }
+
+        entity.in_powder_snow.store(true, Ordering::Relaxed);
+
+        // TODO: Burning entities should also melt the powder snow
+        if entity.fire_ticks.load(Ordering::Relaxed) > 0 {
+            entity.extinguish();
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::block::{
     PlacedArgs,
 };
 use crate::entity::Entity;
-use crate::entity::item::ItemEntity;
+use crate::entity::item::{ItemEntity, THROWN_PICKUP_DELAY};
 use async_trait::async_trait;
 use pumpkin_data::FacingExt;
 use pumpkin_data::block_properties::{BlockProperties, Facing};
@@ -202,8 +202,10 @@ impl BlockBehaviour for DropperBlock {
                     triangle(&mut rng(), 0.2, 0.017_227_5 * 6.),
                     triangle(&mut rng(), facing.z * rd, 0.017_227_5 * 6.),
                 );
-                let item_entity =
-                    Arc::new(ItemEntity::new_with_velocity(entity, drop_item, velocity, 40).await);
+                let item_entity = Arc::new(
+                    ItemEntity::new_with_velocity(entity, drop_item, velocity, THROWN_PICKUP_DELAY)
+                        .await,
+                );
                 args.world.spawn_entity(item_entity).await;
                 args.world
                     .sync_world_event(WorldEvent::DispenserDispenses, *args.position, 0)
@@ -48,8 +48,8 @@ impl TNTBlock {
     }
 }
 
-const DEFAULT_FUSE: u32 = 80;
-const DEFAULT_POWER: f32 = 4.0;
+pub const DEFAULT_FUSE: u32 = 80;
+pub const DEFAULT_POWER: f32 = 4.0;
 
 #[async_trait]
 impl BlockBehaviour for TNTBlock {
@@ -111,7 +111,7 @@ impl FluidBehaviour for FlowingLava {
     async fn on_entity_collision(&self, entity: &dyn EntityBase) {
         let base_entity = entity.get_entity();
         if !base_entity.entity_type.fire_immune {
-            base_entity.set_on_fire_for(15.0);
+            base_entity.set_on_fire_for(15.0).await;
         }
     }
 }
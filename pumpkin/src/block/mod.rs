@@ -5,7 +5,6 @@ use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::random::{RandomGenerator, get_seed, xoroshiro128::Xoroshiro};
 use pumpkin_world::BlockStateId;
 
-use crate::entity::experience_orb::ExperienceOrbEntity;
 use crate::entity::player::Player;
 use crate::world::World;
 use crate::world::loot::{LootContextParameters, LootTableExt};
@@ -318,7 +317,9 @@ pub async fn drop_loot(
         let amount = experience.experience.get(&mut random);
         // TODO: Silk touch gives no exp
         if amount > 0 {
-            ExperienceOrbEntity::spawn(world, pos.to_f64(), amount as u32).await;
+            world
+                .spawn_experience_orbs(pos.to_centered_f64(), amount as u32)
+                .await;
         }
     }
 }
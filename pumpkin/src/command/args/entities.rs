@@ -11,6 +11,7 @@ use pumpkin_data::entity::EntityType;
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
 use pumpkin_util::GameMode;
+use pumpkin_util::text::TextComponent;
 use uuid::Uuid;
 
 use super::super::args::ArgumentConsumer;
@@ -35,6 +36,15 @@ pub enum ValueCondition<T> {
     NotEquals(T),
 }
 
+impl<T: PartialEq> ValueCondition<T> {
+    pub fn test(&self, value: &T) -> bool {
+        match self {
+            Self::Equals(expected) => value == expected,
+            Self::NotEquals(expected) => value != expected,
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub enum ComparableValueCondition<T> {
     Equals(T),
@@ -46,6 +56,46 @@ pub enum ComparableValueCondition<T> {
     Between(T, T),
 }
 
+impl<T: PartialOrd> ComparableValueCondition<T> {
+    pub fn test(&self, value: &T) -> bool {
+        match self {
+            Self::Equals(expected) => value == expected,
+            Self::NotEquals(expected) => value != expected,
+            Self::GreaterThan(min) => value > min,
+            Self::LessThan(max) => value < max,
+            Self::GreaterThanOrEquals(min) => value >= min,
+            Self::LessThanOrEquals(max) => value <= max,
+            Self::Between(min, max) => value >= min && value <= max,
+        }
+    }
+}
+
+impl ComparableValueCondition<f64> {
+    /// Parses an inclusive range like `5`, `..10`, `5..` or `5..10`
+    fn parse_range(value: &str) -> Result<Self, String> {
+        let parse = |bound: &str| {
+            bound
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{bound}'"))
+        };
+        let Some((min, max)) = value.split_once("..") else {
+            return Ok(Self::Equals(parse(value)?));
+        };
+        match (min.is_empty(), max.is_empty()) {
+            (true, true) => Err("Range must have at least one bound".to_string()),
+            (true, false) => Ok(Self::LessThanOrEquals(parse(max)?)),
+            (false, true) => Ok(Self::GreaterThanOrEquals(parse(min)?)),
+            (false, false) => {
+                let (min, max) = (parse(min)?, parse(max)?);
+                if min > max {
+                    return Err(format!("Range minimum {min} is larger than maximum {max}"));
+                }
+                Ok(Self::Between(min, max))
+            }
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Copy, Clone, PartialEq)]
 pub enum EntityFilterSort {
@@ -90,6 +140,30 @@ impl FromStr for EntityFilter {
         }
 
         match key {
+            "distance" => {
+                if negate {
+                    return Err("Negation of distance is not allowed".to_string());
+                }
+                let distance = ComparableValueCondition::parse_range(value)?;
+                if matches!(
+                    distance,
+                    ComparableValueCondition::Equals(d)
+                        | ComparableValueCondition::LessThanOrEquals(d)
+                        | ComparableValueCondition::GreaterThanOrEquals(d)
+                        | ComparableValueCondition::Between(d, _) if d < 0.0
+                ) {
+                    return Err("Distance cannot be negative".to_string());
+                }
+                Ok(Self::Distance(distance))
+            }
+            "name" => {
+                let name = value.trim_matches('"').to_string();
+                Ok(Self::Name(if negate {
+                    ValueCondition::NotEquals(name)
+                } else {
+                    ValueCondition::Equals(name)
+                }))
+            }
             "type" => {
                 let entity_type =
                     EntityType::from_name(value).ok_or(format!("Invalid entity type {value}"))?;
@@ -121,7 +195,7 @@ impl FromStr for EntityFilter {
                 }
                 Ok(Self::Sort(sort))
             }
-            _ => todo!("{key}"),
+            _ => Err(format!("Unknown option '{key}'")),
         }
     }
 }
@@ -194,6 +268,38 @@ impl TargetSelector {
     }
 }
 
+impl TargetSelector {
+    /// Parses a target selector, describing what is wrong with invalid ones
+    pub fn parse(arg: &str) -> Result<Self, CommandError> {
+        arg.parse().map_err(|e| {
+            CommandError::CommandFailed(Box::new(TextComponent::text(format!(
+                "Invalid target selector '{arg}': {e}"
+            ))))
+        })
+    }
+
+    #[must_use]
+    pub fn get_distance(&self) -> Option<&ComparableValueCondition<f64>> {
+        self.conditions.iter().rev().find_map(|f| {
+            if let EntityFilter::Distance(distance) = f {
+                Some(distance)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_names(&self) -> impl Iterator<Item = &ValueCondition<String>> {
+        self.conditions.iter().filter_map(|f| {
+            if let EntityFilter::Name(name) = f {
+                Some(name)
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl FromStr for TargetSelector {
     type Err = String;
 
@@ -221,6 +327,7 @@ impl FromStr for TargetSelector {
             let conditions: Vec<_> = body[1][..body[1].len() - 1]
                 .split(',')
                 .map(str::trim)
+                .filter(|s| !s.is_empty())
                 .collect();
             for s in conditions {
                 selector.conditions.push(EntityFilter::from_str(s)?);
@@ -258,18 +365,23 @@ impl ArgumentConsumer for EntitiesArgumentConsumer {
         server: &'a Server,
         args: &mut RawArgs<'a>,
     ) -> Option<Arg<'a>> {
-        let s = args.pop()?;
-        let entity_selector = match s.parse::<TargetSelector>() {
-            Ok(selector) => selector,
-            Err(e) => {
-                log::debug!("Failed to parse target selector '{s}': {e}");
-                return None;
-            }
+        self.try_consume(src, server, args).await.ok().flatten()
+    }
+
+    async fn try_consume<'a>(
+        &'a self,
+        src: &CommandSender,
+        server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Result<Option<Arg<'a>>, CommandError> {
+        let Some(s) = args.pop() else {
+            return Ok(None);
         };
+        let entity_selector = TargetSelector::parse(s)?;
         // todo: command context
         let entities = server.select_entities(&entity_selector, Some(src)).await;
 
-        Some(Arg::Entities(entities))
+        Ok(Some(Arg::Entities(entities)))
     }
 
     async fn suggest<'a>(
@@ -298,3 +410,49 @@ impl<'a> FindArg<'a> for EntitiesArgumentConsumer {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+
+    use super::{ComparableValueCondition, EntityFilter, EntitySelectorType, TargetSelector};
+
+    #[test]
+    fn parses_selector_options() {
+        let selector =
+            TargetSelector::parse("@e[type=minecraft:zombie,distance=..10,limit=2,name=Bob]")
+                .unwrap_or_else(|_| panic!("valid selector"));
+        assert!(matches!(
+            selector.selector_type,
+            EntitySelectorType::AllEntities
+        ));
+        assert_eq!(selector.get_limit(), 2);
+        assert!(selector.conditions.iter().any(|f| matches!(
+            f,
+            EntityFilter::Type(super::ValueCondition::Equals(t)) if *t == &EntityType::ZOMBIE
+        )));
+        let distance = selector.get_distance().unwrap();
+        assert!(distance.test(&10.0));
+        assert!(!distance.test(&10.5));
+        assert!(
+            selector
+                .get_names()
+                .any(|name| name.test(&"Bob".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let range = ComparableValueCondition::parse_range("2..5").unwrap();
+        assert!(range.test(&2.0) && range.test(&5.0) && !range.test(&1.9));
+        assert!(ComparableValueCondition::parse_range("5..2").is_err());
+        assert!(ComparableValueCondition::parse_range("..").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        assert!(TargetSelector::parse("@e[foo=bar]").is_err());
+        assert!(TargetSelector::parse("@e[distance=-1]").is_err());
+        assert!(TargetSelector::parse("@x").is_err());
+    }
+}
@@ -8,6 +8,7 @@ use crate::entity::EntityBase;
 use crate::server::Server;
 use async_trait::async_trait;
 use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
+use pumpkin_util::text::TextComponent;
 
 use super::super::args::ArgumentConsumer;
 use super::{Arg, DefaultNameArgConsumer, FindArg, GetClientSideArgParser};
@@ -40,24 +41,30 @@ impl ArgumentConsumer for EntityArgumentConsumer {
         server: &'a Server,
         args: &mut RawArgs<'a>,
     ) -> Option<Arg<'a>> {
-        let s = args.pop()?;
+        self.try_consume(src, server, args).await.ok().flatten()
+    }
 
-        let entity_selector = match s.parse::<TargetSelector>() {
-            Ok(selector) => selector,
-            Err(e) => {
-                log::debug!("Failed to parse target selector '{s}': {e}");
-                return None;
-            }
+    async fn try_consume<'a>(
+        &'a self,
+        src: &CommandSender,
+        server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Result<Option<Arg<'a>>, CommandError> {
+        let Some(s) = args.pop() else {
+            return Ok(None);
         };
+
+        let entity_selector = TargetSelector::parse(s)?;
         if entity_selector.get_limit() > 1 {
-            log::debug!("Target selector '{s}' has limit > 1, expected single entity");
-            return None;
+            return Err(CommandError::CommandFailed(Box::new(TextComponent::text(
+                format!("Target selector '{s}' can select more than one entity"),
+            ))));
         }
         // todo: command context
         let entities = server.select_entities(&entity_selector, Some(src)).await;
 
         // Take first
-        entities.into_iter().next().map(Arg::Entity)
+        Ok(entities.into_iter().next().map(Arg::Entity))
     }
 
     async fn suggest<'a>(
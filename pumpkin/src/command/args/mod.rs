@@ -3,10 +3,12 @@ use std::{collections::HashMap, hash::Hash, sync::Arc};
 use async_trait::async_trait;
 use bounded_num::{NotInBounds, Number};
 use pumpkin_data::Enchantment;
+use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DamageType;
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::particle::Particle;
 use pumpkin_data::sound::SoundCategory;
+use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
 use pumpkin_util::Difficulty;
 use pumpkin_util::text::TextComponent;
@@ -36,6 +38,7 @@ pub mod entities;
 pub mod entity;
 pub mod gamemode;
 pub mod message;
+pub mod nbt;
 pub mod players;
 pub mod position_2d;
 pub mod position_3d;
@@ -60,6 +63,17 @@ pub trait ArgumentConsumer: Sync + GetClientSideArgParser {
         args: &mut RawArgs<'a>,
     ) -> Option<Arg>;
 
+    /// Like [`ArgumentConsumer::consume`], but lets the consumer describe why the argument is
+    /// invalid instead of the command failing with a generic error.
+    async fn try_consume<'a>(
+        &'a self,
+        sender: &CommandSender,
+        server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Result<Option<Arg<'a>>, CommandError> {
+        Ok(self.consume(sender, server, args).await)
+    }
+
     /// Used for tab completion (but only if argument suggestion type is "`minecraft:ask_server`"!).
     ///
     /// NOTE: This is called after this consumer's [`ArgumentConsumer::consume`] method returned None, so if args is used here, make sure [`ArgumentConsumer::consume`] never returns None after mutating args.
@@ -102,6 +116,7 @@ pub enum Arg<'a> {
     BossbarStyle(BossbarDivisions),
     Particle(Particle),
     Msg(String),
+    Nbt(NbtCompound),
     TextComponent(TextComponent),
     Time(i32),
     Num(Result<Number, NotInBounds>),
@@ -111,6 +126,7 @@ pub enum Arg<'a> {
     SoundCategory(SoundCategory),
     DamageType(DamageType),
     Effect(&'static StatusEffect),
+    Attribute(&'static Attributes),
     Enchantment(&'static Enchantment),
 }
 
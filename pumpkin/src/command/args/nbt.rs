@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use pumpkin_nbt::{compound::NbtCompound, from_snbt};
+use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
+
+use crate::{command::dispatcher::CommandError, server::Server};
+
+use super::{
+    super::{
+        CommandSender,
+        args::{ArgumentConsumer, RawArgs},
+    },
+    Arg, DefaultNameArgConsumer, FindArg, GetClientSideArgParser,
+};
+
+/// Consumes an SNBT compound like `{CustomName: "Bob", Health: 5.0f}`, which may span multiple words.
+pub struct NbtArgConsumer;
+
+impl GetClientSideArgParser for NbtArgConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType<'_> {
+        ArgumentType::Nbt
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        None
+    }
+}
+
+#[async_trait]
+impl ArgumentConsumer for NbtArgConsumer {
+    async fn consume<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Option<Arg<'a>> {
+        parse_nbt_arg(args).map(Arg::Nbt)
+    }
+
+    async fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        _input: &'a str,
+    ) -> Result<Option<Vec<CommandSuggestion>>, CommandError> {
+        Ok(None)
+    }
+}
+
+/// Joins words until they form a complete compound. Returns [`None`] on a syntax error.
+fn parse_nbt_arg(args: &mut RawArgs) -> Option<NbtCompound> {
+    let first = args.pop()?;
+    if !first.starts_with('{') {
+        return None;
+    }
+
+    let mut snbt = first.to_string();
+    loop {
+        if let Ok(compound) = from_snbt(&snbt) {
+            return Some(compound);
+        }
+        snbt.push(' ');
+        snbt.push_str(args.pop()?);
+    }
+}
+
+impl DefaultNameArgConsumer for NbtArgConsumer {
+    fn default_name(&self) -> &'static str {
+        "nbt"
+    }
+}
+
+impl<'a> FindArg<'a> for NbtArgConsumer {
+    type Data = &'a NbtCompound;
+
+    fn find_arg(args: &'a super::ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
+        match args.get(name) {
+            Some(Arg::Nbt(data)) => Ok(data),
+            _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_nbt_arg;
+
+    fn raw_args(input: &str) -> Vec<&str> {
+        input.split(' ').rev().collect()
+    }
+
+    #[test]
+    fn nbt_spanning_words_is_parsed_into_compound() {
+        let mut args = raw_args(r#"{CustomName: "Bob", Tags: [a, b], Health: 5.0f} next"#);
+        let compound = parse_nbt_arg(&mut args).unwrap();
+
+        assert_eq!(compound.get_string("CustomName"), Some("Bob"));
+        assert_eq!(compound.get_list("Tags").map(<[_]>::len), Some(2));
+        assert_eq!(compound.get_float("Health"), Some(5.0));
+        assert_eq!(args, vec!["next"]);
+    }
+
+    #[test]
+    fn malformed_nbt_is_rejected() {
+        assert!(parse_nbt_arg(&mut raw_args("{Health: 5.0f")).is_none());
+        assert!(parse_nbt_arg(&mut raw_args("{Tags: [1, \"a\"]}")).is_none());
+        assert!(parse_nbt_arg(&mut raw_args("Health: 5.0f}")).is_none());
+    }
+}
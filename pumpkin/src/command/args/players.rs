@@ -2,8 +2,10 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
+use pumpkin_util::text::TextComponent;
 
 use crate::command::CommandSender;
+use crate::command::args::entities::{EntitySelectorType, TargetSelector};
 use crate::command::dispatcher::CommandError;
 use crate::command::tree::RawArgs;
 use crate::entity::player::Player;
@@ -36,28 +38,47 @@ impl ArgumentConsumer for PlayersArgumentConsumer {
         server: &'a Server,
         args: &mut RawArgs<'a>,
     ) -> Option<Arg<'a>> {
-        let s = args.pop()?;
+        self.try_consume(src, server, args).await.ok().flatten()
+    }
 
-        let players = match s {
-            "@s" => match src {
-                CommandSender::Player(p) => Some(vec![p.clone()]),
-                _ => None,
-            },
-            #[allow(clippy::match_same_arms)]
-            // todo: implement for non-players and remove this line
-            "@n" | "@p" => match src {
-                CommandSender::Player(p) => Some(vec![p.clone()]),
-                // todo: implement for non-players: how should this behave when sender is console/rcon?
-                _ => None,
-            },
-            "@r" => {
-                (server.get_random_player().await).map_or_else(|| Some(vec![]), |p| Some(vec![p]))
-            }
-            "@a" | "@e" => Some(server.get_all_players().await),
-            name => server.get_player_by_name(name).await.map(|p| vec![p]),
+    async fn try_consume<'a>(
+        &'a self,
+        src: &CommandSender,
+        server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Result<Option<Arg<'a>>, CommandError> {
+        let Some(s) = args.pop() else {
+            return Ok(None);
         };
+        let selector = TargetSelector::parse(s)?;
+        if matches!(
+            selector.selector_type,
+            EntitySelectorType::NearestPlayer | EntitySelectorType::NearestEntity
+        ) && src.position().is_none()
+        {
+            // Console and rcon have no position to find the nearest player from
+            return Ok(None);
+        }
 
-        players.map(Arg::Players)
+        // Only the players of `@e` can be selected here, use `EntitiesArgumentConsumer` to
+        // select every entity
+        let mut players = Vec::new();
+        for entity in server.select_entities(&selector, Some(src)).await {
+            if entity.get_player().is_some()
+                && let Some(player) = server
+                    .get_player_by_uuid(entity.get_entity().entity_uuid)
+                    .await
+            {
+                players.push(player);
+            }
+        }
+
+        if players.is_empty() {
+            return Err(CommandError::CommandFailed(Box::new(
+                TextComponent::translate("argument.entity.notfound.player", []),
+            )));
+        }
+        Ok(Some(Arg::Players(players)))
     }
 
     async fn suggest<'a>(
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_protocol::java::client::play::{ArgumentType, CommandSuggestion, SuggestionProviders};
+
+use crate::command::{
+    CommandSender,
+    args::{
+        Arg, ArgumentConsumer, ConsumedArgs, DefaultNameArgConsumer, FindArg,
+        GetClientSideArgParser, SplitSingleWhitespaceIncludingEmptyParts,
+    },
+    dispatcher::CommandError,
+    tree::RawArgs,
+};
+use crate::server::Server;
+
+pub struct AttributeArgumentConsumer;
+
+impl GetClientSideArgParser for AttributeArgumentConsumer {
+    fn get_client_side_parser(&self) -> ArgumentType<'_> {
+        ArgumentType::Resource {
+            identifier: "attribute",
+        }
+    }
+
+    fn get_client_side_suggestion_type_override(&self) -> Option<SuggestionProviders> {
+        Some(SuggestionProviders::AskServer)
+    }
+}
+
+#[async_trait]
+impl ArgumentConsumer for AttributeArgumentConsumer {
+    async fn consume<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        args: &mut RawArgs<'a>,
+    ) -> Option<Arg<'a>> {
+        let name = args.pop()?;
+        let name = name.strip_prefix("minecraft:").unwrap_or(name);
+        let attribute = Attributes::from_minecraft_name(&format!("minecraft:{name}"))?;
+        Some(Arg::Attribute(attribute))
+    }
+
+    async fn suggest<'a>(
+        &'a self,
+        _sender: &CommandSender,
+        _server: &'a Server,
+        input: &'a str,
+    ) -> Result<Option<Vec<CommandSuggestion>>, CommandError> {
+        let Some(input) = input.split_single_whitespace_including_empty_parts().last() else {
+            return Ok(None);
+        };
+
+        let suggestions = attribute_suggestions(input)
+            .map(|suggestion| CommandSuggestion::new(suggestion.to_string(), None))
+            .collect();
+        Ok(Some(suggestions))
+    }
+}
+
+/// All attribute names starting with `input`, both with and without the `minecraft:` namespace.
+fn attribute_suggestions(input: &str) -> impl Iterator<Item = &'static str> {
+    Attributes::all()
+        .iter()
+        .flat_map(|attribute| {
+            let name = attribute.minecraft_name;
+            [name, name.strip_prefix("minecraft:").unwrap_or(name)]
+        })
+        .filter(move |name| name.starts_with(input))
+}
+
+impl DefaultNameArgConsumer for AttributeArgumentConsumer {
+    fn default_name(&self) -> &'static str {
+        "attribute"
+    }
+}
+
+impl<'a> FindArg<'a> for AttributeArgumentConsumer {
+    type Data = &'static Attributes;
+
+    fn find_arg(args: &'a ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
+        match args.get(name) {
+            Some(Arg::Attribute(data)) => Ok(data),
+            _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::attribute_suggestions;
+
+    #[test]
+    fn suggests_attributes_by_prefix() {
+        let suggestions: Vec<_> = attribute_suggestions("gra").collect();
+        assert_eq!(suggestions, vec!["gravity"]);
+
+        let suggestions: Vec<_> = attribute_suggestions("max_").collect();
+        assert_eq!(suggestions, vec!["max_absorption", "max_health"]);
+
+        let suggestions: Vec<_> = attribute_suggestions("minecraft:max_h").collect();
+        assert_eq!(suggestions, vec!["minecraft:max_health"]);
+
+        assert_eq!(
+            attribute_suggestions("").count(),
+            2 * pumpkin_data::attributes::Attributes::all().len()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod attribute;
 pub mod damage_type;
 pub mod effect;
 pub mod enchantment;
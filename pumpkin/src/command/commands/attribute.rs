@@ -0,0 +1,582 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::data_component_impl::Operation;
+use pumpkin_util::text::TextComponent;
+
+use crate::command::{
+    CommandError, CommandExecutor, CommandSender,
+    args::{
+        ConsumedArgs, FindArg, bounded_num::BoundedNumArgumentConsumer,
+        entity::EntityArgumentConsumer, resource::attribute::AttributeArgumentConsumer,
+        resource_location::ResourceLocationArgumentConsumer,
+    },
+    tree::{
+        CommandTree,
+        builder::{argument, literal},
+    },
+};
+use crate::entity::EntityBase;
+use crate::entity::attributes::{AttributeManager, AttributeModifier, operation_name};
+use crate::server::Server;
+
+const NAMES: [&str; 1] = ["attribute"];
+const DESCRIPTION: &str = "Queries, adds, removes or sets an entity attribute.";
+
+const ARG_TARGET: &str = "target";
+const ARG_ATTRIBUTE: &str = "attribute";
+const ARG_ID: &str = "id";
+const ARG_VALUE: &str = "value";
+const ARG_SCALE: &str = "scale";
+
+/// The scale only multiplies the command's result value, which commands can't return yet.
+fn scale_consumer() -> BoundedNumArgumentConsumer<f64> {
+    BoundedNumArgumentConsumer::new().name(ARG_SCALE)
+}
+
+fn value_consumer() -> BoundedNumArgumentConsumer<f64> {
+    BoundedNumArgumentConsumer::new().name(ARG_VALUE)
+}
+
+fn attribute_name(attribute: &Attributes) -> TextComponent {
+    let name = attribute
+        .minecraft_name
+        .strip_prefix("minecraft:")
+        .unwrap_or(attribute.minecraft_name);
+    TextComponent::translate(format!("attribute.name.{name}"), [])
+}
+
+fn value_text(value: f64) -> TextComponent {
+    TextComponent::text(value.to_string())
+}
+
+/// Modifier ids are resource locations, so a missing namespace defaults to `minecraft`.
+fn modifier_id(id: &str) -> String {
+    if id.contains(':') {
+        id.to_string()
+    } else {
+        format!("minecraft:{id}")
+    }
+}
+
+/// Returns the attributes of the target, failing if it isn't a living entity or lacks the attribute.
+async fn target_attributes<'a>(
+    target: &'a Arc<dyn EntityBase>,
+    attribute: &'static Attributes,
+) -> Result<&'a AttributeManager, CommandError> {
+    let Some(manager) = target.get_attribute_manager() else {
+        return Err(CommandError::CommandFailed(Box::new(
+            TextComponent::translate(
+                "commands.attribute.failed.entity",
+                [target.get_display_name().await],
+            ),
+        )));
+    };
+    if !manager.has_attribute(attribute) {
+        return Err(CommandError::CommandFailed(Box::new(
+            TextComponent::translate(
+                "commands.attribute.failed.no_attribute",
+                [target.get_display_name().await, attribute_name(attribute)],
+            ),
+        )));
+    }
+    Ok(manager)
+}
+
+/// Lets clients know about the changed attribute, e.g. so a new scale resizes the entity.
+async fn update_attribute(target: &Arc<dyn EntityBase>, attribute: &'static Attributes) {
+    if let Some(living) = target.get_living_entity() {
+        living.update_attribute(attribute).await;
+    }
+}
+
+/// `/attribute <target> <attribute> get [<scale>]`
+struct GetExecutor;
+
+#[async_trait]
+impl CommandExecutor for GetExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let manager = target_attributes(&target, attribute).await?;
+
+        // Equipment and effects count as well
+        let value = match target.get_living_entity() {
+            Some(living) => living.get_attribute_value(attribute).await,
+            None => manager.get_modified(attribute, []),
+        };
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.value.get.success",
+                [
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                    value_text(value),
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> base get [<scale>]`
+struct BaseGetExecutor;
+
+#[async_trait]
+impl CommandExecutor for BaseGetExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let manager = target_attributes(&target, attribute).await?;
+
+        let value = manager.get_base(attribute);
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.base_value.get.success",
+                [
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                    value_text(value),
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> base set <value>`
+struct BaseSetExecutor;
+
+#[async_trait]
+impl CommandExecutor for BaseSetExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let value = BoundedNumArgumentConsumer::<f64>::find_arg(args, ARG_VALUE)??;
+        let manager = target_attributes(&target, attribute).await?;
+
+        let _ = manager.set_base(attribute, value);
+        update_attribute(&target, attribute).await;
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.base_value.set.success",
+                [
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                    value_text(manager.get_base(attribute)),
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> base reset`
+struct BaseResetExecutor;
+
+#[async_trait]
+impl CommandExecutor for BaseResetExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let manager = target_attributes(&target, attribute).await?;
+
+        let default = AttributeManager::new(target.get_entity().entity_type).get_base(attribute);
+        let _ = manager.set_base(attribute, default);
+        update_attribute(&target, attribute).await;
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.base_value.reset.success",
+                [
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                    value_text(default),
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> modifier add <id> <value> <operation>`
+struct AddModifierExecutor(Operation);
+
+#[async_trait]
+impl CommandExecutor for AddModifierExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let id = modifier_id(ResourceLocationArgumentConsumer::find_arg(args, ARG_ID)?);
+        let amount = BoundedNumArgumentConsumer::<f64>::find_arg(args, ARG_VALUE)??;
+        let manager = target_attributes(&target, attribute).await?;
+
+        let _ = manager.add_modifier(
+            attribute,
+            AttributeModifier {
+                id: id.clone(),
+                amount,
+                operation: self.0,
+            },
+        );
+        update_attribute(&target, attribute).await;
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.modifier.add.success",
+                [
+                    TextComponent::text(id),
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> modifier remove <id>`
+struct RemoveModifierExecutor;
+
+#[async_trait]
+impl CommandExecutor for RemoveModifierExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let id = modifier_id(ResourceLocationArgumentConsumer::find_arg(args, ARG_ID)?);
+        let manager = target_attributes(&target, attribute).await?;
+
+        if !manager.remove_modifier(attribute, &id) {
+            return Err(CommandError::CommandFailed(Box::new(
+                TextComponent::translate(
+                    "commands.attribute.failed.no_modifier",
+                    [
+                        attribute_name(attribute),
+                        target.get_display_name().await,
+                        TextComponent::text(id),
+                    ],
+                ),
+            )));
+        }
+        update_attribute(&target, attribute).await;
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.modifier.remove.success",
+                [
+                    TextComponent::text(id),
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> modifier value get <id> [<scale>]`
+struct GetModifierExecutor;
+
+#[async_trait]
+impl CommandExecutor for GetModifierExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let id = modifier_id(ResourceLocationArgumentConsumer::find_arg(args, ARG_ID)?);
+        let manager = target_attributes(&target, attribute).await?;
+
+        let Some(modifier) = manager
+            .get_modifiers(attribute)
+            .into_iter()
+            .find(|modifier| modifier.id == id)
+        else {
+            return Err(CommandError::CommandFailed(Box::new(
+                TextComponent::translate(
+                    "commands.attribute.failed.no_modifier",
+                    [
+                        attribute_name(attribute),
+                        target.get_display_name().await,
+                        TextComponent::text(id),
+                    ],
+                ),
+            )));
+        };
+        sender
+            .send_message(TextComponent::translate(
+                "commands.attribute.modifier.value.get.success",
+                [
+                    TextComponent::text(id),
+                    attribute_name(attribute),
+                    target.get_display_name().await,
+                    value_text(modifier.amount),
+                ],
+            ))
+            .await;
+        Ok(())
+    }
+}
+
+/// `/attribute <target> <attribute> modifier list`
+struct ListModifiersExecutor;
+
+#[async_trait]
+impl CommandExecutor for ListModifiersExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let attribute = AttributeArgumentConsumer::find_arg(args, ARG_ATTRIBUTE)?;
+        let manager = target_attributes(&target, attribute).await?;
+
+        let locale = sender.get_locale().await;
+        let modifiers = manager.get_modifiers(attribute);
+        let mut with = vec![attribute_name(attribute), target.get_display_name().await];
+        let key = if modifiers.is_empty() {
+            "commands.attribute.modifier.list.empty"
+        } else {
+            with.push(TextComponent::text(modifiers.len().to_string()));
+            "commands.attribute.modifier.list.success"
+        };
+        sender
+            .send_message(TextComponent::custom("pumpkin", key, locale, with))
+            .await;
+        for modifier in modifiers {
+            sender
+                .send_message(TextComponent::custom(
+                    "pumpkin",
+                    "commands.attribute.modifier.list.entry",
+                    locale,
+                    vec![
+                        TextComponent::text(modifier.id),
+                        value_text(modifier.amount),
+                        TextComponent::text(operation_name(modifier.operation)),
+                    ],
+                ))
+                .await;
+        }
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        argument(ARG_TARGET, EntityArgumentConsumer).then(
+            argument(ARG_ATTRIBUTE, AttributeArgumentConsumer)
+                .then(
+                    literal("get")
+                        .execute(GetExecutor)
+                        .then(argument(ARG_SCALE, scale_consumer()).execute(GetExecutor)),
+                )
+                .then(
+                    literal("base")
+                        .then(
+                            literal("get").execute(BaseGetExecutor).then(
+                                argument(ARG_SCALE, scale_consumer()).execute(BaseGetExecutor),
+                            ),
+                        )
+                        .then(
+                            literal("set").then(
+                                argument(ARG_VALUE, value_consumer()).execute(BaseSetExecutor),
+                            ),
+                        )
+                        .then(literal("reset").execute(BaseResetExecutor)),
+                )
+                .then(
+                    literal("modifier")
+                        .then(
+                            literal("add").then(
+                                argument(ARG_ID, ResourceLocationArgumentConsumer::new(false))
+                                    .then(
+                                        argument(ARG_VALUE, value_consumer())
+                                            .then(
+                                                literal("add_value").execute(AddModifierExecutor(
+                                                    Operation::AddValue,
+                                                )),
+                                            )
+                                            .then(literal("add_multiplied_base").execute(
+                                                AddModifierExecutor(Operation::AddMultipliedBase),
+                                            ))
+                                            .then(literal("add_multiplied_total").execute(
+                                                AddModifierExecutor(Operation::AddMultipliedTotal),
+                                            )),
+                                    ),
+                            ),
+                        )
+                        .then(literal("list").execute(ListModifiersExecutor))
+                        .then(
+                            literal("remove").then(
+                                argument(ARG_ID, ResourceLocationArgumentConsumer::new(false))
+                                    .execute(RemoveModifierExecutor),
+                            ),
+                        )
+                        .then(
+                            literal("value").then(
+                                literal("get").then(
+                                    argument(ARG_ID, ResourceLocationArgumentConsumer::new(false))
+                                        .execute(GetModifierExecutor)
+                                        .then(
+                                            argument(ARG_SCALE, scale_consumer())
+                                                .execute(GetModifierExecutor),
+                                        ),
+                                ),
+                            ),
+                        ),
+                ),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::command::tree::{CommandTree, NodeType};
+
+    use super::{ARG_ID, ARG_SCALE, init_command_tree};
+
+    /// The literals and argument names along a path of the tree.
+    fn path_names(tree: &CommandTree, path: &[usize]) -> Vec<String> {
+        path.iter()
+            .filter_map(|&i| match &tree.nodes[i].node_type {
+                NodeType::Literal { string } => Some(string.clone()),
+                NodeType::Argument { name, .. } => Some(format!("<{name}>")),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn modifier_value_get_path_resolves_id() {
+        let tree = init_command_tree();
+        let paths: Vec<_> = tree
+            .iter_paths()
+            .map(|path| path_names(&tree, &path))
+            .collect();
+
+        for names in &paths {
+            let mut arguments: Vec<_> = names.iter().filter(|name| name.starts_with('<')).collect();
+            let count = arguments.len();
+            arguments.sort();
+            arguments.dedup();
+            assert_eq!(arguments.len(), count, "duplicate argument in {names:?}");
+        }
+
+        let id = format!("<{ARG_ID}>");
+        let scale = format!("<{ARG_SCALE}>");
+        assert!(paths.contains(&vec![
+            "<target>".to_string(),
+            "<attribute>".to_string(),
+            "modifier".to_string(),
+            "value".to_string(),
+            "get".to_string(),
+            id.clone(),
+        ]));
+        assert!(paths.contains(&vec![
+            "<target>".to_string(),
+            "<attribute>".to_string(),
+            "modifier".to_string(),
+            "value".to_string(),
+            "get".to_string(),
+            id,
+            scale,
+        ]));
+    }
+
+    #[tokio::test]
+    async fn modifier_list_reports_every_modifier() {
+        use std::sync::Arc;
+
+        use pumpkin_data::attributes::Attributes;
+        use pumpkin_data::data_component_impl::Operation;
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_util::math::vector3::Vector3;
+
+        use crate::command::{CommandSender, commands::default_dispatcher};
+        use crate::entity::Entity;
+        use crate::entity::attributes::AttributeModifier;
+        use crate::entity::mob::{Mob, zombie::Zombie};
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        world.spawn_entity(zombie.clone()).await;
+        let living = &zombie.get_mob_entity().living_entity;
+        for (id, amount, operation) in [
+            ("minecraft:fast", 0.5, Operation::AddMultipliedBase),
+            ("minecraft:slow", -0.1, Operation::AddValue),
+        ] {
+            assert!(living.attribute_manager.add_modifier(
+                &Attributes::MOVEMENT_SPEED,
+                AttributeModifier {
+                    id: id.to_string(),
+                    amount,
+                    operation,
+                },
+            ));
+        }
+
+        let dispatcher = default_dispatcher().await;
+        let messages = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let command = format!(
+            "attribute {} minecraft:movement_speed modifier list",
+            living.entity.entity_uuid
+        );
+        dispatcher
+            .dispatch(
+                &mut CommandSender::Rcon(messages.clone()),
+                &server,
+                &command,
+            )
+            .await
+            .unwrap();
+
+        let messages = messages.lock().await;
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].ends_with("has 2 modifiers:"));
+        assert_eq!(messages[1], "minecraft:fast: 0.5 (add_multiplied_base)");
+        assert_eq!(messages[2], "minecraft:slow: -0.1 (add_value)");
+    }
+}
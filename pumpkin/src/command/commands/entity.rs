@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use pumpkin_data::entity::EntityPose;
+use pumpkin_util::text::TextComponent;
+
+use crate::command::{
+    CommandError, CommandExecutor, CommandSender,
+    args::{ConsumedArgs, FindArg, entity::EntityArgumentConsumer},
+    tree::{
+        CommandTree,
+        builder::{argument, literal},
+    },
+};
+
+const NAMES: [&str; 1] = ["entity"];
+
+const DESCRIPTION: &str = "Debugging tools for entities, e.g. changing their pose.";
+
+const ARG_TARGET: &str = "target";
+
+/// Poses by their vanilla names
+const POSES: [(&str, EntityPose); 18] = [
+    ("standing", EntityPose::Standing),
+    ("fall_flying", EntityPose::FallFlying),
+    ("sleeping", EntityPose::Sleeping),
+    ("swimming", EntityPose::Swimming),
+    ("spin_attack", EntityPose::SpinAttack),
+    ("crouching", EntityPose::Crouching),
+    ("long_jumping", EntityPose::LongJumping),
+    ("dying", EntityPose::Dying),
+    ("croaking", EntityPose::Croaking),
+    ("using_tongue", EntityPose::UsingTongue),
+    ("sitting", EntityPose::Sitting),
+    ("roaring", EntityPose::Roaring),
+    ("sniffing", EntityPose::Sniffing),
+    ("emerging", EntityPose::Emerging),
+    ("digging", EntityPose::Digging),
+    ("sliding", EntityPose::Sliding),
+    ("shooting", EntityPose::Shooting),
+    ("inhaling", EntityPose::Inhaling),
+];
+
+struct PoseExecutor {
+    name: &'static str,
+    pose: EntityPose,
+}
+
+#[async_trait]
+impl CommandExecutor for PoseExecutor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &crate::server::Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+        let entity = target.get_entity();
+
+        entity.set_pose(self.pose).await;
+
+        let bounding_box = entity.bounding_box.load();
+        sender
+            .send_message(TextComponent::text(format!(
+                "Set the pose of {} to {}, its hitbox is now {:.2} x {:.2} x {:.2}",
+                entity.entity_type.resource_name,
+                self.name,
+                bounding_box.max.x - bounding_box.min.x,
+                bounding_box.max.y - bounding_box.min.y,
+                bounding_box.max.z - bounding_box.min.z,
+            )))
+            .await;
+        Ok(())
+    }
+}
+
+pub fn init_command_tree() -> CommandTree {
+    let poses = POSES.iter().fold(
+        argument(ARG_TARGET, EntityArgumentConsumer),
+        |target, &(name, pose)| target.then(literal(name).execute(PoseExecutor { name, pose })),
+    );
+    CommandTree::new(NAMES, DESCRIPTION).then(literal("pose").then(poses))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use pumpkin_data::entity::{EntityPose, EntityType};
+    use pumpkin_util::math::vector3::Vector3;
+
+    use crate::command::{CommandSender, commands::default_dispatcher};
+    use crate::entity::Entity;
+    use crate::server::Server;
+
+    #[tokio::test]
+    async fn swimming_pose_shrinks_the_player() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let entity = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::PLAYER,
+            false,
+        ));
+        world.spawn_entity(entity.clone()).await;
+        let height = |entity: &Entity| {
+            let bounding_box = entity.bounding_box.load();
+            bounding_box.max.y - bounding_box.min.y
+        };
+        assert!((height(&entity) - 1.8).abs() < 1e-6);
+
+        let dispatcher = default_dispatcher().await;
+        let messages = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let command = format!("entity pose {} swimming", entity.entity_uuid);
+        dispatcher
+            .dispatch(
+                &mut CommandSender::Rcon(messages.clone()),
+                &server,
+                &command,
+            )
+            .await
+            .unwrap();
+
+        assert!(entity.pose.load() == EntityPose::Swimming);
+        assert!((height(&entity) - 0.6).abs() < 1e-6);
+        assert_eq!(messages.lock().await.len(), 1);
+    }
+}
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use pumpkin_data::particle::Particle;
+use pumpkin_util::math::{boundingbox::BoundingBox, vector3::Vector3};
+use pumpkin_util::text::TextComponent;
+
+use crate::command::{
+    CommandError, CommandExecutor, CommandSender,
+    args::{ConsumedArgs, FindArg, entity::EntityArgumentConsumer},
+    dispatcher::CommandError::InvalidRequirement,
+    tree::{
+        CommandTree,
+        builder::{argument, require},
+    },
+};
+use crate::entity::Entity;
+
+const NAMES: [&str; 1] = ["hitbox"];
+
+const DESCRIPTION: &str = "Outlines an entity's bounding box with particles, only visible to you.";
+
+const ARG_TARGET: &str = "target";
+
+/// Distance between two particles on an edge.
+const PARTICLE_SPACING: f64 = 0.1;
+
+/// Points along the 12 edges of the entity's bounding box, including all 8 corners.
+fn hitbox_outline(entity: &Entity) -> Vec<Vector3<f64>> {
+    let BoundingBox { min, max } = entity.bounding_box.load();
+    let corners = [
+        Vector3::new(min.x, min.y, min.z),
+        Vector3::new(max.x, min.y, min.z),
+        Vector3::new(max.x, min.y, max.z),
+        Vector3::new(min.x, min.y, max.z),
+        Vector3::new(min.x, max.y, min.z),
+        Vector3::new(max.x, max.y, min.z),
+        Vector3::new(max.x, max.y, max.z),
+        Vector3::new(min.x, max.y, max.z),
+    ];
+    // Bottom face, top face, then the vertical edges
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (4, 5),
+        (5, 6),
+        (6, 7),
+        (7, 4),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+    ];
+
+    let mut points = Vec::new();
+    for (from, to) in edges {
+        let from = corners[from];
+        let edge = corners[to].sub(&from);
+        let steps = (edge.length() / PARTICLE_SPACING).ceil().max(1.0) as usize;
+        // The end corner is the start of another edge
+        points.extend((0..steps).map(|step| from + edge * (step as f64 / steps as f64)));
+    }
+    points
+}
+
+struct Executor;
+
+#[async_trait]
+impl CommandExecutor for Executor {
+    async fn execute<'a>(
+        &self,
+        sender: &mut CommandSender,
+        _server: &crate::server::Server,
+        args: &ConsumedArgs<'a>,
+    ) -> Result<(), CommandError> {
+        let CommandSender::Player(player) = sender else {
+            return Err(InvalidRequirement);
+        };
+        let target = EntityArgumentConsumer::find_arg(args, ARG_TARGET)?;
+
+        let bounding_box = target.get_entity().bounding_box.load();
+        for point in hitbox_outline(target.get_entity()) {
+            player
+                .spawn_particle(point, Vector3::new(0.0, 0.0, 0.0), 0.0, 1, Particle::Flame)
+                .await;
+        }
+
+        sender
+            .send_message(TextComponent::text(format!(
+                "Showing the hitbox of {} from {:?} to {:?}",
+                target.get_entity().entity_type.resource_name,
+                bounding_box.min,
+                bounding_box.max
+            )))
+            .await;
+        Ok(())
+    }
+}
+
+#[allow(clippy::redundant_closure_for_method_calls)]
+pub fn init_command_tree() -> CommandTree {
+    CommandTree::new(NAMES, DESCRIPTION).then(
+        require(|sender| sender.is_player())
+            .then(argument(ARG_TARGET, EntityArgumentConsumer).execute(Executor)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::hitbox_outline;
+    use crate::entity::Entity;
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn outline_follows_the_edges_of_the_hitbox() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let points = hitbox_outline(&zombie);
+
+        let half_width = f64::from(EntityType::ZOMBIE.dimension[0]) / 2.0;
+        let height = f64::from(EntityType::ZOMBIE.dimension[1]);
+        let near = |a: f64, b: f64| (a - b).abs() < 1e-6;
+        let xs = [0.5 - half_width, 0.5 + half_width];
+        let ys = [300.0, 300.0 + height];
+        for x in xs {
+            for y in ys {
+                for z in xs {
+                    assert!(
+                        points
+                            .iter()
+                            .any(|point| near(point.x, x) && near(point.y, y) && near(point.z, z))
+                    );
+                }
+            }
+        }
+
+        // Every point lies on an edge, where at least two coordinates are at a side of the box
+        assert!(points.iter().all(|point| {
+            let sides = [
+                xs.iter().any(|&x| near(point.x, x)),
+                ys.iter().any(|&y| near(point.y, y)),
+                xs.iter().any(|&z| near(point.z, z)),
+            ];
+            sides.iter().filter(|side| **side).count() >= 2
+        }));
+    }
+}
@@ -7,6 +7,7 @@ use crate::PERMISSION_REGISTRY;
 
 use super::dispatcher::CommandDispatcher;
 
+mod attribute;
 mod ban;
 mod banip;
 mod banlist;
@@ -19,12 +20,14 @@ mod deop;
 mod difficulty;
 mod effect;
 mod enchant;
+mod entity;
 mod experience;
 mod fill;
 mod gamemode;
 mod gamerule;
 mod give;
 mod help;
+mod hitbox;
 mod kick;
 mod kill;
 mod list;
@@ -121,6 +124,12 @@ pub async fn default_dispatcher() -> CommandDispatcher {
         "minecraft:command.setworldspawn",
     );
     dispatcher.register(data::init_command_tree(), "minecraft:command.data");
+    dispatcher.register(
+        attribute::init_command_tree(),
+        "minecraft:command.attribute",
+    );
+    dispatcher.register(hitbox::init_command_tree(), "pumpkin:command.hitbox");
+    dispatcher.register(entity::init_command_tree(), "pumpkin:command.entity");
     // Three
     dispatcher.register(op::init_command_tree(), "minecraft:command.op");
     dispatcher.register(deop::init_command_tree(), "minecraft:command.deop");
@@ -386,6 +395,27 @@ fn register_level_2_permissions(registry: &mut PermissionRegistry) {
             PermissionDefault::Op(PermissionLvl::Two),
         ))
         .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "minecraft:command.attribute",
+            "Queries and modifies entity attributes",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.hitbox",
+            "Shows entity bounding boxes for debugging",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
+    registry
+        .register_permission(Permission::new(
+            "pumpkin:command.entity",
+            "Changes entity state like poses for debugging",
+            PermissionDefault::Op(PermissionLvl::Two),
+        ))
+        .unwrap();
     registry
         .register_permission(Permission::new(
             "minecraft:command.enchant",
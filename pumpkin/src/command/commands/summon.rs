@@ -1,6 +1,5 @@
 use async_trait::async_trait;
 use pumpkin_util::{math::vector3::Vector3, text::TextComponent};
-use uuid::Uuid;
 
 use crate::{
     command::{
@@ -11,7 +10,7 @@ use crate::{
         },
         tree::{CommandTree, builder::argument},
     },
-    entity::r#type::from_type,
+    entity::r#type::spawn_from_type,
 };
 const NAMES: [&str; 1] = ["summon"];
 
@@ -58,7 +57,12 @@ impl CommandExecutor for Executor {
                 (player.world().clone(), pos)
             }
         };
-        let mob = from_type(entity, pos, &world, Uuid::new_v4()).await;
+        let mob = spawn_from_type(entity, &world, pos).await.map_err(|_| {
+            CommandError::CommandFailed(Box::new(TextComponent::translate(
+                "commands.summon.failed",
+                [],
+            )))
+        })?;
         world.spawn_entity(mob).await;
 
         sender
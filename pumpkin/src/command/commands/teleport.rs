@@ -77,7 +77,7 @@ impl CommandExecutor for EntitiesToEntityExecutor {
             let world = base_entity.world.clone();
             target
                 .clone()
-                .teleport(pos, yaw.into(), pitch.into(), world)
+                .teleport(pos, yaw.into(), pitch.into(), world, false)
                 .await;
         }
 
@@ -116,7 +116,7 @@ impl CommandExecutor for EntitiesToPosFacingPosExecutor {
         for target in targets {
             target
                 .clone()
-                .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                .teleport(pos, Some(yaw), Some(pitch), world.clone(), false)
                 .await;
         }
 
@@ -153,6 +153,7 @@ impl CommandExecutor for EntitiesToPosFacingEntityExecutor {
                     Some(yaw),
                     Some(pitch),
                     facing_entity.get_entity().world.clone(),
+                    false,
                 )
                 .await;
         }
@@ -186,7 +187,7 @@ impl CommandExecutor for EntitiesToPosWithRotationExecutor {
         for target in targets {
             target
                 .clone()
-                .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                .teleport(pos, Some(yaw), Some(pitch), world.clone(), false)
                 .await;
         }
 
@@ -224,7 +225,7 @@ impl CommandExecutor for EntitiesToPosExecutor {
             let pitch = target.get_entity().pitch.load();
             target
                 .clone()
-                .teleport(pos, Some(yaw), Some(pitch), world.clone())
+                .teleport(pos, Some(yaw), Some(pitch), world.clone(), false)
                 .await;
         }
 
@@ -257,7 +258,7 @@ impl CommandExecutor for SelfToEntityExecutor {
                 }
                 player
                     .clone()
-                    .teleport(pos, Some(yaw), Some(pitch), world)
+                    .teleport(pos, Some(yaw), Some(pitch), world, false)
                     .await;
             }
             _ => {
@@ -292,7 +293,7 @@ impl CommandExecutor for SelfToPosExecutor {
                 }
                 player
                     .clone()
-                    .teleport(pos, Some(yaw), Some(pitch), player.world().clone())
+                    .teleport(pos, Some(yaw), Some(pitch), player.world().clone(), false)
                     .await;
             }
             _ => {
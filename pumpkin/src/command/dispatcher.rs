@@ -267,11 +267,26 @@ impl CommandDispatcher {
         let tree = self.get_tree(key)?;
 
         // try paths until fitting path is found
+        let mut argument_error = None;
         for path in tree.iter_paths() {
-            if Self::try_is_fitting_path(src, server, &path, tree, &mut raw_args.clone()).await? {
+            if Self::try_is_fitting_path(
+                src,
+                server,
+                &path,
+                tree,
+                &mut raw_args.clone(),
+                &mut argument_error,
+            )
+            .await?
+            {
                 return Ok(());
             }
         }
+        // Another path may have accepted what one argument rejected, so this is only reported
+        // when none fits
+        if let Some(error) = argument_error {
+            return Err(error);
+        }
         Err(CommandFailed(Box::new(TextComponent::text(format!(
             "Invalid Syntax. Usage: {tree}"
         )))))
@@ -307,6 +322,7 @@ impl CommandDispatcher {
         path: &[usize],
         tree: &'a CommandTree,
         raw_args: &mut RawArgs<'a>,
+        argument_error: &mut Option<CommandError>,
     ) -> Result<bool, CommandError> {
         let mut parsed_args: ConsumedArgs = HashMap::new();
 
@@ -330,13 +346,20 @@ impl CommandDispatcher {
                     }
                 }
                 NodeType::Argument { consumer, name, .. } => {
-                    if let Some(consumed) = consumer.consume(src, server, raw_args).await {
-                        parsed_args.insert(name, consumed);
-                    } else {
-                        log::debug!(
-                            "Error while parsing command: {raw_args:?}: cannot parse argument {name}"
-                        );
-                        return Ok(false);
+                    match consumer.try_consume(src, server, raw_args).await {
+                        Ok(Some(consumed)) => {
+                            parsed_args.insert(name, consumed);
+                        }
+                        Ok(None) => {
+                            log::debug!(
+                                "Error while parsing command: {raw_args:?}: cannot parse argument {name}"
+                            );
+                            return Ok(false);
+                        }
+                        Err(error) => {
+                            argument_error.get_or_insert(error);
+                            return Ok(false);
+                        }
                     }
                 }
                 NodeType::Require { predicate, .. } => {
@@ -449,4 +472,60 @@ mod test {
         let tree = CommandTree::new(["test"], "test_desc");
         dispatcher.register(tree, "minecraft:test");
     }
+
+    #[tokio::test]
+    async fn rejected_arguments_fail_only_if_no_other_path_fits() {
+        use async_trait::async_trait;
+
+        use super::CommandError;
+        use crate::command::args::{
+            ConsumedArgs, entity::EntityArgumentConsumer, simple::SimpleArgConsumer,
+        };
+        use crate::command::tree::builder::argument;
+        use crate::command::{CommandExecutor, CommandSender};
+        use crate::server::Server;
+
+        struct Executor;
+
+        #[async_trait]
+        impl CommandExecutor for Executor {
+            async fn execute<'a>(
+                &self,
+                _sender: &mut CommandSender,
+                _server: &Server,
+                _args: &ConsumedArgs<'a>,
+            ) -> Result<(), CommandError> {
+                Ok(())
+            }
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let mut dispatcher = default_dispatcher().await;
+        dispatcher.register(
+            CommandTree::new(["single"], "test_desc")
+                .then(argument("target", EntityArgumentConsumer).execute(Executor)),
+            "minecraft:single",
+        );
+        dispatcher.register(
+            CommandTree::new(["either"], "test_desc")
+                .then(argument("target", EntityArgumentConsumer).execute(Executor))
+                .then(argument("text", SimpleArgConsumer).execute(Executor)),
+            "minecraft:either",
+        );
+
+        // `@e` can select more than one entity, which is reported when nothing else fits
+        let result = dispatcher
+            .dispatch(&mut CommandSender::Console, &server, "single @e")
+            .await;
+        assert!(matches!(result, Err(CommandError::CommandFailed(_))));
+
+        // But doesn't stop the other paths from being tried
+        assert!(
+            dispatcher
+                .dispatch(&mut CommandSender::Console, &server, "either @e")
+                .await
+                .is_ok()
+        );
+    }
 }
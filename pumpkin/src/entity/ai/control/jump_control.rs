@@ -0,0 +1,38 @@
+use crate::entity::ai::control::Control;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// Makes a mob jump on its next movement tick. The living entity's jump cooldown keeps it from
+/// bouncing continuously while a jump keeps being requested.
+#[derive(Default)]
+pub struct JumpControl {
+    active: AtomicBool,
+}
+
+impl Control for JumpControl {}
+
+impl JumpControl {
+    pub fn set_active(&self) {
+        self.active.store(true, Relaxed);
+    }
+
+    /// Whether a jump was requested since the last tick, resetting the request.
+    pub fn tick(&self) -> bool {
+        self.active.swap(false, Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::JumpControl;
+
+    #[test]
+    fn jump_request_lasts_one_tick() {
+        let jump_control = JumpControl::default();
+        assert!(!jump_control.tick());
+
+        jump_control.set_active();
+        assert!(jump_control.tick());
+        assert!(!jump_control.tick());
+    }
+}
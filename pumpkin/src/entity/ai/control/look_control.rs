@@ -1,6 +1,6 @@
 use crate::entity::EntityBase;
 use crate::entity::ai::control::Control;
-use crate::entity::mob::{Mob, MobEntity};
+use crate::entity::mob::Mob;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_util::math::clamp_angle;
 use pumpkin_util::math::vector3::Vector3;
@@ -24,9 +24,7 @@ impl LookControl {
     }
 
     pub fn look_at_entity(&self, mob: &dyn Mob, entity: &Arc<dyn EntityBase>) {
-        let entity = entity.get_entity();
-        let pos = entity.pos.load();
-        self.look_at_position(mob, pos);
+        self.look_at_position(mob, entity.get_entity().get_eye_pos());
     }
 
     pub fn look_at_entity_with_range(
@@ -35,8 +33,7 @@ impl LookControl {
         max_yaw_change: f32,
         max_pitch_change: f32,
     ) {
-        let entity = entity.get_entity();
-        let pos = entity.pos.load();
+        let pos = entity.get_entity().get_eye_pos();
         self.look_at_with_range(pos.x, pos.y, pos.z, max_yaw_change, max_pitch_change);
     }
 
@@ -70,22 +67,14 @@ impl LookControl {
             entity.set_pitch(0.0);
         }
 
-        if self.look_at_timer.load(Relaxed) > 0 {
+        let tracking = self.look_at_timer.load(Relaxed) > 0;
+        if tracking {
             self.look_at_timer.fetch_sub(1, Relaxed);
-            if let Some(yaw) = self.get_target_yaw(mob.get_mob_entity()) {
-                entity.head_yaw.store(self.change_angle(
-                    entity.head_yaw.load(),
-                    yaw,
-                    self.max_yaw_change.load(),
-                ));
-            }
-            if let Some(pitch) = self.get_target_pitch(mob.get_mob_entity()) {
-                entity.set_pitch(self.change_angle(
-                    entity.pitch.load(),
-                    pitch,
-                    self.max_pitch_change.load(),
-                ));
-            }
+            entity.look_at_gradually(
+                self.position.load(),
+                self.max_yaw_change.load(),
+                self.max_pitch_change.load(),
+            );
         } else {
             entity.head_yaw.store(self.change_angle(
                 entity.head_yaw.load(),
@@ -94,17 +83,18 @@ impl LookControl {
             ));
         }
 
-        self.clamp_head_yaw(mob).await;
+        self.clamp_head_yaw(mob, tracking).await;
     }
 
     fn should_stay_horizontal() -> bool {
         true
     }
 
-    async fn clamp_head_yaw(&self, mob: &dyn Mob) {
+    /// Keeps the head within `get_max_head_rotation` of the body while walking or looking at
+    /// something, the body then turns to catch up.
+    async fn clamp_head_yaw(&self, mob: &dyn Mob, tracking: bool) {
         let mob_entity = mob.get_mob_entity();
-        let navigator = mob_entity.navigator.lock().await;
-        if !navigator.is_idle() {
+        if tracking || !mob_entity.navigator.lock().await.is_idle() {
             let entity = &mob_entity.living_entity.entity;
             let max_head_rotation = mob.get_max_head_rotation() as f32;
             entity.head_yaw.store(clamp_angle(
@@ -114,30 +104,4 @@ impl LookControl {
             ));
         }
     }
-
-    fn get_target_pitch(&self, mob: &MobEntity) -> Option<f32> {
-        let position = self.position.load();
-        let mob_position = mob.living_entity.entity.pos.load();
-        let d = position.x - mob_position.x;
-        let e = position.y - mob.living_entity.entity.get_eye_y();
-        let f = position.z - mob_position.z;
-        let g = (d * d + f * f).sqrt();
-        if e.abs() <= 1.0E-5 && g.abs() <= 1.0E-5 {
-            None
-        } else {
-            Some(-(e.atan2(g) as f32 * 180.0 / std::f32::consts::PI))
-        }
-    }
-
-    fn get_target_yaw(&self, mob: &MobEntity) -> Option<f32> {
-        let position = self.position.load();
-        let mob_position = mob.living_entity.entity.pos.load();
-        let d = position.x - mob_position.x;
-        let e = position.z - mob_position.z;
-        if e.abs() <= 1.0E-5 && d.abs() <= 1.0E-5 {
-            None
-        } else {
-            Some(e.atan2(d) as f32 * 180.0 / std::f32::consts::PI - 90.0)
-        }
-    }
 }
@@ -1,5 +1,6 @@
 use pumpkin_util::math::subtract_angles;
 
+pub mod jump_control;
 pub mod look_control;
 
 pub trait Control: Send + Sync {
@@ -40,6 +40,9 @@ impl ActiveTargetGoal {
         track_target_goal.set_controls(&[Control::Target]).await;
         let mut target_predicate = TargetPredicate::attackable();
         target_predicate.base_max_distance = TrackTargetGoal::get_follow_range(mob);
+        if !check_visibility {
+            target_predicate.ignore_visibility();
+        }
         if let Some(predicate) = predicate {
             target_predicate.set_predicate(predicate);
         }
@@ -62,6 +65,9 @@ impl ActiveTargetGoal {
         track_target_goal.set_controls(&[Control::Target]).await;
         let mut target_predicate = TargetPredicate::attackable();
         target_predicate.base_max_distance = TrackTargetGoal::get_follow_range(mob);
+        if !check_visibility {
+            target_predicate.ignore_visibility();
+        }
         Self {
             track_target_goal,
             target: Mutex::new(None),
@@ -71,9 +77,12 @@ impl ActiveTargetGoal {
         }
     }
 
+    /// Finds the closest target in the follow range, which also has to be in sight unless
+    /// visibility is ignored
     async fn find_closest_target(&self, mob: &MobEntity) {
         let mut target = self.target.lock().await;
-        let world = &mob.living_entity.entity.world;
+        let entity = &mob.living_entity.entity;
+        let world = &entity.world;
         if self.target_type == &EntityType::PLAYER {
             *target = world
                 .get_closest_player(
@@ -91,6 +100,12 @@ impl ActiveTargetGoal {
                 )
                 .await;
         }
+        if self.target_predicate.respects_visibility
+            && let Some(found) = target.as_ref()
+            && !entity.can_see(found.get_entity()).await
+        {
+            *target = None;
+        }
     }
 }
 
@@ -128,3 +143,65 @@ impl Goal for ActiveTargetGoal {
         self.track_target_goal.get_goal_control()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::Block;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_world::world::BlockFlags;
+    use std::sync::Arc;
+
+    use super::ActiveTargetGoal;
+    use crate::entity::ai::goal::Goal;
+    use crate::entity::mob::{Mob, zombie::Zombie};
+    use crate::entity::{Entity, EntityBase};
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn targets_behind_walls_are_not_noticed() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // High up in the air, away from any terrain
+        let new_entity = |x, entity_type| {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.0, 0.5),
+                entity_type,
+                false,
+            )
+        };
+        let zombie = Zombie::make(new_entity(0.5, &EntityType::ZOMBIE)).await;
+        let pig: Arc<dyn EntityBase> = Arc::new(new_entity(10.5, &EntityType::PIG));
+        world.spawn_entity(pig.clone()).await;
+        let goal = ActiveTargetGoal {
+            // Look every time
+            reciprocal_chance: 0,
+            ..ActiveTargetGoal::with_default(zombie.get_mob_entity(), &EntityType::PIG, true).await
+        };
+        assert!(goal.can_start(zombie.as_ref()).await);
+        let target = goal.target.lock().await.clone().unwrap();
+        assert!(Arc::ptr_eq(&target, &pig));
+
+        for y in 300..=301 {
+            world
+                .set_block_state(
+                    &BlockPos::new(5, y, 0),
+                    Block::STONE.default_state.id,
+                    BlockFlags::FORCE_STATE,
+                )
+                .await;
+        }
+        assert!(!goal.can_start(zombie.as_ref()).await);
+        assert!(goal.target.lock().await.is_none());
+
+        // Mobs that don't need to see their target find it anyway
+        let goal = ActiveTargetGoal {
+            reciprocal_chance: 0,
+            ..ActiveTargetGoal::with_default(zombie.get_mob_entity(), &EntityType::PIG, false).await
+        };
+        assert!(goal.can_start(zombie.as_ref()).await);
+    }
+}
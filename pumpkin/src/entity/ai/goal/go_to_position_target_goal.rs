@@ -0,0 +1,137 @@
+use super::{Control, Goal, GoalControl};
+use crate::entity::ai::path::NavigatorGoal;
+use crate::entity::mob::Mob;
+use async_trait::async_trait;
+use pumpkin_util::math::vector3::Vector3;
+
+/// The furthest a mob walks back towards its position target at once
+const MAX_STEP_DISTANCE: f64 = 16.0;
+
+/// Walks mobs that strayed out of their position target range back towards its center, like
+/// vanilla's `GoToWalkTargetGoal`, e.g. iron golems staying around their village.
+pub struct GoToPositionTargetGoal {
+    goal_control: GoalControl,
+    speed: f64,
+}
+
+impl GoToPositionTargetGoal {
+    #[must_use]
+    pub fn new(speed: f64) -> Self {
+        Self {
+            goal_control: GoalControl::from_array(&[Control::Move]),
+            speed,
+        }
+    }
+
+    /// Goes at most [`MAX_STEP_DISTANCE`] blocks from `from` towards `center`
+    #[must_use]
+    pub fn step_towards(from: Vector3<f64>, center: Vector3<f64>) -> Vector3<f64> {
+        let offset = center.sub(&from);
+        let distance = offset.length();
+        if distance <= MAX_STEP_DISTANCE {
+            center
+        } else {
+            from.add(&offset.multiply(
+                MAX_STEP_DISTANCE / distance,
+                MAX_STEP_DISTANCE / distance,
+                MAX_STEP_DISTANCE / distance,
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl Goal for GoToPositionTargetGoal {
+    async fn can_start(&self, mob: &dyn Mob) -> bool {
+        !mob.get_mob_entity().is_in_position_target_range()
+    }
+
+    async fn should_continue(&self, mob: &dyn Mob) -> bool {
+        !mob.get_mob_entity().navigator.lock().await.is_idle()
+    }
+
+    async fn start(&self, mob: &dyn Mob) {
+        let mob_entity = mob.get_mob_entity();
+        let target = mob_entity.position_target.load().0;
+        let center = Vector3::new(
+            f64::from(target.x) + 0.5,
+            f64::from(target.y),
+            f64::from(target.z) + 0.5,
+        );
+        let pos = mob.get_entity().pos.load();
+        mob_entity
+            .navigator
+            .lock()
+            .await
+            .set_progress(NavigatorGoal {
+                current_progress: pos,
+                destination: Self::step_towards(pos, center),
+                speed: self.speed,
+            });
+    }
+
+    async fn stop(&self, mob: &dyn Mob) {
+        mob.get_mob_entity().navigator.lock().await.cancel();
+    }
+
+    async fn tick(&self, _mob: &dyn Mob) {}
+
+    fn get_goal_control(&self) -> &GoalControl {
+        &self.goal_control
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::GoToPositionTargetGoal;
+    use crate::entity::Entity;
+    use crate::entity::ai::goal::Goal;
+    use crate::entity::mob::{Mob, zombie::Zombie};
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn strayed_mob_walks_back_to_its_position_target() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(40.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let zombie = Zombie::make(entity).await;
+        let mob_entity = zombie.get_mob_entity();
+        let goal = GoToPositionTargetGoal::new(1.0);
+
+        // Without a position target it roams freely
+        assert!(!goal.can_start(zombie.as_ref()).await);
+
+        mob_entity.set_position_target(BlockPos::new(0, 64, 0), 8);
+        assert!(goal.can_start(zombie.as_ref()).await);
+        goal.start(zombie.as_ref()).await;
+        assert!(goal.should_continue(zombie.as_ref()).await);
+        // Heads 16 blocks straight back towards the center
+        let destination = mob_entity.navigator.lock().await.destination().unwrap();
+        assert!((destination.x - 24.5).abs() < 1e-6);
+        assert!((destination.z - 0.5).abs() < 1e-6);
+
+        // Close by, it heads right to the center
+        let center = Vector3::new(0.5, 64.0, 0.5);
+        let from = Vector3::new(10.5, 64.0, 0.5);
+        assert_eq!(GoToPositionTargetGoal::step_towards(from, center), center);
+
+        // Back in range, it has no reason to walk back
+        goal.stop(zombie.as_ref()).await;
+        mob_entity
+            .living_entity
+            .entity
+            .set_pos(Vector3::new(3.5, 64.0, 0.5));
+        assert!(!goal.can_start(zombie.as_ref()).await);
+        assert!(!goal.should_continue(zombie.as_ref()).await);
+    }
+}
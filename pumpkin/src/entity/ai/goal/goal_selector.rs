@@ -42,6 +42,10 @@ impl GoalSelector {
         goals.push(Arc::new(PrioritizedGoal::new(priority, goal)));
     }
 
+    pub async fn is_empty(&self) -> bool {
+        self.goals.lock().await.is_empty()
+    }
+
     pub async fn remove_goal(&self, goal: Arc<dyn Goal>, mob: &dyn Mob) {
         let mut goals = self.goals.lock().await;
         for prioritized_goal in goals.iter() {
@@ -140,12 +140,19 @@ impl Goal for LookAtEntityGoal {
     }
 
     async fn tick(&self, mob: &dyn Mob) {
-        let mob = mob.get_mob_entity();
         if let Some(target) = self.target.lock().await.as_ref()
             && target.get_entity().is_alive()
         {
-            let target_pos = target.get_entity().pos.load();
-            mob.living_entity.entity.look_at(target_pos).await;
+            let look_control = mob.get_mob_entity().look_control.lock().await;
+            if self.look_forward {
+                // Looking forward only turns the head, it stays level
+                let target_pos = target.get_entity().pos.load();
+                let y = mob.get_entity().get_eye_y();
+                look_control.look_at(mob, target_pos.x, y, target_pos.z);
+            } else {
+                look_control.look_at_entity(mob, target);
+            }
+            drop(look_control);
             self.look_time.fetch_sub(1, Relaxed);
         }
     }
@@ -154,3 +161,62 @@ impl Goal for LookAtEntityGoal {
         &self.goal_control
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Weak};
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::{subtract_angles, vector3::Vector3};
+
+    use super::LookAtEntityGoal;
+    use crate::entity::ai::goal::Goal;
+    use crate::entity::mob::{Mob, zombie::Zombie};
+    use crate::entity::{Entity, EntityBase};
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn idle_mob_turns_its_head_towards_a_nearby_entity() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 300.0, 0.5);
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            pos,
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        // In front of the zombie, a bit to the side
+        let pig = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(-2.5, 300.0, 4.5),
+            &EntityType::PIG,
+            false,
+        ));
+        world.spawn_entity(pig.clone() as Arc<dyn EntityBase>).await;
+
+        let mob_weak: Weak<dyn Mob> = {
+            let mob: Arc<dyn Mob> = zombie.clone();
+            Arc::downgrade(&mob)
+        };
+        let goal = LookAtEntityGoal::new(mob_weak, &EntityType::PIG, 8.0, 1.0, false);
+        assert!(goal.can_start(zombie.as_ref()).await);
+        goal.start(zombie.as_ref()).await;
+        let mob_entity = zombie.get_mob_entity();
+        for _ in 0..20 {
+            goal.tick(zombie.as_ref()).await;
+            let look_control = mob_entity.look_control.lock().await;
+            look_control.tick(zombie.as_ref()).await;
+        }
+
+        let entity = &mob_entity.living_entity.entity;
+        let wanted = Entity::rotation_towards(entity.get_eye_pos(), pig.get_eye_pos());
+        assert!(subtract_angles(entity.head_yaw.load(), wanted.0).abs() < 1e-3);
+        // Only the head turned, the zombie stayed where it was
+        assert!(entity.body_yaw.load().abs() < 1e-4);
+        assert_eq!(entity.pos.load(), pos);
+    }
+}
@@ -8,12 +8,14 @@ use tokio::sync::RwLock;
 
 pub mod active_target_goal;
 pub mod ambient_stand_goal;
+pub mod go_to_position_target_goal;
 pub mod goal_selector;
 pub mod look_around_goal;
 pub mod look_at_entity;
 mod melee_attack_goal;
 pub mod move_to_target_pos_goal;
 pub mod step_and_destroy_block_goal;
+pub mod swim_around_goal;
 mod track_target_goal;
 pub mod zombie_attack_goal;
 
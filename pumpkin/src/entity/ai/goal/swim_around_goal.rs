@@ -0,0 +1,86 @@
+use super::{Control, Goal, GoalControl};
+use crate::entity::ai::path::NavigatorGoal;
+use crate::entity::mob::Mob;
+use async_trait::async_trait;
+use pumpkin_data::fluid::Fluid;
+use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use rand::Rng;
+
+/// Tries this many random spots before giving up on finding water to swim to
+const MAX_TARGET_ATTEMPTS: u32 = 10;
+
+/// Swims to random spots in the water nearby, like vanilla's `SwimAroundGoal`
+pub struct SwimAroundGoal {
+    goal_control: GoalControl,
+    speed: f64,
+    /// A new swim starts with a chance of one in this many goal updates
+    chance: u32,
+}
+
+impl SwimAroundGoal {
+    #[must_use]
+    pub fn new(speed: f64, chance: u32) -> Self {
+        Self {
+            goal_control: GoalControl::from_array(&[Control::Move]),
+            speed,
+            chance: chance.max(1),
+        }
+    }
+
+    /// Picks a random water block up to 10 blocks away horizontally and 7 vertically
+    async fn find_target(mob: &dyn Mob) -> Option<Vector3<f64>> {
+        let entity = mob.get_entity();
+        let pos = entity.block_pos.load();
+        for _ in 0..MAX_TARGET_ATTEMPTS {
+            let (x, y, z) = {
+                let mut random = mob.get_random();
+                (
+                    random.random_range(-10..=10),
+                    random.random_range(-7..=7),
+                    random.random_range(-10..=10),
+                )
+            };
+            let target = BlockPos::new(pos.0.x + x, pos.0.y + y, pos.0.z + z);
+            let fluid = entity.world.get_fluid(&target).await;
+            if fluid.id == Fluid::WATER.id || fluid.id == Fluid::FLOWING_WATER.id {
+                return Some(target.to_centered_f64());
+            }
+        }
+        None
+    }
+}
+
+#[async_trait]
+impl Goal for SwimAroundGoal {
+    async fn can_start(&self, mob: &dyn Mob) -> bool {
+        mob.get_mob_entity().navigator.lock().await.is_idle()
+            && mob.get_random().random_range(0..self.chance) == 0
+    }
+
+    async fn should_continue(&self, mob: &dyn Mob) -> bool {
+        !mob.get_mob_entity().navigator.lock().await.is_idle()
+    }
+
+    async fn start(&self, mob: &dyn Mob) {
+        let Some(destination) = Self::find_target(mob).await else {
+            return;
+        };
+        let mut navigator = mob.get_mob_entity().navigator.lock().await;
+        navigator.set_progress(NavigatorGoal {
+            current_progress: mob.get_entity().pos.load(),
+            destination,
+            speed: self.speed,
+        });
+    }
+
+    async fn stop(&self, mob: &dyn Mob) {
+        mob.get_mob_entity().navigator.lock().await.cancel();
+    }
+
+    async fn tick(&self, _mob: &dyn Mob) {}
+
+    fn get_goal_control(&self) -> &GoalControl {
+        &self.goal_control
+    }
+}
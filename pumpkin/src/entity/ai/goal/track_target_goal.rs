@@ -1,9 +1,11 @@
 use super::{Goal, GoalControl, to_goal_ticks};
 use crate::entity::ai::target_predicate::TargetPredicate;
+use crate::entity::attributes::AttributeManager;
 use crate::entity::living::LivingEntity;
 use crate::entity::mob::Mob;
 use crate::entity::{EntityBase, mob::MobEntity};
 use async_trait::async_trait;
+use pumpkin_data::attributes::Attributes;
 use rand::Rng;
 use std::sync::Arc;
 use std::sync::atomic::AtomicI32;
@@ -48,9 +50,15 @@ impl TrackTargetGoal {
         Self::new(check_visibility, false)
     }
 
-    // TODO: get from entity attribute
-    pub fn get_follow_range(_mob: &MobEntity) -> f64 {
-        1.0
+    /// The distance in which the mob finds and keeps following targets
+    pub fn get_follow_range(mob: &MobEntity) -> f64 {
+        Self::follow_range(&mob.living_entity.attribute_manager)
+    }
+
+    /// The follow range attribute with its modifiers applied
+    #[must_use]
+    pub fn follow_range(attributes: &AttributeManager) -> f64 {
+        attributes.get_modified(&Attributes::FOLLOW_RANGE, [])
     }
 
     pub fn set_max_time_without_visibility(&self, time: i32) {
@@ -149,3 +157,30 @@ impl Goal for TrackTargetGoal {
         &self.goal_control
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::attributes::Attributes;
+    use pumpkin_data::data_component_impl::Operation;
+    use pumpkin_data::entity::EntityType;
+
+    use super::TrackTargetGoal;
+    use crate::entity::attributes::{AttributeManager, AttributeModifier};
+
+    #[test]
+    fn follow_range_modifiers_extend_target_search() {
+        let attributes = AttributeManager::new(&EntityType::ZOMBIE);
+        let target_distance = 20.0;
+        assert!(TrackTargetGoal::follow_range(&attributes) < target_distance);
+
+        assert!(attributes.add_modifier(
+            &Attributes::FOLLOW_RANGE,
+            AttributeModifier {
+                id: "minecraft:test".to_string(),
+                amount: 1.0,
+                operation: Operation::AddMultipliedBase,
+            },
+        ));
+        assert!(TrackTargetGoal::follow_range(&attributes) >= target_distance);
+    }
+}
@@ -1,10 +1,29 @@
+use pumpkin_data::fluid::Fluid;
 use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
 
+use crate::entity::ai::control::jump_control::JumpControl;
 use crate::entity::living::LivingEntity;
+use crate::world::World;
+use std::sync::atomic::Ordering::SeqCst;
+
+/// Ticks a chosen move is followed before the neighbouring nodes are searched again
+pub const MIN_RECOMPUTE_INTERVAL: u32 = 4;
+/// Ticks between two checks whether the mob made progress
+pub const STUCK_CHECK_INTERVAL: u32 = 100;
+/// A mob that moved less than this squared distance since the last check is stuck
+pub const STUCK_DISTANCE_SQUARED: f64 = 2.25;
 
 #[derive(Default)]
 pub struct Navigator {
     current_goal: Option<NavigatorGoal>,
+    /// Ticks this navigator has been ticked
+    age: u32,
+    /// The last chosen move and when it was chosen
+    cached_move: Option<(Vector3<f64>, u32)>,
+    /// Position of the mob at the last stuck check and when it was taken
+    last_progress_check: Option<(Vector3<f64>, u32)>,
+    /// Swimming navigators move through water in all directions instead of walking on blocks
+    swimming: bool,
 }
 
 pub struct NavigatorGoal {
@@ -14,15 +33,65 @@ pub struct NavigatorGoal {
 }
 
 impl Navigator {
+    /// A navigator for aquatic mobs, like vanilla's `SwimNavigation`
+    #[must_use]
+    pub fn swimming() -> Self {
+        Self {
+            swimming: true,
+            ..Self::default()
+        }
+    }
+
+    /// Navigates towards a new goal. Updating a running goal keeps its progress and stuck tracking,
+    /// the last move is only kept while the destination stays the same.
     pub fn set_progress(&mut self, goal: NavigatorGoal) {
+        match &self.current_goal {
+            None => {
+                self.cached_move = None;
+                self.last_progress_check = None;
+            }
+            Some(current) if current.destination != goal.destination => self.cached_move = None,
+            Some(_) => {}
+        }
         self.current_goal = Some(goal);
     }
 
     pub fn cancel(&mut self) {
         self.current_goal = None;
+        self.cached_move = None;
+        self.last_progress_check = None;
+    }
+
+    /// Ages the navigator and gives up on the goal when the mob hasn't moved far enough within
+    /// [`STUCK_CHECK_INTERVAL`] ticks. Returns whether the navigator is still navigating.
+    fn tick_timeouts(&mut self, pos: Vector3<f64>) -> bool {
+        self.age = self.age.wrapping_add(1);
+        if self.current_goal.is_none() {
+            return false;
+        }
+        let (last_pos, last_check) = *self.last_progress_check.get_or_insert((pos, self.age));
+        if self.age.wrapping_sub(last_check) >= STUCK_CHECK_INTERVAL {
+            if pos.squared_distance_to_vec(last_pos) < STUCK_DISTANCE_SQUARED {
+                self.cancel();
+                return false;
+            }
+            self.last_progress_check = Some((pos, self.age));
+        }
+        true
+    }
+
+    /// Whether the cached move is too old to be followed any further.
+    fn should_recompute(&self) -> bool {
+        self.cached_move
+            .is_none_or(|(_, chosen)| self.age.wrapping_sub(chosen) >= MIN_RECOMPUTE_INTERVAL)
     }
 
-    pub async fn tick(&mut self, entity: &LivingEntity) {
+    pub async fn tick(&mut self, entity: &LivingEntity, jump_control: &JumpControl) {
+        if !self.tick_timeouts(entity.entity.pos.load()) {
+            return;
+        }
+        let recompute = self.should_recompute();
+        let age = self.age;
         if let Some(goal) = &mut self.current_goal {
             // First, let's check if we have reached the destination
             if goal.current_progress == goal.destination {
@@ -31,27 +100,80 @@ impl Navigator {
                 return;
             }
 
-            // A star algorithm
-            let mut best_move = Vector3::new(0.0, 0.0, 0.0);
-            let mut lowest_cost = f64::MAX;
+            // Follow the entity vertically, so jumping and falling are not undone
+            if !self.swimming {
+                goal.current_progress.y = entity.entity.pos.load().y;
+            }
 
             let world = &entity.entity.world;
 
-            for x in -1..=1 {
+            // Keep following the last move while it is fresh and still walkable
+            let best_move = if let Some((cached_move, _)) = self.cached_move
+                && !recompute
+                && Self::is_passable(world, goal.current_progress + cached_move, self.swimming)
+                    .await
+            {
+                cached_move
+            } else {
+                let best_move = Self::find_best_move(world, goal, self.swimming).await;
+                // Jumps depend on where the entity is each tick, so only level moves are kept
+                self.cached_move =
+                    (self.swimming || best_move.y <= 0.0).then_some((best_move, age));
+                best_move
+            };
+
+            if !self.swimming {
+                if Self::needs_jump(best_move, entity.entity.on_ground.load(SeqCst)) {
+                    jump_control.set_active();
+                }
+                // Wait against the ledge until the jump carries the entity above it
+                if best_move.y > 0.0 {
+                    return;
+                }
+            }
+
+            // This is important. Firstly, this saves us many packets when we don't actually move. Secondly, this prevents division using zero
+            // when normalize
+            if best_move.length_squared() == 0.0 {
+                return;
+            }
+
+            // Update current progress based on the best move
+            goal.current_progress += best_move.normalize() * goal.speed;
+
+            // Now let's move
+            entity.entity.set_pos(goal.current_progress);
+            entity.entity.send_pos().await;
+        }
+    }
+
+    /// Searches the neighbouring nodes for the cheapest move towards the destination. Walkers step
+    /// up one block ledges, swimmers also move up and down through the water.
+    async fn find_best_move(world: &World, goal: &NavigatorGoal, swimming: bool) -> Vector3<f64> {
+        // A star algorithm
+        let mut best_move = Vector3::new(0.0, 0.0, 0.0);
+        let mut lowest_cost = f64::MAX;
+        let heights = if swimming { -1..=1 } else { 0..=0 };
+
+        for x in -1..=1 {
+            for y in heights.clone() {
                 for z in -1..=1 {
                     let x = f64::from(x);
                     let z = f64::from(z);
-                    let potential_pos = Vector3::new(
+                    let mut potential_pos = Vector3::new(
                         goal.current_progress.x + x,
-                        goal.current_progress.y,
+                        goal.current_progress.y + f64::from(y),
                         goal.current_progress.z + z,
                     );
-                    let state = world
-                        .get_block_state(&BlockPos(potential_pos.to_i32()))
-                        .await;
-                    let shapes = state.get_block_collision_shapes();
-                    if !shapes.is_empty() {
-                        continue;
+                    if !Self::is_passable(world, potential_pos, swimming).await {
+                        if swimming {
+                            continue;
+                        }
+                        // A one block ledge can be jumped onto
+                        potential_pos.y += 1.0;
+                        if !Self::is_passable(world, potential_pos, swimming).await {
+                            continue;
+                        }
                     }
 
                     let node = Node::new(potential_pos);
@@ -59,30 +181,50 @@ impl Navigator {
 
                     if cost < lowest_cost {
                         lowest_cost = cost;
-                        best_move = Vector3::new(x, 0.0, z);
+                        best_move = potential_pos.sub(&goal.current_progress);
                     }
                 }
             }
+        }
+        best_move
+    }
 
-            // This is important. Firstly, this saves us many packets when we don't actually move. Secondly, this prevents division using zero
-            // when normalize
-            if best_move.x == 0.0 && best_move.z == 0.0 {
-                return;
-            }
+    /// Walkers pass through blocks without collision, swimmers only through water.
+    async fn is_passable(world: &World, pos: Vector3<f64>, swimming: bool) -> bool {
+        let pos = BlockPos(pos.to_i32());
+        if swimming {
+            return Self::is_water(world.get_fluid(&pos).await)
+                && world
+                    .get_block_state(&pos)
+                    .await
+                    .get_block_collision_shapes()
+                    .is_empty();
+        }
+        world
+            .get_block_state(&pos)
+            .await
+            .get_block_collision_shapes()
+            .is_empty()
+    }
 
-            // Update current progress based on the best move
-            goal.current_progress += best_move.normalize() * goal.speed;
+    fn is_water(fluid: &Fluid) -> bool {
+        fluid.id == Fluid::WATER.id || fluid.id == Fluid::FLOWING_WATER.id
+    }
 
-            // Now let's move
-            entity.entity.set_pos(goal.current_progress);
-            entity.entity.send_pos().await;
-        }
+    /// Jumping is only possible from the ground and needed when the next node is higher.
+    fn needs_jump(best_move: Vector3<f64>, on_ground: bool) -> bool {
+        on_ground && best_move.y > 0.0
     }
 
     #[must_use]
     pub fn is_idle(&self) -> bool {
-        // TODO: implement
-        false
+        self.current_goal.is_none()
+    }
+
+    /// Where the mob is currently heading, if anywhere
+    #[must_use]
+    pub fn destination(&self) -> Option<Vector3<f64>> {
+        self.current_goal.as_ref().map(|goal| goal.destination)
     }
 }
 
@@ -103,3 +245,79 @@ impl Node {
         self.location.squared_distance_to_vec(end).sqrt()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::{Navigator, NavigatorGoal, STUCK_CHECK_INTERVAL};
+
+    #[test]
+    fn jumps_only_onto_higher_nodes_from_the_ground() {
+        let step_up = Vector3::new(1.0, 1.0, 0.0);
+        assert!(Navigator::needs_jump(step_up, true));
+        assert!(!Navigator::needs_jump(step_up, false));
+
+        let level = Vector3::new(1.0, 0.0, 1.0);
+        assert!(!Navigator::needs_jump(level, true));
+    }
+
+    #[test]
+    fn blocked_mob_goes_idle_after_stuck_timeout() {
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let mut navigator = Navigator::default();
+        navigator.set_progress(NavigatorGoal {
+            current_progress: pos,
+            destination: Vector3::new(20.5, 64.0, 0.5),
+            speed: 0.2,
+        });
+
+        for _ in 0..STUCK_CHECK_INTERVAL {
+            assert!(navigator.tick_timeouts(pos));
+            // Goals set while navigating don't reset the stuck timeout
+            navigator.set_progress(NavigatorGoal {
+                current_progress: pos,
+                destination: Vector3::new(20.5, 64.0, 0.5),
+                speed: 0.2,
+            });
+        }
+        assert!(!navigator.tick_timeouts(pos));
+        assert!(navigator.is_idle());
+    }
+
+    #[test]
+    fn moving_mob_keeps_navigating() {
+        let mut pos = Vector3::new(0.5, 64.0, 0.5);
+        let mut navigator = Navigator::default();
+        navigator.set_progress(NavigatorGoal {
+            current_progress: pos,
+            destination: Vector3::new(100.5, 64.0, 0.5),
+            speed: 0.2,
+        });
+        for _ in 0..STUCK_CHECK_INTERVAL * 3 {
+            pos.x += 0.2;
+            assert!(navigator.tick_timeouts(pos));
+        }
+        assert!(!navigator.is_idle());
+    }
+
+    #[test]
+    fn new_destinations_drop_the_last_move() {
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let goal = |destination| NavigatorGoal {
+            current_progress: pos,
+            destination,
+            speed: 0.2,
+        };
+        let mut navigator = Navigator::default();
+        navigator.set_progress(goal(Vector3::new(20.5, 64.0, 0.5)));
+        navigator.cached_move = Some((Vector3::new(1.0, 0.0, 0.0), 0));
+
+        navigator.set_progress(goal(Vector3::new(20.5, 64.0, 0.5)));
+        assert!(navigator.cached_move.is_some());
+
+        // Turning around, the move towards the old destination leads the wrong way
+        navigator.set_progress(goal(Vector3::new(-20.5, 64.0, 0.5)));
+        assert!(navigator.cached_move.is_none());
+    }
+}
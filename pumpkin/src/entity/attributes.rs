@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crossbeam::atomic::AtomicCell;
+use pumpkin_data::AttributeModifierSlot;
+use pumpkin_data::attributes::Attributes;
+use pumpkin_data::data_component_impl::{EquipmentSlot, Modifier, Operation};
+use pumpkin_data::effect::StatusEffect;
+use pumpkin_data::entity::EntityType;
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_nbt::tag::NbtTag;
+
+/// A modifier added to an entity's attribute, e.g. by the `/attribute` command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttributeModifier {
+    /// Identifies the modifier, e.g. `minecraft:sprinting`
+    pub id: String,
+    pub amount: f64,
+    pub operation: Operation,
+}
+
+impl AttributeModifier {
+    fn write_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+        nbt.put_string("id", self.id.clone());
+        nbt.put_double("amount", self.amount);
+        nbt.put_string("operation", operation_name(self.operation).to_string());
+        nbt
+    }
+
+    fn read_nbt(nbt: &NbtCompound) -> Option<Self> {
+        Some(Self {
+            id: nbt.get_string("id")?.to_string(),
+            amount: nbt.get_double("amount")?,
+            operation: operation_from_name(nbt.get_string("operation")?)?,
+        })
+    }
+}
+
+pub const fn operation_name(operation: Operation) -> &'static str {
+    match operation {
+        Operation::AddValue => "add_value",
+        Operation::AddMultipliedBase => "add_multiplied_base",
+        Operation::AddMultipliedTotal => "add_multiplied_total",
+    }
+}
+
+fn operation_from_name(name: &str) -> Option<Operation> {
+    match name {
+        "add_value" => Some(Operation::AddValue),
+        "add_multiplied_base" => Some(Operation::AddMultipliedBase),
+        "add_multiplied_total" => Some(Operation::AddMultipliedTotal),
+        _ => None,
+    }
+}
+
+/// Holds the base values and modifiers of all attributes an entity has (e.g. max health,
+/// attack speed).
+///
+/// Which attributes an entity has is decided once when it is created, see
+/// [`AttributeManager::new`].
+pub struct AttributeManager {
+    base_values: HashMap<&'static Attributes, AtomicCell<f64>>,
+    modifiers: HashMap<&'static Attributes, Mutex<Vec<AttributeModifier>>>,
+}
+
+impl AttributeManager {
+    /// Creates the attributes for an entity of the given type.
+    #[must_use]
+    pub fn new(entity_type: &'static EntityType) -> Self {
+        let builder = if entity_type == &EntityType::PLAYER {
+            player_attribute_builder()
+        } else if entity_type.mob {
+            mob_entity_attribute_builder()
+        } else {
+            living_entity_attribute_builder()
+        };
+
+        let builder = match entity_type.max_health {
+            Some(max_health) => {
+                builder.add_attribute_with_value(&Attributes::MAX_HEALTH, max_health.into())
+            }
+            None => builder,
+        };
+
+        builder.build()
+    }
+
+    #[must_use]
+    pub fn has_attribute(&self, attribute: &'static Attributes) -> bool {
+        self.base_values.contains_key(attribute)
+    }
+
+    /// Returns the base value of the attribute, or its default value if the entity doesn't have it.
+    #[must_use]
+    pub fn get_base(&self, attribute: &'static Attributes) -> f64 {
+        self.base_values
+            .get(attribute)
+            .map_or(attribute.default_value, AtomicCell::load)
+    }
+
+    /// Sets the base value of the attribute, clamped to the attribute's range. Returns `false` if
+    /// the entity doesn't have it.
+    #[must_use]
+    pub fn set_base(&self, attribute: &'static Attributes, value: f64) -> bool {
+        let Some(base) = self.base_values.get(attribute) else {
+            return false;
+        };
+        let clamped = attribute.clamp(value);
+        if !(attribute.get_min()..=attribute.get_max()).contains(&value) {
+            log::warn!(
+                "Clamped {} base value {value} to {clamped}",
+                attribute.minecraft_name
+            );
+        }
+        base.store(clamped);
+        true
+    }
+
+    /// Returns the value of the attribute with the added modifiers and the given
+    /// `(amount, operation)` modifiers, e.g. of equipment and effects, applied together phase by
+    /// phase.
+    #[must_use]
+    pub fn get_modified(
+        &self,
+        attribute: &'static Attributes,
+        modifiers: impl IntoIterator<Item = (f64, Operation)>,
+    ) -> f64 {
+        let added = self
+            .get_modifiers(attribute)
+            .into_iter()
+            .map(|modifier| (modifier.amount, modifier.operation));
+        attribute.clamp(apply_modifiers(
+            self.get_base(attribute),
+            added.chain(modifiers),
+        ))
+    }
+
+    /// Returns the modifiers added to the attribute.
+    #[must_use]
+    pub fn get_modifiers(&self, attribute: &'static Attributes) -> Vec<AttributeModifier> {
+        self.modifiers
+            .get(attribute)
+            .map(|modifiers| modifiers.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+
+    /// Adds a modifier to the attribute, replacing the modifier with the same id.
+    /// Returns `false` if the entity doesn't have the attribute.
+    #[must_use]
+    pub fn add_modifier(
+        &self,
+        attribute: &'static Attributes,
+        modifier: AttributeModifier,
+    ) -> bool {
+        let Some(modifiers) = self.modifiers.get(attribute) else {
+            return false;
+        };
+        let mut modifiers = modifiers.lock().unwrap();
+        modifiers.retain(|other| other.id != modifier.id);
+        modifiers.push(modifier);
+        true
+    }
+
+    /// Removes the modifier with the given id. Returns `false` if there was none.
+    #[must_use]
+    pub fn remove_modifier(&self, attribute: &'static Attributes, id: &str) -> bool {
+        let Some(modifiers) = self.modifiers.get(attribute) else {
+            return false;
+        };
+        let mut modifiers = modifiers.lock().unwrap();
+        let len = modifiers.len();
+        modifiers.retain(|modifier| modifier.id != id);
+        modifiers.len() != len
+    }
+
+    /// Writes the `attributes` list of an entity, containing the base value and modifiers of
+    /// every attribute.
+    pub fn write_nbt(&self, nbt: &mut NbtCompound) {
+        let mut attributes: Vec<_> = self.base_values.iter().collect();
+        attributes.sort_by_key(|(attribute, _)| attribute.id);
+
+        let attributes = attributes
+            .into_iter()
+            .map(|(attribute, base)| {
+                let mut attribute_nbt = NbtCompound::new();
+                attribute_nbt.put_string("id", attribute.minecraft_name.to_string());
+                attribute_nbt.put_double("base", base.load());
+                let modifiers = self.get_modifiers(attribute);
+                if !modifiers.is_empty() {
+                    attribute_nbt.put_list(
+                        "modifiers",
+                        modifiers
+                            .iter()
+                            .map(|modifier| NbtTag::Compound(modifier.write_nbt()))
+                            .collect(),
+                    );
+                }
+                NbtTag::Compound(attribute_nbt)
+            })
+            .collect();
+        nbt.put_list("attributes", attributes);
+    }
+
+    /// Restores the base values and modifiers written by [`AttributeManager::write_nbt`].
+    /// Attributes the entity doesn't have are ignored.
+    pub fn read_nbt(&self, nbt: &NbtCompound) {
+        let Some(attributes) = nbt.get_list("attributes") else {
+            return;
+        };
+        for attribute_nbt in attributes.iter().filter_map(NbtTag::extract_compound) {
+            let Some(attribute) = attribute_nbt
+                .get_string("id")
+                .and_then(Attributes::from_minecraft_name)
+            else {
+                continue;
+            };
+            if let Some(base) = attribute_nbt.get_double("base") {
+                let _ = self.set_base(attribute, base);
+            }
+            if let Some(modifiers) = self.modifiers.get(attribute) {
+                *modifiers.lock().unwrap() = attribute_nbt
+                    .get_list("modifiers")
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(NbtTag::extract_compound)
+                    .filter_map(AttributeModifier::read_nbt)
+                    .collect();
+            }
+        }
+    }
+}
+
+/// Applies modifiers to a base value in vanilla's order: first all `AddValue` modifiers,
+/// then `AddMultipliedBase` (relative to the result of the first step) and finally
+/// every `AddMultipliedTotal` multiplies the running total.
+#[must_use]
+pub fn apply_modifiers(base: f64, modifiers: impl IntoIterator<Item = (f64, Operation)>) -> f64 {
+    let mut add_value = 0.0;
+    let mut multiplied_base = 0.0;
+    let mut multiplied_total = 1.0;
+
+    for (amount, operation) in modifiers {
+        match operation {
+            Operation::AddValue => add_value += amount,
+            Operation::AddMultipliedBase => multiplied_base += amount,
+            Operation::AddMultipliedTotal => multiplied_total *= 1.0 + amount,
+        }
+    }
+
+    let value = base + add_value;
+    (value + value * multiplied_base) * multiplied_total
+}
+
+/// The `(amount, operation)` modifiers an item gives the attribute while it is in `slot`.
+pub fn item_modifiers<'a>(
+    modifiers: &'a [Modifier],
+    slot: &'a EquipmentSlot,
+    attribute: &'static Attributes,
+) -> impl Iterator<Item = (f64, Operation)> + 'a {
+    modifiers
+        .iter()
+        .filter(move |modifier| {
+            modifier.r#type == attribute && modifier_applies_in(&modifier.slot, slot)
+        })
+        .map(|modifier| (modifier.amount, modifier.operation))
+}
+
+/// Whether an item modifier for `modifier_slot` applies while the item is in `slot`.
+#[must_use]
+pub const fn modifier_applies_in(
+    modifier_slot: &AttributeModifierSlot,
+    slot: &EquipmentSlot,
+) -> bool {
+    match modifier_slot {
+        AttributeModifierSlot::Any => true,
+        AttributeModifierSlot::MainHand => matches!(slot, EquipmentSlot::MainHand(_)),
+        AttributeModifierSlot::OffHand => matches!(slot, EquipmentSlot::OffHand(_)),
+        AttributeModifierSlot::Hand => {
+            matches!(slot, EquipmentSlot::MainHand(_) | EquipmentSlot::OffHand(_))
+        }
+        AttributeModifierSlot::Feet => matches!(slot, EquipmentSlot::Feet(_)),
+        AttributeModifierSlot::Legs => matches!(slot, EquipmentSlot::Legs(_)),
+        AttributeModifierSlot::Chest => matches!(slot, EquipmentSlot::Chest(_)),
+        AttributeModifierSlot::Head => matches!(slot, EquipmentSlot::Head(_)),
+        AttributeModifierSlot::Armor => matches!(
+            slot,
+            EquipmentSlot::Feet(_)
+                | EquipmentSlot::Legs(_)
+                | EquipmentSlot::Chest(_)
+                | EquipmentSlot::Head(_)
+        ),
+        AttributeModifierSlot::Body => matches!(slot, EquipmentSlot::Body(_)),
+        AttributeModifierSlot::Saddle => matches!(slot, EquipmentSlot::Saddle(_)),
+    }
+}
+
+/// The `(amount, operation)` modifiers an effect gives the attribute, growing with each level.
+pub fn effect_modifiers(
+    effect: &'static StatusEffect,
+    amplifier: u8,
+    attribute: &'static Attributes,
+) -> impl Iterator<Item = (f64, Operation)> {
+    effect
+        .attribute_modifiers
+        .iter()
+        .filter(move |modifier| modifier.attribute == attribute)
+        .map(move |modifier| {
+            (
+                modifier.base_value * (f64::from(amplifier) + 1.0),
+                modifier.operation,
+            )
+        })
+}
+
+#[derive(Default)]
+pub struct AttributeBuilder {
+    values: HashMap<&'static Attributes, f64>,
+}
+
+impl AttributeBuilder {
+    /// Adds the attribute with its default value.
+    #[must_use]
+    pub fn add_attribute(self, attribute: &'static Attributes) -> Self {
+        self.add_attribute_with_value(attribute, attribute.default_value)
+    }
+
+    #[must_use]
+    pub fn add_attribute_with_value(mut self, attribute: &'static Attributes, value: f64) -> Self {
+        self.values.insert(attribute, value);
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> AttributeManager {
+        AttributeManager {
+            modifiers: self
+                .values
+                .keys()
+                .map(|attribute| (*attribute, Mutex::new(Vec::new())))
+                .collect(),
+            base_values: self
+                .values
+                .into_iter()
+                .map(|(attribute, value)| (attribute, AtomicCell::new(value)))
+                .collect(),
+        }
+    }
+}
+
+/// `LivingEntity.createLivingAttributes()` in yarn
+#[must_use]
+pub fn living_entity_attribute_builder() -> AttributeBuilder {
+    AttributeBuilder::default()
+        .add_attribute(&Attributes::MAX_HEALTH)
+        .add_attribute(&Attributes::KNOCKBACK_RESISTANCE)
+        .add_attribute(&Attributes::MOVEMENT_SPEED)
+        .add_attribute(&Attributes::ARMOR)
+        .add_attribute(&Attributes::ARMOR_TOUGHNESS)
+        .add_attribute(&Attributes::MAX_ABSORPTION)
+        .add_attribute(&Attributes::STEP_HEIGHT)
+        .add_attribute(&Attributes::SCALE)
+        .add_attribute(&Attributes::GRAVITY)
+        .add_attribute(&Attributes::SAFE_FALL_DISTANCE)
+        .add_attribute(&Attributes::FALL_DAMAGE_MULTIPLIER)
+        .add_attribute(&Attributes::JUMP_STRENGTH)
+        .add_attribute(&Attributes::OXYGEN_BONUS)
+        .add_attribute(&Attributes::BURNING_TIME)
+        .add_attribute(&Attributes::EXPLOSION_KNOCKBACK_RESISTANCE)
+        .add_attribute(&Attributes::WATER_MOVEMENT_EFFICIENCY)
+        .add_attribute(&Attributes::MOVEMENT_EFFICIENCY)
+        .add_attribute(&Attributes::ATTACK_KNOCKBACK)
+        .add_attribute(&Attributes::CAMERA_DISTANCE)
+        .add_attribute(&Attributes::WAYPOINT_TRANSMIT_RANGE)
+}
+
+/// `MobEntity.createMobAttributes()` in yarn
+#[must_use]
+pub fn mob_entity_attribute_builder() -> AttributeBuilder {
+    living_entity_attribute_builder().add_attribute_with_value(&Attributes::FOLLOW_RANGE, 16.0)
+}
+
+/// `PlayerEntity.createPlayerAttributes()` in yarn
+#[must_use]
+pub fn player_attribute_builder() -> AttributeBuilder {
+    living_entity_attribute_builder()
+        .add_attribute_with_value(&Attributes::ATTACK_DAMAGE, 1.0)
+        .add_attribute_with_value(&Attributes::MOVEMENT_SPEED, 0.1)
+        .add_attribute(&Attributes::ATTACK_SPEED)
+        .add_attribute(&Attributes::LUCK)
+        .add_attribute(&Attributes::BLOCK_INTERACTION_RANGE)
+        .add_attribute(&Attributes::ENTITY_INTERACTION_RANGE)
+        .add_attribute(&Attributes::BLOCK_BREAK_SPEED)
+        .add_attribute(&Attributes::SUBMERGED_MINING_SPEED)
+        .add_attribute(&Attributes::SNEAKING_SPEED)
+        .add_attribute(&Attributes::MINING_EFFICIENCY)
+        .add_attribute(&Attributes::SWEEPING_DAMAGE_RATIO)
+        .add_attribute(&Attributes::WAYPOINT_RECEIVE_RANGE)
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::attributes::Attributes;
+    use pumpkin_data::data_component_impl::Operation;
+    use pumpkin_data::entity::EntityType;
+
+    use pumpkin_nbt::compound::NbtCompound;
+
+    use super::{AttributeManager, AttributeModifier, apply_modifiers, item_modifiers};
+
+    #[test]
+    fn player_has_attack_speed() {
+        let manager = AttributeManager::new(&EntityType::PLAYER);
+        assert!(manager.has_attribute(&Attributes::ATTACK_SPEED));
+        assert!((manager.get_base(&Attributes::ATTACK_DAMAGE) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn modifiers_apply_in_phases() {
+        let value = apply_modifiers(
+            4.0,
+            [
+                (-2.4, Operation::AddValue),
+                (0.5, Operation::AddMultipliedBase),
+                (1.0, Operation::AddMultipliedTotal),
+            ],
+        );
+        assert!((value - 4.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn item_and_command_modifiers_apply_in_phases() {
+        use pumpkin_data::data_component_impl::{AttributeModifiersImpl, EquipmentSlot};
+        use pumpkin_data::item::Item;
+        use pumpkin_world::item::ItemStack;
+
+        let manager = AttributeManager::new(&EntityType::ZOMBIE);
+        assert!(manager.add_modifier(
+            &Attributes::ARMOR,
+            AttributeModifier {
+                id: "minecraft:test".to_string(),
+                amount: 0.5,
+                operation: Operation::AddMultipliedTotal,
+            }
+        ));
+
+        let helmet = ItemStack::new(1, &Item::DIAMOND_HELMET);
+        let helmet_modifiers = helmet
+            .get_data_component::<AttributeModifiersImpl>()
+            .expect("helmets give armor");
+        let worn: Vec<_> = item_modifiers(
+            &helmet_modifiers.attribute_modifiers,
+            &EquipmentSlot::HEAD,
+            &Attributes::ARMOR,
+        )
+        .collect();
+        assert_eq!(worn, vec![(3.0, Operation::AddValue)]);
+        // Only worn on the head
+        assert_eq!(
+            item_modifiers(
+                &helmet_modifiers.attribute_modifiers,
+                &EquipmentSlot::MAIN_HAND,
+                &Attributes::ARMOR,
+            )
+            .count(),
+            0
+        );
+
+        // The multiplier also scales the helmet's armor, as it applies in a later phase
+        let armor = manager.get_modified(&Attributes::ARMOR, worn);
+        assert!((armor - 4.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn attributes_persist_in_nbt() {
+        let manager = AttributeManager::new(&EntityType::ZOMBIE);
+        assert!(manager.set_base(&Attributes::MAX_HEALTH, 40.0));
+        let modifier = AttributeModifier {
+            id: "minecraft:test".to_string(),
+            amount: 0.5,
+            operation: Operation::AddMultipliedTotal,
+        };
+        assert!(manager.add_modifier(&Attributes::MOVEMENT_SPEED, modifier.clone()));
+
+        let mut nbt = NbtCompound::new();
+        manager.write_nbt(&mut nbt);
+
+        let loaded = AttributeManager::new(&EntityType::ZOMBIE);
+        loaded.read_nbt(&nbt);
+        assert!((loaded.get_base(&Attributes::MAX_HEALTH) - 40.0).abs() < f64::EPSILON);
+        assert_eq!(
+            loaded.get_modifiers(&Attributes::MOVEMENT_SPEED),
+            vec![modifier]
+        );
+        let speed = manager.get_base(&Attributes::MOVEMENT_SPEED) * 1.5;
+        assert!((loaded.get_modified(&Attributes::MOVEMENT_SPEED, []) - speed).abs() < 1e-9);
+    }
+
+    #[test]
+    fn negative_max_health_clamps_to_minimum() {
+        let manager = AttributeManager::new(&EntityType::ZOMBIE);
+        assert!(manager.set_base(&Attributes::MAX_HEALTH, -5.0));
+        let min = Attributes::MAX_HEALTH.get_min();
+        assert!((manager.get_base(&Attributes::MAX_HEALTH) - min).abs() < f64::EPSILON);
+        assert!((min - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn modifier_add_then_get_replaces_duplicate_ids() {
+        let manager = AttributeManager::new(&EntityType::ZOMBIE);
+        let base = manager.get_base(&Attributes::ARMOR);
+        let modifier = |amount| AttributeModifier {
+            id: "minecraft:test".to_string(),
+            amount,
+            operation: Operation::AddValue,
+        };
+
+        assert!(manager.add_modifier(&Attributes::ARMOR, modifier(2.0)));
+        assert!((manager.get_modified(&Attributes::ARMOR, []) - (base + 2.0)).abs() < 1e-9);
+
+        assert!(manager.add_modifier(&Attributes::ARMOR, modifier(5.0)));
+        assert_eq!(
+            manager.get_modifiers(&Attributes::ARMOR),
+            vec![modifier(5.0)]
+        );
+        assert!((manager.get_modified(&Attributes::ARMOR, []) - (base + 5.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn modifier_add_then_remove_restores_value() {
+        let manager = AttributeManager::new(&EntityType::ZOMBIE);
+        let base = manager.get_modified(&Attributes::MOVEMENT_SPEED, []);
+        let modifier = AttributeModifier {
+            id: "minecraft:test".to_string(),
+            amount: 0.5,
+            operation: Operation::AddMultipliedTotal,
+        };
+
+        assert!(manager.add_modifier(&Attributes::MOVEMENT_SPEED, modifier));
+        let speed = manager.get_modified(&Attributes::MOVEMENT_SPEED, []);
+        assert!((speed - base * 1.5).abs() < 1e-9);
+        assert!(manager.remove_modifier(&Attributes::MOVEMENT_SPEED, "minecraft:test"));
+        assert!(!manager.remove_modifier(&Attributes::MOVEMENT_SPEED, "minecraft:test"));
+        assert_eq!(manager.get_modifiers(&Attributes::MOVEMENT_SPEED), vec![]);
+        assert!(
+            (manager.get_modified(&Attributes::MOVEMENT_SPEED, []) - base).abs() < f64::EPSILON
+        );
+    }
+}
@@ -1,18 +1,23 @@
-use std::sync::atomic::Ordering;
+use std::sync::{Arc, atomic::Ordering};
 
 use pumpkin_data::{
+    damage::DamageType,
+    effect::StatusEffect,
     particle::Particle,
     sound::{Sound, SoundCategory},
 };
-use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::CEntityVelocity};
-use pumpkin_util::math::vector3::Vector3;
+use pumpkin_protocol::{
+    codec::var_int::VarInt,
+    java::client::play::{Animation, CEntityAnimation, CEntityVelocity},
+};
+use pumpkin_util::math::{boundingbox::BoundingBox, vector3::Vector3};
 
 use crate::{
-    entity::{Entity, player::Player},
+    entity::{Entity, EntityBase, player::Player},
     world::World,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttackType {
     Knockback,
     Critical,
@@ -21,34 +26,98 @@ pub enum AttackType {
     Weak,
 }
 
+/// The state of the attacker that decides which kind of attack a melee hit is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AttackConditions {
+    pub cooldown_progress: f32,
+    pub sprinting: bool,
+    pub on_ground: bool,
+    pub fall_distance: f32,
+    pub climbing: bool,
+    pub in_water: bool,
+    pub blind: bool,
+    pub has_vehicle: bool,
+    pub sword: bool,
+}
+
 impl AttackType {
     pub async fn new(player: &Player, attack_cooldown_progress: f32) -> Self {
-        let entity = &player.living_entity.entity;
+        let living = &player.living_entity;
+        let entity = &living.entity;
 
-        let sprinting = entity.sprinting.load(Ordering::Relaxed);
-        let on_ground = entity.on_ground.load(Ordering::Relaxed);
-        let fall_distance = player.living_entity.fall_distance.load();
-        let sword = player.inventory().held_item().lock().await.is_sword();
+        let conditions = AttackConditions {
+            cooldown_progress: attack_cooldown_progress,
+            sprinting: entity.sprinting.load(Ordering::Relaxed),
+            on_ground: entity.on_ground.load(Ordering::Relaxed),
+            fall_distance: living.fall_distance.load(),
+            climbing: living.climbing.load(Ordering::Relaxed),
+            in_water: entity.touching_water.load(Ordering::Relaxed),
+            blind: living.has_effect(&StatusEffect::BLINDNESS).await,
+            has_vehicle: entity.has_vehicle().await,
+            sword: player.inventory().held_item().lock().await.is_sword(),
+        };
 
-        let is_strong = attack_cooldown_progress > 0.9;
-        if sprinting && is_strong {
+        Self::from_conditions(&conditions)
+    }
+
+    #[must_use]
+    pub fn from_conditions(conditions: &AttackConditions) -> Self {
+        let is_strong = conditions.cooldown_progress > 0.9;
+        if conditions.sprinting && is_strong {
             return Self::Knockback;
         }
 
-        // TODO: even more checks
-        if is_strong && !on_ground && fall_distance > 0.0 {
-            // !sprinting omitted
+        if is_strong
+            && !conditions.on_ground
+            && conditions.fall_distance > 0.0
+            && !conditions.climbing
+            && !conditions.in_water
+            && !conditions.blind
+            && !conditions.has_vehicle
+        {
             return Self::Critical;
         }
 
         // TODO: movement speed check
-        if sword && is_strong {
-            // !is_crit, !is_knockback_hit, on_ground omitted
+        if conditions.sword && is_strong && conditions.on_ground && !conditions.sprinting {
             return Self::Sweeping;
         }
 
         if is_strong { Self::Strong } else { Self::Weak }
     }
+
+    /// The multiplier applied to the base damage of this attack.
+    #[must_use]
+    pub const fn damage_multiplier(self) -> f64 {
+        match self {
+            Self::Critical => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    #[must_use]
+    pub const fn spawns_crit_particles(self) -> bool {
+        matches!(self, Self::Critical)
+    }
+}
+
+/// Shows the critical hit particles around the victim to all players.
+pub async fn spawn_crit_particles(world: &World, victim: &Entity) {
+    world
+        .broadcast_packet_all(&CEntityAnimation::new(
+            VarInt(victim.entity_id),
+            Animation::CriticalEffect,
+        ))
+        .await;
+}
+
+/// How much of the attack damage is dealt for the given attack cooldown progress.
+///
+/// A fully charged attack deals full damage, while spamming attacks only deals 20% of it.
+#[must_use]
+pub fn cooldown_damage_multiplier(attack_cooldown_progress: f64) -> f64 {
+    let progress = attack_cooldown_progress.clamp(0.0, 1.0);
+    progress.powi(2).mul_add(0.8, 0.2)
 }
 
 pub async fn handle_knockback(attacker: &Entity, world: &World, victim: &Entity, strength: f64) {
@@ -72,6 +141,76 @@ pub async fn handle_knockback(attacker: &Entity, world: &World, victim: &Entity,
     world.broadcast_packet_all(&packet).await;
 }
 
+/// The sweep damage dealt to entities next to the primary target.
+///
+/// Vanilla deals `1 + sweeping_damage_ratio * damage`, where Sweeping Edge raises the ratio to
+/// `level / (level + 1)`.
+#[must_use]
+pub fn sweep_damage(damage: f64, sweeping_level: i32) -> f64 {
+    let level = f64::from(sweeping_level.max(0));
+    let ratio = level / (level + 1.0);
+    ratio.mul_add(damage, 1.0)
+}
+
+/// The area around the primary target in which other entities are hit by a sweep.
+#[must_use]
+pub fn sweep_box(target_box: &BoundingBox) -> BoundingBox {
+    target_box.expand(1.0, 0.25, 1.0)
+}
+
+/// Whether an entity standing at `pos` is close enough to the attacker to be hit by a sweep.
+#[must_use]
+pub fn is_in_sweep_range(attacker_pos: &Vector3<f64>, pos: &Vector3<f64>) -> bool {
+    attacker_pos.squared_distance_to_vec(*pos) < 9.0
+}
+
+/// Hits every other living entity around `victim` with reduced sweep damage and knockback.
+pub async fn sweep_attack(
+    attacker: &Player,
+    world: &World,
+    victim: &Entity,
+    damage: f64,
+    sweeping_level: i32,
+) {
+    let attacker_entity = &attacker.living_entity.entity;
+    let attacker_pos = attacker_entity.pos.load();
+    let area = sweep_box(&victim.bounding_box.load());
+    let damage = sweep_damage(damage, sweeping_level) as f32;
+    let yaw = attacker_entity.yaw.load().to_radians();
+
+    let mut targets = world.get_entities_at_box(&area).await;
+    targets.extend(
+        world
+            .get_players_at_box(&area)
+            .await
+            .into_iter()
+            .map(|player| player as Arc<dyn EntityBase>),
+    );
+
+    for target in targets {
+        let entity = target.get_entity();
+        if entity.entity_id == victim.entity_id
+            || entity.entity_id == attacker_entity.entity_id
+            || target.get_living_entity().is_none()
+            || !is_in_sweep_range(&attacker_pos, &entity.pos.load())
+        {
+            continue;
+        }
+        entity.knockback(0.4, f64::from(yaw.sin()), f64::from(-yaw.cos()));
+        target
+            .damage_with_context(
+                target.clone(),
+                damage,
+                DamageType::PLAYER_ATTACK,
+                None,
+                Some(attacker_entity),
+                Some(attacker_entity),
+            )
+            .await;
+        entity.send_velocity().await;
+    }
+}
+
 pub async fn spawn_sweep_particle(attacker_entity: &Entity, world: &World, pos: &Vector3<f64>) {
     let yaw = attacker_entity.yaw.load();
     let d = -f64::from((yaw.to_radians()).sin());
@@ -124,3 +263,135 @@ pub async fn player_attack_sound(pos: &Vector3<f64>, world: &World, attack_type:
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::{AttackConditions, AttackType, cooldown_damage_multiplier, is_in_sweep_range};
+    use crate::entity::EntityBase;
+
+    #[tokio::test]
+    async fn sweep_hits_secondary_entity_for_reduced_damage() {
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_data::{Enchantment, item::Item};
+        use pumpkin_util::GameMode;
+        use pumpkin_world::item::ItemStack;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        use crate::entity::{player::Player, r#type::spawn_from_type};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // High up in the air, away from any terrain
+        let (player, _packets) = Player::for_test(&world, GameMode::Survival).await;
+        let attacker = player.get_entity();
+        attacker.set_pos(Vector3::new(0.5, 300.0, 0.5));
+        attacker.on_ground.store(true, Relaxed);
+        // Fully charged
+        player.last_attacked_ticks.store(100, Relaxed);
+        let mut sword = ItemStack::new(1, &Item::DIAMOND_SWORD);
+        sword.enchant(&Enchantment::SWEEPING_EDGE, 3);
+        *player.inventory().held_item().lock().await = sword;
+
+        let mut zombies = Vec::new();
+        for pos in [Vector3::new(2.0, 300.0, 0.5), Vector3::new(2.5, 300.0, 1.5)] {
+            let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, pos)
+                .await
+                .unwrap();
+            world.spawn_entity(zombie.clone()).await;
+            zombies.push(zombie);
+        }
+        let health = |index: usize| zombies[index].get_living_entity().unwrap().health.load();
+        let full_health = health(0);
+
+        player.attack(zombies[0].clone()).await;
+
+        // A diamond sword deals 7 damage, Sweeping Edge III passes on 3/4 of it
+        let damage: f32 = 7.0;
+        assert!((full_health - health(0) - damage).abs() < 1e-4);
+        let swept = 0.75f32.mul_add(damage, 1.0);
+        assert!((full_health - health(1) - swept).abs() < 1e-4);
+    }
+
+    #[test]
+    fn sweep_ignores_far_entities() {
+        let attacker = Vector3::new(0.0, 64.0, 0.0);
+        assert!(!is_in_sweep_range(&attacker, &Vector3::new(4.0, 64.0, 0.0)));
+    }
+
+    #[tokio::test]
+    async fn only_falling_attacks_are_critical() {
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_protocol::java::client::play::CEntityAnimation;
+        use pumpkin_protocol::packet::Packet;
+        use pumpkin_util::GameMode;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        use crate::entity::{player::Player, r#type::spawn_from_type};
+        use crate::net::java::JavaClient;
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // High up in the air, away from any terrain
+        let (player, mut packets) = Player::for_test(&world, GameMode::Survival).await;
+        let attacker = player.get_entity();
+        attacker.set_pos(Vector3::new(0.5, 300.0, 0.5));
+
+        for on_ground in [false, true] {
+            let zombie =
+                spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(1.5, 300.0, 0.5))
+                    .await
+                    .unwrap();
+            world.spawn_entity(zombie.clone()).await;
+            let living = zombie.get_living_entity().unwrap();
+            let health = living.health.load();
+
+            attacker.on_ground.store(on_ground, Relaxed);
+            player.living_entity.fall_distance.store(0.5);
+            // Fully charged
+            player.last_attacked_ticks.store(100, Relaxed);
+            JavaClient::queued_packet_ids(&mut packets);
+            player.attack(zombie).await;
+
+            // Bare hands deal 1 damage
+            let damage = if on_ground { 1.0 } else { 1.5 };
+            assert!((health - living.health.load() - damage).abs() < 1e-4);
+            let crit_particles =
+                JavaClient::queued_packet_ids(&mut packets).contains(&CEntityAnimation::PACKET_ID);
+            assert_eq!(crit_particles, !on_ground);
+        }
+    }
+
+    #[test]
+    fn blind_falling_attack_is_not_critical() {
+        let conditions = AttackConditions {
+            cooldown_progress: 1.0,
+            fall_distance: 0.5,
+            blind: true,
+            ..Default::default()
+        };
+
+        assert!(!AttackType::from_conditions(&conditions).spawns_crit_particles());
+    }
+
+    #[test]
+    fn half_charged_attack_deals_reduced_damage() {
+        let damage = 7.0;
+        let full = damage * cooldown_damage_multiplier(1.0);
+        let half = damage * cooldown_damage_multiplier(0.5);
+
+        assert!((full - damage).abs() < f64::EPSILON);
+        assert!((half - 2.8).abs() < 1e-9);
+        assert!(half < full);
+        assert_eq!(
+            AttackType::from_conditions(&AttackConditions {
+                cooldown_progress: 0.5,
+                ..Default::default()
+            }),
+            AttackType::Weak
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! Metadata entries of an entity, tracked by index so only changed values are sent.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use bytes::BufMut;
+use crossbeam::atomic::AtomicCell;
+use pumpkin_protocol::{
+    java::client::play::{MetaDataType, Metadata},
+    ser::serializer::Serializer,
+};
+use serde::Serialize;
+
+/// Marks the end of the entries in a `CSetEntityMetadata` payload
+const END_OF_METADATA: u8 = 255;
+
+struct TrackedEntry {
+    /// The serialized entry, including its index and type
+    data: Vec<u8>,
+    /// Whether the entry changed since it was last sent
+    dirty: bool,
+}
+
+/// Holds the metadata entries of an entity by index. Entities register their values once in
+/// `init_data_tracker` and update them with [`DataTracker::track`]; only entries whose value
+/// changed are serialized by [`DataTracker::serialize_dirty`].
+#[derive(Default)]
+pub struct DataTracker {
+    entries: Mutex<BTreeMap<u8, TrackedEntry>>,
+}
+
+impl DataTracker {
+    /// Sets the entry at `index`. Returns whether the value changed, which marks it dirty
+    pub fn track<T: Serialize>(&self, index: u8, r#type: MetaDataType, value: T) -> bool {
+        self.track_meta(&Metadata::new(index, r#type, value))
+    }
+
+    /// Like [`DataTracker::track`], for an already built entry
+    pub fn track_meta<T: Serialize>(&self, meta: &Metadata<T>) -> bool {
+        let mut data = Vec::new();
+        let mut serializer = Serializer::new(&mut data);
+        meta.serialize(&mut serializer).unwrap();
+
+        let mut entries = self.entries.lock().unwrap();
+        if entries
+            .get(&meta.index())
+            .is_some_and(|entry| entry.data == data)
+        {
+            return false;
+        }
+        entries.insert(meta.index(), TrackedEntry { data, dirty: true });
+        true
+    }
+
+    /// Sends the entry at `index` again with the next changes, even if its value is the same
+    pub fn mark_dirty(&self, index: u8) {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(&index) {
+            entry.dirty = true;
+        }
+    }
+
+    /// Serializes the changed entries into a `CSetEntityMetadata` payload and marks them as sent.
+    /// Returns [`None`] if nothing changed.
+    pub fn serialize_dirty(&self) -> Option<Box<[u8]>> {
+        let mut entries = self.entries.lock().unwrap();
+        let mut buf = Vec::new();
+        for entry in entries.values_mut().filter(|entry| entry.dirty) {
+            entry.dirty = false;
+            buf.extend_from_slice(&entry.data);
+        }
+        if buf.is_empty() {
+            return None;
+        }
+        buf.put_u8(END_OF_METADATA);
+        Some(buf.into())
+    }
+
+    /// Serializes every entry, e.g. for players that start seeing the entity. Dirty entries stay
+    /// dirty, the players already seeing the entity still need them.
+    /// Returns [`None`] if nothing is tracked.
+    pub fn serialize_all(&self) -> Option<Box<[u8]>> {
+        let entries = self.entries.lock().unwrap();
+        if entries.is_empty() {
+            return None;
+        }
+        let mut buf = Vec::new();
+        for entry in entries.values() {
+            buf.extend_from_slice(&entry.data);
+        }
+        buf.put_u8(END_OF_METADATA);
+        Some(buf.into())
+    }
+}
+
+/// The last value of a field sent to clients, e.g. an entity's rotation, so packets for an
+/// unchanged value can be skipped
+#[derive(Default)]
+pub struct LastSent<T: Copy + PartialEq>(AtomicCell<Option<T>>);
+
+impl<T: Copy + PartialEq> LastSent<T> {
+    /// Remembers `value` as sent. Returns whether it differs from the previous one and so has to
+    /// be sent.
+    pub fn update(&self, value: T) -> bool {
+        self.0.swap(Some(value)) != Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::MetaDataType};
+
+    use super::{DataTracker, LastSent};
+
+    #[test]
+    fn identical_rotations_are_sent_once() {
+        let rotation = LastSent::default();
+        assert!(rotation.update((64u8, 0u8, true)));
+        assert!(!rotation.update((64, 0, true)));
+        // Landing alone changes the packet
+        assert!(rotation.update((64, 0, false)));
+    }
+
+    #[test]
+    fn only_changed_entries_are_serialized() {
+        let tracker = DataTracker::default();
+        assert!(tracker.track(0, MetaDataType::Byte, 0i8));
+        assert!(tracker.track(7, MetaDataType::Integer, VarInt(0)));
+        // Sorted by index and terminated
+        assert_eq!(
+            *tracker.serialize_dirty().unwrap(),
+            [
+                0,
+                MetaDataType::Byte as u8,
+                0,
+                7,
+                MetaDataType::Integer as u8,
+                0,
+                255
+            ]
+        );
+        assert!(tracker.serialize_dirty().is_none());
+
+        // Setting the same value again doesn't send it
+        assert!(!tracker.track(0, MetaDataType::Byte, 0i8));
+        assert!(tracker.track(7, MetaDataType::Integer, VarInt(5)));
+        assert_eq!(
+            *tracker.serialize_dirty().unwrap(),
+            [7, MetaDataType::Integer as u8, 5, 255]
+        );
+
+        // New viewers get everything
+        assert_eq!(
+            *tracker.serialize_all().unwrap(),
+            [
+                0,
+                MetaDataType::Byte as u8,
+                0,
+                7,
+                MetaDataType::Integer as u8,
+                5,
+                255
+            ]
+        );
+
+        // A change not sent yet still goes out to the players who saw the old value
+        assert!(tracker.track(0, MetaDataType::Byte, 1i8));
+        assert!(tracker.serialize_all().is_some());
+        assert_eq!(
+            *tracker.serialize_dirty().unwrap(),
+            [0, MetaDataType::Byte as u8, 1, 255]
+        );
+    }
+}
@@ -52,6 +52,9 @@ impl EntityBase for EndCrystalEntity {
         _source: Option<&dyn EntityBase>,
         _cause: Option<&dyn EntityBase>,
     ) -> bool {
+        if self.entity.is_invulnerable_to(&damage_type) {
+            return false;
+        }
         if damage_type != DamageType::EXPLOSION {
             self.entity.world.explode(self.entity.pos.load(), 6.0).await;
         }
@@ -43,11 +43,14 @@ impl EntityBase for PaintingEntity {
         &self,
         _caller: Arc<dyn EntityBase>,
         _amount: f32,
-        _damage_type: DamageType,
+        damage_type: DamageType,
         _position: Option<Vector3<f64>>,
         _source: Option<&dyn EntityBase>,
         _cause: Option<&dyn EntityBase>,
     ) -> bool {
+        if self.entity.is_invulnerable_to(&damage_type) {
+            return false;
+        }
         // TODO
         self.entity.remove().await;
         true
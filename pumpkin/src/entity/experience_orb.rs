@@ -7,6 +7,7 @@ use std::sync::{
 use async_trait::async_trait;
 use pumpkin_data::entity::EntityType;
 use pumpkin_util::math::vector3::Vector3;
+use rand::Rng;
 use uuid::Uuid;
 
 use crate::{server::Server, world::World};
@@ -30,10 +31,7 @@ impl ExperienceOrbEntity {
     }
 
     pub async fn spawn(world: &Arc<World>, position: Vector3<f64>, amount: u32) {
-        let mut amount = amount;
-        while amount > 0 {
-            let i = Self::round_to_orb_size(amount);
-            amount -= i;
+        for orb_size in Self::split_into_orbs(amount) {
             let entity = Entity::new(
                 Uuid::new_v4(),
                 world.clone(),
@@ -41,11 +39,33 @@ impl ExperienceOrbEntity {
                 &EntityType::EXPERIENCE_ORB,
                 false,
             );
-            let orb = Arc::new(Self::new(entity, i));
+            entity
+                .velocity
+                .store(Self::random_velocity(&mut rand::rng()));
+            let orb = Arc::new(Self::new(entity, orb_size));
             world.spawn_entity(orb).await;
         }
     }
 
+    fn random_velocity(rng: &mut impl Rng) -> Vector3<f64> {
+        Vector3::new(
+            (rng.random::<f64>() * 0.2 - 0.1) * 2.0,
+            rng.random::<f64>() * 0.2 * 2.0,
+            (rng.random::<f64>() * 0.2 - 0.1) * 2.0,
+        )
+    }
+
+    /// Splits `amount` into orbs, always taking the biggest orb size that still fits.
+    fn split_into_orbs(mut amount: u32) -> Vec<u32> {
+        let mut orbs = Vec::new();
+        while amount > 0 {
+            let orb_size = Self::round_to_orb_size(amount);
+            amount -= orb_size;
+            orbs.push(orb_size);
+        }
+        orbs
+    }
+
     fn round_to_orb_size(value: u32) -> u32 {
         if value >= 2477 {
             2477
@@ -79,7 +99,7 @@ impl NBTStorage for ExperienceOrbEntity {}
 impl EntityBase for ExperienceOrbEntity {
     async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
         let entity = &self.entity;
-        entity.tick(caller.clone(), server).await;
+        entity.base_tick(caller.clone(), server).await;
         let bounding_box = entity.bounding_box.load();
 
         let original_velo = entity.velocity.load();
@@ -91,8 +111,13 @@ impl EntityBase for ExperienceOrbEntity {
             .world
             .is_space_empty(bounding_box.expand(-1.0e-7, -1.0e-7, -1.0e-7))
             .await;
-        // TODO: isSubmergedIn
-        if !no_clip {
+        if self.entity.is_eye_in_water().await {
+            velo.x *= 0.99;
+
+            velo.z *= 0.99;
+
+            velo.y = Entity::apply_gravity(velo.y, self.get_gravity(), true);
+        } else if !no_clip {
             velo.y -= self.get_gravity();
         }
 
@@ -137,3 +162,48 @@ impl EntityBase for ExperienceOrbEntity {
         0.03
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[tokio::test]
+    async fn killed_mobs_drop_their_experience_as_orbs() {
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_util::GameMode;
+        use pumpkin_util::math::vector3::Vector3;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        use crate::entity::{EntityBase, player::Player, r#type::spawn_from_type};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let (player, _packets) = Player::for_test(&world, GameMode::Survival).await;
+        player.get_entity().set_pos(Vector3::new(0.5, 300.0, 0.5));
+        player.last_attacked_ticks.store(100, Relaxed);
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(1.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        zombie.get_living_entity().unwrap().health.store(0.5);
+
+        player.attack(zombie.clone()).await;
+        assert!(zombie.get_living_entity().unwrap().dead.load(Relaxed));
+
+        let orbs: Vec<_> = world
+            .entities
+            .read()
+            .await
+            .values()
+            .filter(|entity| entity.get_entity().entity_type == &EntityType::EXPERIENCE_ORB)
+            .cloned()
+            .collect();
+        // A zombie gives 5 experience, split into orbs of 3, 1 and 1
+        assert_eq!(orbs.len(), 3);
+        for orb in &orbs {
+            *player.experience_pick_up_delay.lock().await = 0;
+            orb.on_player_collision(&player).await;
+        }
+        assert_eq!(player.experience_level.load(Relaxed), 0);
+        assert_eq!(player.experience_points.load(Relaxed), 5);
+    }
+}
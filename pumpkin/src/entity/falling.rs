@@ -1,14 +1,14 @@
 use async_trait::async_trait;
 use pumpkin_data::Block;
 use pumpkin_data::entity::EntityType;
-use pumpkin_protocol::java::client::play::{MetaDataType, Metadata};
+use pumpkin_protocol::java::client::play::MetaDataType;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_world::{BlockStateId, world::BlockFlags};
 use std::sync::{Arc, atomic::Ordering};
 use uuid::Uuid;
 
 use crate::{
-    entity::{Entity, EntityBase, NBTStorage, living::LivingEntity},
+    entity::{Entity, EntityBase, NBTStorage, living::LivingEntity, physics::AIR_DRAG},
     server::Server,
     world::World,
 };
@@ -57,7 +57,7 @@ impl NBTStorage for FallingEntity {}
 impl EntityBase for FallingEntity {
     async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
         let entity = &self.entity;
-        entity.tick(caller.clone(), server).await;
+        entity.base_tick(caller.clone(), server).await;
 
         let original_velo = entity.velocity.load();
         let mut velo = original_velo;
@@ -80,21 +80,18 @@ impl EntityBase for FallingEntity {
             entity.remove().await;
         }
 
-        entity.velocity.store(velo.multiply(0.98, 0.98, 0.98));
+        entity.velocity.store(velo * AIR_DRAG);
 
         entity.send_pos_rot().await;
 
-        entity.send_velocity().await;
+        entity.send_velocity_if_dirty().await;
     }
 
     async fn init_data_tracker(&self) {
+        self.entity.init_tracked_data();
         self.entity
-            .send_meta_data(&[Metadata::new(
-                8,
-                MetaDataType::BlockPos,
-                self.entity.block_pos.load(),
-            )])
-            .await;
+            .data_tracker
+            .track(8, MetaDataType::BlockPos, self.entity.block_pos.load());
     }
 
     fn get_entity(&self) -> &Entity {
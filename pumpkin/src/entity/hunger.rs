@@ -4,6 +4,7 @@ use super::{EntityBase, NBTStorage, NBTStorageInit, player::Player};
 use async_trait::async_trait;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::data_component_impl::FoodImpl;
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::Difficulty;
 
@@ -80,19 +81,29 @@ impl HungerManager {
     }
 
     pub async fn add_modifier(&self, player: &Player, food: u8, saturation_modifier: f32) {
-        let saturation = f32::from(food) * saturation_modifier * 2.0;
-        self.level.store(food + self.level.load());
-        self.saturation.store(saturation + self.saturation.load());
+        self.add(food, f32::from(food) * saturation_modifier * 2.0);
         player.send_health().await;
     }
 
-    pub async fn eat(&self, player: &Player, food: u8, saturation: f32) {
-        self.level.store(food + self.level.load());
-        self.saturation.store(saturation + self.saturation.load());
-
+    /// Restores the nutrition and saturation of the eaten food.
+    pub async fn eat(&self, player: &Player, food: &FoodImpl) {
+        self.add(food.nutrition.clamp(0, 20) as u8, food.saturation);
         player.send_health().await;
     }
 
+    /// Adds to the food level, which is capped at 20. The saturation can never exceed the food level.
+    pub fn add(&self, food: u8, saturation: f32) {
+        let level = self.level.load().saturating_add(food).min(20);
+        self.level.store(level);
+        self.saturation
+            .store((self.saturation.load() + saturation).clamp(0.0, f32::from(level)));
+    }
+
+    #[must_use]
+    pub fn is_not_full(&self) -> bool {
+        self.level.load() < 20
+    }
+
     pub fn add_exhaustion(&self, exhaustion: f32) {
         self.exhaustion
             .store((self.exhaustion.load() + exhaustion).min(40.0));
@@ -130,3 +141,37 @@ impl NBTStorage for HungerManager {
 }
 
 impl NBTStorageInit for HungerManager {}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::data_component_impl::FoodImpl;
+    use pumpkin_data::item::Item;
+    use pumpkin_world::item::ItemStack;
+
+    use super::HungerManager;
+
+    #[test]
+    fn eating_bread_restores_nutrition() {
+        let hunger = HungerManager::default();
+        hunger.level.store(10);
+
+        let bread = ItemStack::new(1, &Item::BREAD);
+        let food = bread.get_data_component::<FoodImpl>().unwrap();
+        hunger.add(food.nutrition as u8, food.saturation);
+
+        assert_eq!(hunger.level.load(), 10 + food.nutrition as u8);
+        assert!((hunger.saturation.load() - 11.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn saturation_is_capped_by_food_level() {
+        let hunger = HungerManager::default();
+        hunger.level.store(2);
+        hunger.saturation.store(0.0);
+
+        hunger.add(1, 9.6);
+
+        assert_eq!(hunger.level.load(), 3);
+        assert!((hunger.saturation.load() - 3.0).abs() < f32::EPSILON);
+    }
+}
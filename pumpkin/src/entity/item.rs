@@ -2,7 +2,7 @@ use core::f32;
 use std::sync::{
     Arc,
     atomic::{
-        AtomicBool, AtomicU32,
+        AtomicBool,
         Ordering::{self},
     },
 };
@@ -20,11 +20,20 @@ use tokio::sync::Mutex;
 
 use crate::server::Server;
 
-use super::{Entity, EntityBase, NBTStorage, living::LivingEntity, player::Player};
+use super::{
+    Entity, EntityBase, NBTStorage, living::LivingEntity, physics::AIR_DRAG, player::Player,
+};
+
+/// Dropped items despawn after 5 minutes
+pub const DESPAWN_AGE: i32 = 6000;
+/// Ticks before an item can be picked up
+pub const DEFAULT_PICKUP_DELAY: u8 = 10;
+/// Ticks before an item thrown by a player or dropper can be picked up, so it isn't picked up
+/// again right away
+pub const THROWN_PICKUP_DELAY: u8 = 40;
 
 pub struct ItemEntity {
     entity: Entity,
-    item_age: AtomicU32,
     // These cannot be atomic values because we mutate their state based on what they are; we run
     // into the ABA problem
     item_stack: Mutex<ItemStack>,
@@ -36,19 +45,17 @@ pub struct ItemEntity {
 
 impl ItemEntity {
     pub async fn new(entity: Entity, item_stack: ItemStack) -> Self {
-        entity
-            .set_velocity(Vector3::new(
-                rand::random::<f64>() * 0.2 - 0.1,
-                0.2,
-                rand::random::<f64>() * 0.2 - 0.1,
-            ))
-            .await;
+        // Not spawned yet, so the velocity is sent with the spawn packet
+        entity.velocity.store(Vector3::new(
+            rand::random::<f64>() * 0.2 - 0.1,
+            0.2,
+            rand::random::<f64>() * 0.2 - 0.1,
+        ));
         entity.yaw.store(rand::random::<f32>() * 360.0);
         Self {
             entity,
             item_stack: Mutex::new(item_stack),
-            item_age: AtomicU32::new(0),
-            pickup_delay: Mutex::new(10), // Vanilla pickup delay is 10 ticks
+            pickup_delay: Mutex::new(DEFAULT_PICKUP_DELAY),
             health: AtomicCell::new(5.0),
             never_despawn: AtomicBool::new(false),
             never_pickup: AtomicBool::new(false),
@@ -61,24 +68,50 @@ impl ItemEntity {
         velocity: Vector3<f64>,
         pickup_delay: u8,
     ) -> Self {
-        entity.set_velocity(velocity).await;
+        entity.velocity.store(velocity);
         entity.yaw.store(rand::random::<f32>() * 360.0);
         Self {
             entity,
             item_stack: Mutex::new(item_stack),
-            item_age: AtomicU32::new(0),
-            pickup_delay: Mutex::new(pickup_delay), // Vanilla pickup delay is 10 ticks
+            pickup_delay: Mutex::new(pickup_delay),
             health: AtomicCell::new(5.0),
             never_despawn: AtomicBool::new(false),
             never_pickup: AtomicBool::new(false),
         }
     }
 
-    async fn can_merge(&self) -> bool {
-        if self.never_pickup.load(Ordering::Relaxed) || self.entity.removed.load(Ordering::Relaxed)
+    /// Sends the item stack to viewers after its count changed
+    async fn send_item_stack(&self) {
+        self.entity
+            .send_meta_data(&[Metadata::new(
+                8,
+                MetaDataType::ItemStack,
+                &ItemStackSerializer::from(self.item_stack.lock().await.clone()),
+            )])
+            .await;
+    }
+
+    /// Whether the item is old enough to despawn
+    #[must_use]
+    pub const fn should_despawn(age: i32) -> bool {
+        age >= DESPAWN_AGE
+    }
+
+    /// Discards the item once it is too old. Returns whether it despawned.
+    async fn check_despawn(&self) -> bool {
+        if self.never_despawn.load(Ordering::Relaxed)
+            || !Self::should_despawn(self.entity.age.load(Ordering::Relaxed))
         {
             return false;
         }
+        self.entity.discard().await;
+        true
+    }
+
+    async fn can_merge(&self) -> bool {
+        if self.never_pickup.load(Ordering::Relaxed) || self.entity.is_removed() {
+            return false;
+        }
 
         let item_stack = self.item_stack.lock().await;
 
@@ -108,7 +141,7 @@ impl ItemEntity {
             if item.can_merge().await {
                 self.try_merge_with(&item).await;
 
-                if self.entity.removed.load(Ordering::SeqCst) {
+                if self.entity.is_removed() {
                     break;
                 }
             }
@@ -159,11 +192,12 @@ impl ItemEntity {
 
         if !never_despawn {
             let age = target
-                .item_age
+                .entity
+                .age
                 .load(Ordering::Relaxed)
-                .min(source.item_age.load(Ordering::Relaxed));
+                .min(source.entity.age.load(Ordering::Relaxed));
 
-            target.item_age.store(age, Ordering::Relaxed);
+            target.entity.age.store(age, Ordering::Relaxed);
         }
 
         let never_pickup = source.never_pickup.load(Ordering::Relaxed);
@@ -179,15 +213,15 @@ impl ItemEntity {
         }
 
         if empty1 {
-            target.entity.remove().await;
+            target.entity.discard().await;
         } else {
-            target.init_data_tracker().await;
+            target.send_item_stack().await;
         }
 
         if empty2 {
-            source.entity.remove().await;
+            source.entity.discard().await;
         } else {
-            source.init_data_tracker().await;
+            source.send_item_stack().await;
         }
     }
 }
@@ -198,7 +232,12 @@ impl NBTStorage for ItemEntity {}
 impl EntityBase for ItemEntity {
     async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
         let entity = &self.entity;
-        entity.tick(caller.clone(), server).await;
+        // e.g. summoned without an item
+        if self.item_stack.lock().await.is_empty() {
+            entity.discard().await;
+            return;
+        }
+        entity.base_tick(caller.clone(), server).await;
         {
             let mut delay = self.pickup_delay.lock().await;
             *delay = delay.saturating_sub(1);
@@ -208,26 +247,23 @@ impl EntityBase for ItemEntity {
 
         let mut velo = original_velo;
 
-        if entity.touching_water.load(Ordering::SeqCst) && entity.water_height.load() > 0.1 {
+        let in_water =
+            entity.touching_water.load(Ordering::SeqCst) && entity.water_height.load() > 0.1;
+        let in_lava =
+            entity.touching_lava.load(Ordering::SeqCst) && entity.lava_height.load() > 0.1;
+
+        if in_water {
             velo.x *= 0.99;
 
             velo.z *= 0.99;
-
-            if velo.y < 0.06 {
-                velo.y += 5.0e-4;
-            }
-        } else if entity.touching_lava.load(Ordering::SeqCst) && entity.lava_height.load() > 0.1 {
+        } else if in_lava {
             velo.x *= 0.95;
 
             velo.z *= 0.95;
-
-            if velo.y < 0.06 {
-                velo.y += 5.0e-4;
-            }
-        } else {
-            velo.y -= self.get_gravity();
         }
 
+        velo.y = Entity::apply_gravity(velo.y, self.get_gravity(), in_water || in_lava);
+
         entity.velocity.store(velo);
 
         let pos = entity.pos.load();
@@ -258,13 +294,9 @@ impl EntityBase for ItemEntity {
             !entity.on_ground.load(Ordering::SeqCst) || velo.horizontal_length_squared() > 1.0e-5;
 
         if !tick_move {
-            let Ok(item_age) = i32::try_from(self.item_age.load(Ordering::Relaxed)) else {
-                entity.remove().await;
-
-                return;
-            };
+            let age = entity.age.load(Ordering::Relaxed);
 
-            tick_move = (item_age + entity.entity_id) % 4 == 0;
+            tick_move = (age + entity.entity_id) % 4 == 0;
         }
 
         if tick_move {
@@ -272,17 +304,17 @@ impl EntityBase for ItemEntity {
 
             entity.tick_block_collisions(&caller, server).await;
 
-            let mut friction = 0.98;
+            let mut friction = AIR_DRAG;
 
             let on_ground = entity.on_ground.load(Ordering::SeqCst);
 
             if on_ground {
                 let block_affecting_velo = entity.get_block_with_y_offset(0.999_999).await.1;
 
-                friction *= f64::from(block_affecting_velo.slipperiness) * 0.98;
+                friction *= f64::from(block_affecting_velo.slipperiness) * AIR_DRAG;
             }
 
-            velo = velo.multiply(friction, 0.98, friction);
+            velo = velo.multiply(friction, AIR_DRAG, friction);
 
             if on_ground && velo.y < 0.0 {
                 velo = velo.multiply(1.0, -0.5, 1.0);
@@ -291,14 +323,12 @@ impl EntityBase for ItemEntity {
             entity.velocity.store(velo);
         }
 
-        if !self.never_despawn.load(Ordering::Relaxed) {
-            let age = self.item_age.fetch_add(1, Ordering::Relaxed) + 1;
-
-            if age >= 6000 {
-                entity.remove().await;
+        if self.check_despawn().await {
+            return;
+        }
 
-                return;
-            }
+        if !self.never_despawn.load(Ordering::Relaxed) {
+            let age = entity.age.load(Ordering::Relaxed);
 
             let n = if entity
                 .last_pos
@@ -312,7 +342,7 @@ impl EntityBase for ItemEntity {
                 2
             };
 
-            if age.is_multiple_of(n) && self.can_merge().await {
+            if age % n == 0 && self.can_merge().await {
                 self.try_merge().await;
             }
         }
@@ -341,28 +371,30 @@ impl EntityBase for ItemEntity {
     }
 
     async fn init_data_tracker(&self) {
-        self.entity
-            .send_meta_data(&[Metadata::new(
-                8,
-                MetaDataType::ItemStack,
-                &ItemStackSerializer::from(self.item_stack.lock().await.clone()),
-            )])
-            .await;
+        self.entity.init_tracked_data();
+        self.entity.data_tracker.track(
+            8,
+            MetaDataType::ItemStack,
+            &ItemStackSerializer::from(self.item_stack.lock().await.clone()),
+        );
     }
 
     async fn damage_with_context(
         &self,
         _caller: Arc<dyn EntityBase>,
         amount: f32,
-        _damage_type: DamageType,
+        damage_type: DamageType,
         _position: Option<Vector3<f64>>,
         _source: Option<&dyn EntityBase>,
         _cause: Option<&dyn EntityBase>,
     ) -> bool {
-        //TODO: invulnerability, e.g. ancient debris
+        if self.entity.is_invulnerable_to(&damage_type) {
+            return false;
+        }
+        //TODO: fire immune items, e.g. ancient debris
         self.health.store(self.health.load() - amount);
         if self.health.load() <= 0.0 {
-            self.entity.remove().await;
+            self.entity.discard().await;
         }
         true
     }
@@ -408,10 +440,10 @@ impl EntityBase for ItemEntity {
                 .await;
 
             if self.item_stack.lock().await.is_empty() {
-                self.entity.remove().await;
+                self.entity.discard().await;
             } else {
                 // Update entity
-                self.init_data_tracker().await;
+                self.send_item_stack().await;
             }
         }
     }
@@ -436,3 +468,65 @@ impl EntityBase for ItemEntity {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::Ordering;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_world::item::ItemStack;
+
+    use super::{DESPAWN_AGE, ItemEntity};
+    use crate::entity::{Entity, RemovalReason};
+    use crate::world::World;
+
+    async fn dirt(world: &std::sync::Arc<World>, count: u8) -> ItemEntity {
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ITEM,
+            false,
+        );
+        ItemEntity::new(entity, ItemStack::new(count, &Item::DIRT)).await
+    }
+
+    #[tokio::test]
+    async fn items_despawn_after_five_minutes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let item = dirt(&world, 1).await;
+
+        item.entity.age.store(DESPAWN_AGE - 1, Ordering::Relaxed);
+        assert!(!item.check_despawn().await);
+        assert!(item.entity.is_alive());
+
+        item.entity.age.store(DESPAWN_AGE, Ordering::Relaxed);
+        assert!(item.check_despawn().await);
+        assert!(item.entity.removal_reason.load() == Some(RemovalReason::Discarded));
+    }
+
+    #[tokio::test]
+    async fn dropped_stacks_merge_up_to_the_max_stack_size() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+
+        let big = dirt(&world, 40).await;
+        let small = dirt(&world, 20).await;
+        small.entity.age.store(100, Ordering::Relaxed);
+        big.try_merge_with(&small).await;
+        assert_eq!(big.item_stack.lock().await.item_count, 60);
+        assert!(small.entity.is_removed());
+        // The merged item is as old as the younger one
+        assert_eq!(big.entity.age.load(Ordering::Relaxed), 0);
+
+        // 60 + 10 wouldn't fit in a stack of 64
+        let rest = dirt(&world, 10).await;
+        big.try_merge_with(&rest).await;
+        assert_eq!(big.item_stack.lock().await.item_count, 60);
+        assert_eq!(rest.item_stack.lock().await.item_count, 10);
+        assert!(rest.entity.is_alive());
+    }
+}
@@ -1,8 +1,9 @@
 use pumpkin_data::potion::Effect;
 use pumpkin_inventory::build_equipment_slots;
 use pumpkin_inventory::player::player_inventory::PlayerInventory;
-use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::{GameMode, Hand};
+use std::f64::consts::PI;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::{
@@ -11,32 +12,53 @@ use std::sync::atomic::{
 };
 use std::{collections::HashMap, sync::atomic::AtomicI32};
 
+use super::attributes::{AttributeManager, effect_modifiers, item_modifiers};
+use super::physics::{
+    AIR_DRAG, AIR_FRICTION, FLUID_GRAVITY_FACTOR, FLUID_VERTICAL_DRAG, GROUND_FRICTION,
+    GROUND_SPEED_FACTOR, LAVA_DRAG, LIVING_GRAVITY, MOVEMENT_INPUT_DECAY, OFF_GROUND_SPEED,
+    SWIM_UP_SPEED,
+};
+use super::player::Player;
 use super::{Entity, NBTStorage};
-use super::{EntityBase, NBTStorageInit};
+use super::{EntityBase, InteractionResult, NBTStorageInit};
+use crate::PLUGIN_MANAGER;
+use crate::plugin::entity::entity_damage::EntityDamageEvent;
+use crate::plugin::entity::entity_death::EntityDeathEvent;
 use crate::server::Server;
 use crate::world::loot::{LootContextParameters, LootTableExt};
 use async_trait::async_trait;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_config::advanced_config;
-use pumpkin_data::Block;
+use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DeathMessageType;
-use pumpkin_data::data_component_impl::{DeathProtectionImpl, EquipmentSlot, FoodImpl};
+use pumpkin_data::data_component_impl::{
+    AttributeModifiersImpl, BlocksAttacksImpl, ConsumableImpl, ConsumeEffect, CustomNameImpl,
+    DeathProtectionImpl, EquipmentSlot, FoodImpl,
+};
 use pumpkin_data::effect::StatusEffect;
-use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
+use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType, MobCategory};
+use pumpkin_data::item::Item;
 use pumpkin_data::sound::SoundCategory;
+use pumpkin_data::tag::{self, RegistryKey, Taggable, get_tag_values};
+use pumpkin_data::{Block, Enchantment};
 use pumpkin_data::{damage::DamageType, sound::Sound};
 use pumpkin_inventory::entity_equipment::EntityEquipment;
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_nbt::tag::NbtTag;
 use pumpkin_protocol::codec::var_int::VarInt;
-use pumpkin_protocol::java::client::play::{CHurtAnimation, CTakeItemEntity};
+use pumpkin_protocol::java::client::play::{
+    AttributeModifierProperty, AttributeProperty, CCooldown, CHurtAnimation, CTakeItemEntity,
+    CUpdateAttributes,
+};
 use pumpkin_protocol::{
     codec::item_stack_seralizer::ItemStackSerializer,
     java::client::play::{CDamageEvent, CSetEquipment, MetaDataType, Metadata},
 };
 use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::resource_location::ResourceLocation;
 use pumpkin_util::text::TextComponent;
 use pumpkin_world::item::ItemStack;
+use rand::Rng;
 use tokio::sync::Mutex;
 
 /// Represents a living entity within the game world.
@@ -53,6 +75,8 @@ pub struct LivingEntity {
     pub health: AtomicCell<f32>,
     pub item_use_time: AtomicI32,
     pub item_in_use: Mutex<Option<ItemStack>>,
+    /// Ticks until the entity can block again after its shield was disabled, e.g. by an axe
+    pub blocking_cooldown: AtomicI32,
     pub death_time: AtomicU8,
     /// Indicates whether the entity is dead. (`on_death` called)
     pub dead: AtomicBool,
@@ -74,6 +98,9 @@ pub struct LivingEntity {
     /// The position where the entity was last climbing, used for death messages
     pub climbing_pos: AtomicCell<Option<BlockPos>>,
 
+    /// The base values of the entity's attributes, e.g. max health or attack speed
+    pub attribute_manager: AttributeManager,
+
     water_movement_speed_multiplier: f32,
     livings_flags: AtomicU8,
 }
@@ -102,6 +129,7 @@ impl LivingEntity {
 
         // TODO: Extract default MOVEMENT_SPEED Entity Attribute
         let default_movement_speed = 0.25;
+        let attribute_manager = AttributeManager::new(entity.entity_type);
         Self {
             entity,
             hurt_cooldown: AtomicI32::new(0),
@@ -112,6 +140,7 @@ impl LivingEntity {
             dead: AtomicBool::new(false),
             item_use_time: AtomicI32::new(0),
             item_in_use: Mutex::new(None),
+            blocking_cooldown: AtomicI32::new(0),
             livings_flags: AtomicU8::new(0),
             active_effects: Mutex::new(HashMap::new()),
             entity_equipment: Arc::new(Mutex::new(EntityEquipment::new())),
@@ -122,12 +151,28 @@ impl LivingEntity {
             climbing_pos: AtomicCell::new(None),
             movement_input: AtomicCell::new(Vector3::default()),
             movement_speed: AtomicCell::new(default_movement_speed),
+            attribute_manager,
             water_movement_speed_multiplier,
         }
     }
 
     pub async fn send_equipment_changes(&self, equipment: &[(EquipmentSlot, ItemStack)]) {
-        let equipment: Vec<(i8, ItemStackSerializer)> = equipment
+        self.entity
+            .world
+            .broadcast_packet_except(
+                &[self.entity.entity_uuid],
+                &CSetEquipment::new(
+                    self.entity_id().into(),
+                    Self::serialize_equipment(equipment),
+                ),
+            )
+            .await;
+    }
+
+    fn serialize_equipment(
+        equipment: &[(EquipmentSlot, ItemStack)],
+    ) -> Vec<(i8, ItemStackSerializer<'static>)> {
+        equipment
             .iter()
             .map(|(slot, stack)| {
                 (
@@ -135,16 +180,102 @@ impl LivingEntity {
                     ItemStackSerializer::from(stack.clone()),
                 )
             })
-            .collect();
+            .collect()
+    }
+
+    /// Equips all given slots at once, observers receive the changes in a single packet
+    pub async fn set_equipment(&self, changes: &[(EquipmentSlot, ItemStack)]) {
+        let mut equipment = self.entity_equipment.lock().await;
+        for (slot, stack) in changes {
+            equipment.put(slot, stack.clone()).await;
+        }
+        drop(equipment);
+        self.send_equipment_changes(changes).await;
+    }
+
+    /// The equipment of every occupied slot, for players which start tracking this entity.
+    /// Returns `None` if the entity has nothing equipped.
+    pub async fn equipment_packet(&self) -> Option<CSetEquipment> {
+        let mut stacks = Vec::new();
+        for (slot, stack) in &self.entity_equipment.lock().await.equipment {
+            stacks.push((slot.clone(), stack.lock().await.clone()));
+        }
+        let occupied = Self::occupied_equipment(stacks);
+        (!occupied.is_empty()).then(|| {
+            CSetEquipment::new(
+                self.entity_id().into(),
+                Self::serialize_equipment(&occupied),
+            )
+        })
+    }
+
+    /// Returns the value of the attribute with the modifiers added by commands, of the equipment
+    /// and of the active effects applied together.
+    pub async fn get_attribute_value(&self, attribute: &'static Attributes) -> f64 {
+        let mut modifiers = Vec::new();
+        for (slot, stack) in &self.entity_equipment.lock().await.equipment {
+            let stack = stack.lock().await;
+            if let Some(item) = stack.get_data_component::<AttributeModifiersImpl>() {
+                modifiers.extend(item_modifiers(&item.attribute_modifiers, slot, attribute));
+            }
+        }
+        for effect in self.active_effects.lock().await.values() {
+            modifiers.extend(effect_modifiers(
+                effect.effect_type,
+                effect.amplifier,
+                attribute,
+            ));
+        }
+        self.attribute_manager.get_modified(attribute, modifiers)
+    }
+
+    /// Lets clients know about a changed attribute, e.g. after `/attribute`. A changed scale also
+    /// resizes the bounding box, which is only sent if the size actually changed.
+    pub async fn update_attribute(&self, attribute: &'static Attributes) {
+        if attribute == &Attributes::SCALE {
+            let scale = self.attribute_manager.get_modified(attribute, []) as f32;
+            if !self.entity.set_scale(scale) {
+                return;
+            }
+        }
         self.entity
             .world
-            .broadcast_packet_except(
-                &[self.entity.entity_uuid],
-                &CSetEquipment::new(self.entity_id().into(), equipment),
-            )
+            .broadcast_packet_all(&self.attribute_packet(attribute))
             .await;
     }
 
+    /// The base value and modifiers of `attribute`, clients work out the value themselves
+    #[must_use]
+    pub fn attribute_packet(&self, attribute: &'static Attributes) -> CUpdateAttributes {
+        let modifiers = self
+            .attribute_manager
+            .get_modifiers(attribute)
+            .into_iter()
+            .map(|modifier| AttributeModifierProperty {
+                id: modifier.id,
+                amount: modifier.amount,
+                operation: modifier.operation as u8,
+            })
+            .collect();
+        CUpdateAttributes::new(
+            self.entity_id().into(),
+            vec![AttributeProperty {
+                id: VarInt(i32::from(attribute.id)),
+                base: self.attribute_manager.get_base(attribute),
+                modifiers,
+            }],
+        )
+    }
+
+    /// Drops empty slots and orders the rest like the client lists them
+    fn occupied_equipment(
+        mut stacks: Vec<(EquipmentSlot, ItemStack)>,
+    ) -> Vec<(EquipmentSlot, ItemStack)> {
+        stacks.retain(|(_, stack)| !stack.is_empty());
+        stacks.sort_by_key(|(slot, _)| slot.discriminant());
+        stacks
+    }
+
     /// Picks up and Item entity or XP Orb
     pub async fn pickup(&self, item: &Entity, stack_amount: u32) {
         // TODO: Only nearby
@@ -164,7 +295,8 @@ impl LivingEntity {
             .store(stack.get_max_use_time(), Ordering::Relaxed);
         *self.item_in_use.lock().await = Some(stack);
         self.set_living_flag(Self::USING_ITEM_FLAG, true).await;
-        self.set_living_flag(Self::OFF_HAND_ACTIVE_FLAG, hand == Hand::Left)
+        // Like in the use item packet, the left hand is the main hand
+        self.set_living_flag(Self::OFF_HAND_ACTIVE_FLAG, hand == Hand::Right)
             .await;
     }
 
@@ -182,6 +314,313 @@ impl LivingEntity {
             .await;
     }
 
+    /// The hand the entity is currently using an item with
+    pub fn get_active_hand(&self) -> Hand {
+        if self.livings_flags.load(Ordering::Relaxed) & Self::OFF_HAND_ACTIVE_FLAG as u8 != 0 {
+            Hand::Right
+        } else {
+            Hand::Left
+        }
+    }
+
+    /// Returns the item the entity is blocking with, e.g. a shield that has been raised long enough.
+    pub async fn get_blocking_item(&self) -> Option<ItemStack> {
+        if self.blocking_cooldown.load(Relaxed) > 0 {
+            return None;
+        }
+        let item_in_use = self.item_in_use.lock().await;
+        let item = item_in_use.as_ref()?;
+        let blocks_attacks = item.get_data_component::<BlocksAttacksImpl>()?;
+        let used_ticks = item.get_max_use_time() - self.item_use_time.load(Relaxed);
+        (used_ticks >= blocks_attacks.block_delay_ticks()).then(|| item.clone())
+    }
+
+    /// The angle in radians between the horizontal look direction and the direction towards `target`.
+    #[must_use]
+    pub fn horizontal_angle_to(pos: Vector3<f64>, yaw: f32, target: Vector3<f64>) -> f64 {
+        let look = Vector3::rotation_vector(0.0, f64::from(yaw));
+        let direction = target.sub(&pos);
+        let length = direction.horizontal_length();
+        if length < 1.0e-5 {
+            return PI;
+        }
+        let cos = (direction.x * look.x + direction.z * look.z) / length;
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    /// Returns how much of the damage is blocked by the item the entity is blocking with.
+    /// Plays the block sound and disables blocking if the attacker hit with an axe.
+    async fn block_damage(
+        &self,
+        caller: &Arc<dyn EntityBase>,
+        amount: f32,
+        damage_type: DamageType,
+        position: Option<Vector3<f64>>,
+        source: Option<&dyn EntityBase>,
+    ) -> f32 {
+        let Some(item) = self.get_blocking_item().await else {
+            return 0.0;
+        };
+        let Some(blocks_attacks) = item.get_data_component::<BlocksAttacksImpl>() else {
+            return 0.0;
+        };
+        if blocks_attacks
+            .bypassed_by
+            .is_some_and(|tag| is_damage_type_in(damage_type, tag))
+        {
+            return 0.0;
+        }
+
+        let source_pos = position.or_else(|| source.map(|source| source.get_entity().pos.load()));
+        // Attacks without a position, e.g. from magic, can't be blocked
+        let angle = source_pos.map_or(PI, |source_pos| {
+            Self::horizontal_angle_to(self.entity.pos.load(), self.entity.yaw.load(), source_pos)
+        });
+        let blocked = blocks_attacks.resolve_blocked_damage(amount, angle);
+        if blocked <= 0.0 {
+            return 0.0;
+        }
+
+        if let Some(sound) = blocks_attacks.block_sound.and_then(sound_from_name) {
+            self.entity
+                .world
+                .play_sound(sound, SoundCategory::Players, &self.entity.pos.load())
+                .await;
+        }
+
+        // TODO: Damage the blocking item
+        if let Some(source) = source
+            && !is_damage_type_in(damage_type, "#minecraft:is_projectile")
+            && self.attacker_disables_blocking(source).await
+        {
+            self.disable_blocking(caller, &item, blocks_attacks, AXE_DISABLE_BLOCKING_SECONDS)
+                .await;
+        }
+
+        blocked
+    }
+
+    async fn attacker_disables_blocking(&self, attacker: &dyn EntityBase) -> bool {
+        // TODO: Use the weapon component once it is implemented, mobs can also hold axes
+        let Some(player) = self
+            .entity
+            .world
+            .get_player_by_id(attacker.get_entity().entity_id)
+            .await
+        else {
+            return false;
+        };
+        player
+            .inventory
+            .held_item()
+            .lock()
+            .await
+            .item
+            .is_tagged_with_by_tag(&tag::Item::MINECRAFT_AXES)
+    }
+
+    /// Stops the entity from blocking for `seconds` scaled by the blocking item's disable cooldown scale.
+    pub async fn disable_blocking(
+        &self,
+        caller: &Arc<dyn EntityBase>,
+        item: &ItemStack,
+        blocks_attacks: &BlocksAttacksImpl,
+        seconds: f32,
+    ) {
+        let ticks = (seconds * blocks_attacks.disable_cooldown_scale * 20.0) as i32;
+        if ticks <= 0 {
+            return;
+        }
+        self.blocking_cooldown.store(ticks, Relaxed);
+        self.clear_active_hand().await;
+
+        if let Some(player) = caller.get_player() {
+            player
+                .client
+                .enqueue_packet(&CCooldown::new(
+                    ResourceLocation::vanilla(item.item.registry_key),
+                    ticks.into(),
+                ))
+                .await;
+        }
+        if let Some(sound) = blocks_attacks.disabled_sound.and_then(sound_from_name) {
+            self.entity
+                .world
+                .play_sound(sound, SoundCategory::Players, &self.entity.pos.load())
+                .await;
+        }
+    }
+
+    /// Counts down the use time of the active item and consumes it once it reaches zero.
+    async fn tick_active_item(&self, caller: &dyn EntityBase, item: &ItemStack) {
+        let Some(player) = caller.get_player() else {
+            if self.item_use_time.fetch_sub(1, Ordering::Relaxed) <= 1 {
+                self.clear_active_hand().await;
+            }
+            return;
+        };
+
+        let hand_stack = if self.get_active_hand() == Hand::Left {
+            player.inventory.held_item()
+        } else {
+            player.inventory.off_hand_item().await
+        };
+        let mut hand_stack = hand_stack.lock().await;
+        // Stop eating when the item is no longer held, e.g. after switching slots
+        if hand_stack.is_empty() || hand_stack.item != item.item {
+            drop(hand_stack);
+            self.clear_active_hand().await;
+            return;
+        }
+
+        if self.item_use_time.fetch_sub(1, Ordering::Relaxed) > 1 {
+            return;
+        }
+
+        // Blocking items just stop being used, they aren't consumed
+        if item.get_data_component::<BlocksAttacksImpl>().is_some() {
+            drop(hand_stack);
+            self.clear_active_hand().await;
+            return;
+        }
+
+        // Consume item
+        if let Some(food) = item.get_data_component::<FoodImpl>() {
+            player.hunger_manager.eat(player, food).await;
+        }
+        hand_stack.decrement_unless_creative(player.gamemode.load(), 1);
+        drop(hand_stack);
+
+        if let Some(consumable) = item.get_data_component::<ConsumableImpl>() {
+            self.apply_consume_effects(player, consumable).await;
+        }
+
+        self.clear_active_hand().await;
+    }
+
+    /// Applies the `on_consume_effects` of an item the player finished consuming
+    async fn apply_consume_effects(&self, player: &Player, consumable: &ConsumableImpl) {
+        let world = &self.entity.world;
+        for consume_effect in consumable.on_consume_effects {
+            match consume_effect {
+                ConsumeEffect::ApplyEffects {
+                    effects,
+                    probability,
+                } => {
+                    if rand::rng().random::<f32>() >= *probability {
+                        continue;
+                    }
+                    for effect in *effects {
+                        let Some(effect_type) = StatusEffect::from_name(effect.id) else {
+                            continue;
+                        };
+                        player
+                            .add_effect(Effect {
+                                effect_type,
+                                duration: effect.duration,
+                                amplifier: effect.amplifier,
+                                ambient: effect.ambient,
+                                show_particles: effect.show_particles,
+                                show_icon: effect.show_icon,
+                                blend: false,
+                            })
+                            .await;
+                    }
+                }
+                ConsumeEffect::RemoveEffects(names) => {
+                    for effect_type in names
+                        .iter()
+                        .filter_map(|name| StatusEffect::from_name(name))
+                    {
+                        if self.has_effect(effect_type).await {
+                            player.remove_effect(effect_type).await;
+                        }
+                    }
+                }
+                ConsumeEffect::ClearAllEffects => {
+                    player.remove_all_effect().await;
+                }
+                ConsumeEffect::TeleportRandomly { diameter } => {
+                    let from = self.entity.pos.load();
+                    let Some(target) = self.random_teleport_target(f64::from(*diameter)).await
+                    else {
+                        continue;
+                    };
+                    let Some(player) = world.get_player_by_id(player.entity_id()).await else {
+                        continue;
+                    };
+                    EntityBase::teleport(player, target, None, None, world.clone(), false).await;
+                    for pos in [from, target] {
+                        world
+                            .play_sound(
+                                Sound::ItemChorusFruitTeleport,
+                                SoundCategory::Players,
+                                &pos,
+                            )
+                            .await;
+                    }
+                    self.fall_distance.store(0.0);
+                }
+                ConsumeEffect::PlaySound(name) => {
+                    if let Some(sound) = sound_from_name(name) {
+                        world
+                            .play_sound(sound, SoundCategory::Players, &self.entity.pos.load())
+                            .await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Picks where a chorus fruit takes the entity: the first of 16 random spots within
+    /// `diameter` blocks that has ground below and room without fluids, like vanilla's
+    /// `LivingEntity.teleport`.
+    pub async fn random_teleport_target(&self, diameter: f64) -> Option<Vector3<f64>> {
+        let world = &self.entity.world;
+        let min_y = f64::from(world.min_y);
+        let max_y = f64::from(i32::from(world.generation_settings().shape.max_y()) - 1);
+        let pos = self.entity.pos.load();
+        let size = self.entity.bounding_box_size.load();
+        let offset = self.entity.bounding_box_offset.load();
+
+        for _ in 0..16 {
+            let (x, y, z) = {
+                let mut rng = rand::rng();
+                (
+                    pos.x + (rng.random::<f64>() - 0.5) * diameter,
+                    (pos.y + (rng.random::<f64>() - 0.5) * diameter).clamp(min_y, max_y),
+                    pos.z + (rng.random::<f64>() - 0.5) * diameter,
+                )
+            };
+            let mut block_pos = Vector3::new(x, y, z).to_block_pos();
+            let mut target_y = y;
+            let mut on_ground = false;
+            // Drop down onto the first block below
+            while block_pos.0.y > world.min_y {
+                let below = block_pos.down();
+                if world.get_block_state(&below).await.is_solid() {
+                    on_ground = true;
+                    break;
+                }
+                target_y -= 1.0;
+                block_pos = below;
+            }
+            if !on_ground {
+                continue;
+            }
+
+            let target = Vector3::new(x, target_y, z);
+            if world
+                .is_space_empty(Entity::bounding_box_at(target, &size, offset))
+                .await
+                && !world.get_block_state(&block_pos).await.is_liquid()
+            {
+                return Some(target);
+            }
+        }
+        None
+    }
+
     pub async fn clear_active_hand(&self) {
         *self.item_in_use.lock().await = None;
         self.item_use_time.store(0, Ordering::Relaxed);
@@ -195,6 +634,18 @@ impl LivingEntity {
             .await;
     }
 
+    /// Registers the metadata entries of living entities on top of the [`Entity`] ones
+    pub fn init_tracked_data(&self) {
+        self.entity.init_tracked_data();
+        let tracker = &self.entity.data_tracker;
+        tracker.track(
+            8,
+            MetaDataType::Byte,
+            self.livings_flags.load(Ordering::Relaxed),
+        );
+        tracker.track(9, MetaDataType::Float, self.health.load());
+    }
+
     pub async fn set_health(&self, health: f32) {
         self.health.store(health.max(0.0));
         // tell everyone entities health changed
@@ -272,9 +723,9 @@ impl LivingEntity {
 
         let mut movement_input = self.movement_input.load();
 
-        movement_input.x *= 0.98;
+        movement_input.x *= MOVEMENT_INPUT_DECAY;
 
-        movement_input.z *= 0.98;
+        movement_input.z *= MOVEMENT_INPUT_DECAY;
 
         self.movement_input.store(movement_input);
 
@@ -300,7 +751,7 @@ impl LivingEntity {
 
                 let mut velo = self.entity.velocity.load();
 
-                velo.y += 0.04;
+                velo.y += SWIM_UP_SPEED;
 
                 self.entity.velocity.store(velo);
             } else if (on_ground || in_water && fluid_height <= swim_height)
@@ -340,7 +791,240 @@ impl LivingEntity {
         let suffocating = self.entity.tick_block_collisions(&caller, server).await;
 
         if suffocating {
-            self.damage(caller, 1.0, DamageType::IN_WALL).await;
+            self.damage(caller.clone(), 1.0, DamageType::IN_WALL).await;
+        }
+
+        self.tick_freezing(caller).await;
+    }
+
+    /// Whether the entity can stay underwater without running out of air
+    async fn can_breathe_in_water(&self, caller: &Arc<dyn EntityBase>) -> bool {
+        let entity_type = self.entity.entity_type.id;
+        if tag::EntityType::MINECRAFT_CAN_BREATHE_UNDER_WATER
+            .1
+            .contains(&entity_type)
+            || tag::EntityType::MINECRAFT_AQUATIC.1.contains(&entity_type)
+            || self.has_effect(&StatusEffect::WATER_BREATHING).await
+            || self.has_effect(&StatusEffect::CONDUIT_POWER).await
+        {
+            return true;
+        }
+        if let Some(player) = caller.get_player() {
+            return player.abilities.lock().await.invulnerable;
+        }
+        false
+    }
+
+    /// Returns the air supply after one tick underwater and whether the entity takes drowning damage.
+    /// Every level of Respiration gives a chance to not lose air.
+    #[must_use]
+    pub fn next_air_underwater(air_supply: i32, respiration: i32) -> (i32, bool) {
+        if respiration > 0 && rand::rng().random_range(0..=respiration) > 0 {
+            return (air_supply, false);
+        }
+        let air_supply = air_supply - 1;
+        if air_supply <= -20 {
+            (0, true)
+        } else {
+            (air_supply, false)
+        }
+    }
+
+    /// Returns the air supply after one tick out of water
+    #[must_use]
+    pub fn next_air_on_land(air_supply: i32) -> i32 {
+        (air_supply + 4).min(super::MAX_AIR_SUPPLY)
+    }
+
+    async fn tick_air(&self, caller: &Arc<dyn EntityBase>) {
+        let air_supply = self.entity.air_supply.load(Relaxed);
+        if caller.is_aquatic() {
+            // Aquatic mobs refill their air in water at once and drown out of it
+            if self.entity.touching_water.load(SeqCst) {
+                self.entity.set_air_supply(super::MAX_AIR_SUPPLY).await;
+                return;
+            }
+            let (air_supply, drown) = Self::next_air_underwater(air_supply, 0);
+            self.entity.set_air_supply(air_supply).await;
+            if drown {
+                self.damage(caller.clone(), 2.0, DamageType::DROWN).await;
+            }
+        } else if self.entity.is_eye_in_water().await {
+            if self.can_breathe_in_water(caller).await {
+                return;
+            }
+            let respiration = {
+                let equipment = self.entity_equipment.lock().await;
+                let helmet = equipment.get(&EquipmentSlot::HEAD);
+                helmet
+                    .lock()
+                    .await
+                    .get_enchantment_level(&Enchantment::RESPIRATION)
+            };
+            let (air_supply, drown) = Self::next_air_underwater(air_supply, respiration);
+            self.entity.set_air_supply(air_supply).await;
+            if drown {
+                self.damage(caller.clone(), 2.0, DamageType::DROWN).await;
+            }
+        } else if air_supply < super::MAX_AIR_SUPPLY {
+            self.entity
+                .set_air_supply(Self::next_air_on_land(air_supply))
+                .await;
+        }
+    }
+
+    /// Whether the entity is able to freeze in powder snow
+    async fn can_freeze(&self, caller: &Arc<dyn EntityBase>) -> bool {
+        if caller.is_spectator()
+            || tag::EntityType::MINECRAFT_FREEZE_IMMUNE_ENTITY_TYPES
+                .1
+                .contains(&self.entity.entity_type.id)
+        {
+            return false;
+        }
+        let equipment = self.entity_equipment.lock().await;
+        for slot in [
+            EquipmentSlot::HEAD,
+            EquipmentSlot::CHEST,
+            EquipmentSlot::LEGS,
+            EquipmentSlot::FEET,
+        ] {
+            let stack = equipment.get(&slot);
+            if stack
+                .lock()
+                .await
+                .item
+                .is_tagged_with_by_tag(&tag::Item::MINECRAFT_FREEZE_IMMUNE_WEARABLES)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    async fn tick_freezing(&self, caller: Arc<dyn EntityBase>) {
+        let frozen_ticks = self.entity.frozen_ticks.load(Relaxed);
+        let frozen_ticks =
+            if self.entity.in_powder_snow.load(Relaxed) && self.can_freeze(&caller).await {
+                (frozen_ticks + 1).min(super::MIN_FREEZE_DAMAGE_TICKS)
+            } else {
+                (frozen_ticks - 2).max(0)
+            };
+        self.entity.set_frozen_ticks(frozen_ticks).await;
+
+        if self.entity.age.load(Relaxed) % 40 == 0 && frozen_ticks >= super::MIN_FREEZE_DAMAGE_TICKS
+        {
+            let amount = if tag::EntityType::MINECRAFT_FREEZE_HURTS_EXTRA_TYPES
+                .1
+                .contains(&self.entity.entity_type.id)
+            {
+                5.0
+            } else {
+                1.0
+            };
+            self.damage(caller, amount, DamageType::FREEZE).await;
+        }
+    }
+
+    /// Level of Soul Speed on the boots of the entity
+    pub async fn get_soul_speed_level(&self) -> i32 {
+        let equipment = self.entity_equipment.lock().await;
+        let boots = equipment.get(&EquipmentSlot::FEET);
+        boots
+            .lock()
+            .await
+            .get_enchantment_level(&Enchantment::SOUL_SPEED)
+    }
+
+    /// Movement speed added by Soul Speed while walking on soul sand or soul soil
+    #[must_use]
+    pub fn soul_speed_bonus(level: i32) -> f64 {
+        if level <= 0 {
+            return 0.0;
+        }
+        0.03 * (1.0 + f64::from(level) * 0.35)
+    }
+
+    /// Returns the movement speed bonus of Soul Speed on the given block, wearing down the boots
+    /// every now and then unless `caller` is a player in creative mode
+    async fn tick_soul_speed(&self, caller: &dyn EntityBase, block: &Block) -> f64 {
+        if !block.is_tagged_with_by_tag(&tag::Block::MINECRAFT_SOUL_SPEED_BLOCKS) {
+            return 0.0;
+        }
+        let level = self.get_soul_speed_level().await;
+        if level <= 0 {
+            return 0.0;
+        }
+        let velocity = self.entity.velocity.load();
+        let moving = velocity.x != 0.0 || velocity.z != 0.0;
+        let creative = caller
+            .get_player()
+            .is_some_and(|player| player.gamemode.load() == GameMode::Creative);
+        if moving && !creative && rand::rng().random::<f32>() < 0.04 {
+            let equipment = self.entity_equipment.lock().await;
+            let boots = equipment.get(&EquipmentSlot::FEET);
+            let mut boots = boots.lock().await;
+            boots.damage_item(1);
+            let boots = boots.clone();
+            drop(equipment);
+            self.send_equipment_changes(&[(EquipmentSlot::FEET, boots)])
+                .await;
+        }
+        Self::soul_speed_bonus(level)
+    }
+
+    /// Rolls the Thorns enchantment of an armor piece, each level giving a 15% chance to reflect
+    /// 1-4 damage. A triggered armor piece loses 2 durability.
+    pub fn roll_thorns(stack: &mut ItemStack, rng: &mut impl Rng) -> Option<f32> {
+        let level = stack.get_enchantment_level(&Enchantment::THORNS);
+        if level <= 0 || rng.random::<f32>() >= 0.15 * level as f32 {
+            return None;
+        }
+        stack.damage_item(2);
+        Some(rng.random_range(1..=4) as f32)
+    }
+
+    /// Reflects Thorns damage from the armor of this entity back to the attacker, each armor piece
+    /// hurting it on its own like vanilla's `ThornsEnchantment.onUserDamaged`
+    async fn apply_thorns(&self, caller: &Arc<dyn EntityBase>, attacker: &dyn EntityBase) {
+        let mut reflected = Vec::new();
+        let mut changes = Vec::new();
+        let equipment = self.entity_equipment.lock().await;
+        for slot in [
+            EquipmentSlot::HEAD,
+            EquipmentSlot::CHEST,
+            EquipmentSlot::LEGS,
+            EquipmentSlot::FEET,
+        ] {
+            let stack = equipment.get(&slot);
+            let mut stack = stack.lock().await;
+            if let Some(damage) = Self::roll_thorns(&mut stack, &mut rand::rng()) {
+                reflected.push(damage);
+                changes.push((slot, stack.clone()));
+            }
+        }
+        drop(equipment);
+        if changes.is_empty() {
+            return;
+        }
+        self.send_equipment_changes(&changes).await;
+
+        // The attacker may only be passed as its bare `Entity`, so look up the full entity to damage it
+        let attacker_id = attacker.get_entity().entity_id;
+        let Some(attacker) = self.entity.world.get_entity_by_id(attacker_id).await else {
+            return;
+        };
+        for damage in reflected {
+            attacker
+                .damage_with_context(
+                    attacker.clone(),
+                    damage,
+                    DamageType::THORNS,
+                    None,
+                    Some(caller.as_ref()),
+                    Some(caller.as_ref()),
+                )
+                .await;
         }
     }
 
@@ -350,28 +1034,25 @@ impl LivingEntity {
         let (speed, friction) = if self.entity.on_ground.load(SeqCst) {
             // getVelocityAffectingPos
 
-            let slipperiness = f64::from(
-                self.entity
-                    .get_block_with_y_offset(0.500_001)
-                    .await
-                    .1
-                    .slipperiness,
-            );
+            let block = self.entity.get_block_with_y_offset(0.500_001).await.1;
+            let slipperiness = f64::from(block.slipperiness);
 
+            let movement_speed =
+                self.movement_speed.load() + self.tick_soul_speed(caller.as_ref(), block).await;
             let speed =
-                self.movement_speed.load() * 0.216 / (slipperiness * slipperiness * slipperiness);
+                movement_speed * GROUND_SPEED_FACTOR / (slipperiness * slipperiness * slipperiness);
 
-            (speed, slipperiness * 0.91)
+            (speed, slipperiness * GROUND_FRICTION)
         } else {
             let speed = if let Some(player) = caller.get_player() {
                 player.get_off_ground_speed().await
             } else {
                 // TODO: If the passenger is a player, ogs = movement_speed * 0.1
 
-                0.02
+                OFF_GROUND_SPEED
             };
 
-            (speed, 0.91)
+            (speed, AIR_FRICTION)
         };
 
         self.entity
@@ -412,7 +1093,7 @@ impl LivingEntity {
         velo.y *= if caller.is_flutterer() {
             friction
         } else {
-            0.98
+            AIR_DRAG
         };
 
         self.entity.velocity.store(velo);
@@ -425,7 +1106,12 @@ impl LivingEntity {
 
         let falling = self.entity.velocity.load().y <= 0.0;
 
-        let gravity = self.get_effective_gravity(&caller).await;
+        // Aquatic mobs are carried by their buoyancy instead
+        let gravity = if water && caller.is_aquatic() {
+            0.0
+        } else {
+            self.get_effective_gravity(&caller).await
+        };
 
         if water {
             let mut friction = if self.entity.sprinting.load(Relaxed) {
@@ -434,7 +1120,7 @@ impl LivingEntity {
                 f64::from(self.water_movement_speed_multiplier)
             };
 
-            let mut speed = 0.02;
+            let mut speed = OFF_GROUND_SPEED;
 
             let mut water_movement_efficiency = 0.0; // TODO: Entity attribute
 
@@ -463,28 +1149,39 @@ impl LivingEntity {
                 velo.y = 0.2;
             }
 
-            velo = velo.multiply(friction, 0.8, friction);
+            velo = velo.multiply(friction, FLUID_VERTICAL_DRAG, friction);
 
-            self.apply_fluid_moving_speed(&mut velo.y, gravity, falling);
+            velo.y = Self::fluid_falling_speed(
+                velo.y,
+                gravity,
+                falling,
+                self.entity.sprinting.load(Relaxed),
+            );
 
             self.entity.velocity.store(velo);
         } else {
-            self.entity.update_velocity_from_input(movement_input, 0.02);
+            self.entity
+                .update_velocity_from_input(movement_input, OFF_GROUND_SPEED);
 
             self.make_move(caller).await;
 
             let mut velo = self.entity.velocity.load();
 
             if self.entity.lava_height.load() <= self.get_swim_height() {
-                velo.x *= 0.5;
+                velo.x *= LAVA_DRAG;
 
-                velo.z *= 0.5;
+                velo.z *= LAVA_DRAG;
 
-                velo.y *= 0.8;
+                velo.y *= FLUID_VERTICAL_DRAG;
 
-                self.apply_fluid_moving_speed(&mut velo.y, gravity, falling);
+                velo.y = Self::fluid_falling_speed(
+                    velo.y,
+                    gravity,
+                    falling,
+                    self.entity.sprinting.load(Relaxed),
+                );
             } else {
-                velo = velo * 0.5;
+                velo = velo * LAVA_DRAG;
             }
 
             if gravity != 0.0 {
@@ -511,13 +1208,18 @@ impl LivingEntity {
         }
     }
 
-    fn apply_fluid_moving_speed(&self, dy: &mut f64, gravity: f64, falling: bool) {
-        if gravity != 0.0 && !self.entity.sprinting.load(Relaxed) {
-            if falling && (*dy - 0.005).abs() >= 0.003 && (*dy - gravity / 16.0).abs() < 0.003 {
-                *dy = -0.003;
-            } else {
-                *dy -= gravity / 16.0;
-            }
+    /// Vertical velocity after one tick of gravity in a fluid. Buoyancy carries most of the
+    /// weight, so entities only sink with a fraction of their gravity, and swimmers not at all.
+    #[must_use]
+    pub fn fluid_falling_speed(dy: f64, gravity: f64, falling: bool, sprinting: bool) -> f64 {
+        if gravity == 0.0 || sprinting {
+            return dy;
+        }
+        let fluid_gravity = gravity * FLUID_GRAVITY_FACTOR;
+        if falling && (dy - 0.005).abs() >= 0.003 && (dy - fluid_gravity).abs() < 0.003 {
+            -0.003
+        } else {
+            dy - fluid_gravity
         }
     }
 
@@ -659,10 +1361,12 @@ impl LivingEntity {
 
         if self.entity.sprinting.load(Relaxed) {
             let yaw = f64::from(self.entity.yaw.load()).to_radians();
+            // Honey holds back the sprint boost just like the jump itself
+            let boost = 0.2 * f64::from(self.entity.get_jump_velocity_multiplier().await);
 
-            velo.x -= yaw.sin() * 0.2;
+            velo.x -= yaw.sin() * boost;
 
-            velo.y += yaw.cos() * 0.2;
+            velo.z += yaw.cos() * boost;
         }
 
         self.entity.velocity.store(velo);
@@ -670,16 +1374,25 @@ impl LivingEntity {
         self.entity.velocity_dirty.store(true, SeqCst);
     }
 
-    async fn get_jump_velocity(&self, mut strength: f64) -> f64 {
-        strength *= 1.0; // TODO: Entity Attribute jump strength
-
-        strength *= f64::from(self.entity.get_jump_velocity_multiplier().await);
-
-        if let Some(effect) = self.get_effect(&StatusEffect::JUMP_BOOST).await {
-            strength += 0.1 * f64::from(effect.amplifier + 1);
-        }
+    async fn get_jump_velocity(&self, strength: f64) -> f64 {
+        let jump_strength = self
+            .attribute_manager
+            .get_modified(&Attributes::JUMP_STRENGTH, []);
+        let multiplier = self.entity.get_jump_velocity_multiplier().await;
+        let jump_boost = self
+            .get_effect(&StatusEffect::JUMP_BOOST)
+            .await
+            .map(|effect| effect.amplifier);
+        // Jump height grows with the square of the velocity, so a scaled entity jumps about
+        // `scale` times as high
+        let scale = f64::from(self.entity.scale.load()).sqrt();
+        Self::jump_velocity(strength * jump_strength * scale, multiplier, jump_boost)
+    }
 
-        strength
+    /// Upwards velocity of a jump, with each jump boost level adding 0.1.
+    fn jump_velocity(jump_strength: f64, block_multiplier: f32, jump_boost: Option<u8>) -> f64 {
+        let boost = jump_boost.map_or(0.0, |amplifier| 0.1 * (f64::from(amplifier) + 1.0));
+        jump_strength * f64::from(block_multiplier) + boost
     }
 
     pub async fn update_fall_distance(
@@ -699,9 +1412,21 @@ impl LivingEntity {
                 return;
             }
 
-            let safe_fall_distance = 3.0;
-            let mut damage = fall_distance - safe_fall_distance;
-            damage = damage.ceil();
+            let jump_boost = self
+                .get_effect(&StatusEffect::JUMP_BOOST)
+                .await
+                .map(|effect| effect.amplifier);
+            let safe_fall_distance = self
+                .attribute_manager
+                .get_modified(&Attributes::SAFE_FALL_DISTANCE, [])
+                + jump_boost.map_or(0.0, |amplifier| f64::from(amplifier) + 1.0);
+            let (_pos, landing_block, _state) = self.entity.get_block_with_y_offset(0.2).await;
+            let damage_multiplier =
+                Self::landing_damage_multiplier(landing_block, self.entity.sneaking.load(Relaxed))
+                    * self
+                        .attribute_manager
+                        .get_modified(&Attributes::FALL_DAMAGE_MULTIPLIER, []);
+            let damage = Self::fall_damage(fall_distance, safe_fall_distance, damage_multiplier);
 
             // TODO: Play block fall sound
             if damage > 0.0 {
@@ -726,6 +1451,23 @@ impl LivingEntity {
         }
     }
 
+    /// Slime blocks bounce the entity unless it sneaks, hay and honey soften the landing.
+    fn landing_damage_multiplier(block: &Block, sneaking: bool) -> f64 {
+        if block == &Block::SLIME_BLOCK && !sneaking {
+            0.0
+        } else if block == &Block::HAY_BLOCK || block == &Block::HONEY_BLOCK {
+            0.2
+        } else {
+            1.0
+        }
+    }
+
+    /// Damage taken when landing, every block fallen beyond the safe distance deals one damage.
+    fn fall_damage(fall_distance: f32, safe_fall_distance: f64, damage_multiplier: f64) -> f32 {
+        let damage = (f64::from(fall_distance) - safe_fall_distance) * damage_multiplier;
+        damage.ceil().max(0.0) as f32
+    }
+
     fn get_fall_sound(distance: i32) -> Sound {
         if distance > 4 {
             Sound::EntityGenericBigFall
@@ -775,7 +1517,9 @@ impl LivingEntity {
         }
     }
 
-    pub async fn on_death(
+    /// Runs the death sequence once: the [`EntityBase::on_death`] hook, the dying pose and the
+    /// death message.
+    pub async fn die(
         &self,
         damage_type: DamageType,
         source: Option<&dyn EntityBase>,
@@ -791,37 +1535,51 @@ impl LivingEntity {
             .compare_exchange(false, true, Relaxed, Relaxed)
             .is_ok()
         {
-            // Plays the death sound
-            world
-                .send_entity_status(
-                    &self.entity,
-                    EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
-                )
-                .await;
-            let params = LootContextParameters {
-                killed_by_player: cause.map(|c| c.get_entity().entity_type == &EntityType::PLAYER),
-                ..Default::default()
-            };
-
-            self.drop_loot(params).await;
+            dyn_self.on_death(source.or(cause)).await;
             self.entity.pose.store(EntityPose::Dying);
 
-            let level_info = world.level_info.read().await;
-            let game_rules = &level_info.game_rules;
-            if self.entity.entity_type == &EntityType::PLAYER && game_rules.show_death_messages {
-                //TODO: KillCredit
-                let death_message =
-                    Self::get_death_message(&*dyn_self, damage_type, source, cause).await;
-                if let Some(server) = world.server.upgrade() {
-                    for player in server.get_all_players().await {
-                        player.send_system_message(&death_message).await;
-                    }
+            let is_player = self.entity.entity_type == &EntityType::PLAYER;
+            let custom_name = self.entity.custom_name.lock().unwrap().clone();
+            if !is_player && custom_name.is_none() {
+                return;
+            }
+            //TODO: KillCredit
+            let death_message =
+                Self::get_death_message(&*dyn_self, damage_type, source, cause).await;
+            if !is_player {
+                // Like vanilla, deaths of named mobs only show up in the console
+                log::info!(
+                    "Named entity {} died: {}",
+                    self.entity.entity_type.resource_name,
+                    death_message.to_pretty_console()
+                );
+                return;
+            }
+            let show_death_messages = world.level_info.read().await.game_rules.show_death_messages;
+            if show_death_messages && let Some(server) = world.server.upgrade() {
+                for player in server.get_all_players().await {
+                    player.send_system_message(&death_message).await;
                 }
             }
         }
     }
 
-    async fn drop_loot(&self, params: LootContextParameters) {
+    /// Experience a mob drops when killed by a player. Monsters give 5, animals 1 to 3 and
+    /// players drop theirs separately.
+    #[must_use]
+    pub fn experience_reward(entity_type: &EntityType) -> u32 {
+        if !entity_type.mob || entity_type == &EntityType::PLAYER {
+            return 0;
+        }
+        match entity_type.category {
+            category if category == &MobCategory::MONSTER => 5,
+            category if category == &MobCategory::AMBIENT || category == &MobCategory::MISC => 0,
+            _ => rand::rng().random_range(1..=3),
+        }
+    }
+
+    /// Drops the loot of the entity's type at its position
+    pub async fn drop_loot(&self, params: LootContextParameters) {
         if let Some(loot_table) = &self.get_entity().entity_type.loot_table {
             let pos = self.entity.block_pos.load();
             for stack in loot_table.get_loot(params) {
@@ -848,23 +1606,99 @@ impl LivingEntity {
         }
     }
 
+    /// The name a name tag gives to an entity, `None` for other items and name tags that haven't
+    /// been renamed in an anvil
+    fn name_tag_name(stack: &ItemStack) -> Option<&'static str> {
+        if stack.item != &Item::NAME_TAG {
+            return None;
+        }
+        stack
+            .get_data_component::<CustomNameImpl>()
+            .map(|custom_name| custom_name.name)
+    }
+
+    /// Returns the hand whose item saves the entity from death. The main hand is checked first, so
+    /// only one totem gets used when holding one in each hand.
+    fn get_death_protector_hand(main_hand: &ItemStack, off_hand: &ItemStack) -> Option<Hand> {
+        [(Hand::Right, main_hand), (Hand::Left, off_hand)]
+            .into_iter()
+            .find(|(_, stack)| stack.get_data_component::<DeathProtectionImpl>().is_some())
+            .map(|(hand, _)| hand)
+    }
+
     async fn try_use_death_protector(&self, caller: &Arc<dyn EntityBase>) -> bool {
-        for hand in Hand::all() {
-            let stack = self.get_stack_in_hand(caller, hand).await;
-            let mut stack = stack.lock().await;
-            // TODO: effects...
-            if stack.get_data_component::<DeathProtectionImpl>().is_some() {
-                stack.decrement(1);
-                self.set_health(1.0).await;
-                self.entity
-                    .world
-                    .send_entity_status(&self.entity, EntityStatus::UseTotemOfUndying)
+        let hand = {
+            let main_hand = self.get_stack_in_hand(caller, Hand::Right).await;
+            let off_hand = self.get_stack_in_hand(caller, Hand::Left).await;
+            let main_hand = main_hand.lock().await;
+            let off_hand = off_hand.lock().await;
+            Self::get_death_protector_hand(&main_hand, &off_hand)
+        };
+        let Some(hand) = hand else {
+            return false;
+        };
+        self.get_stack_in_hand(caller, hand)
+            .await
+            .lock()
+            .await
+            .decrement(1);
+
+        self.set_health(1.0).await;
+
+        // TODO: Use the death_protection component's effects once they are generated
+        let effects = [
+            (&StatusEffect::REGENERATION, 900, 1),
+            (&StatusEffect::ABSORPTION, 100, 1),
+            (&StatusEffect::FIRE_RESISTANCE, 800, 0),
+        ];
+        if let Some(player) = caller.get_player() {
+            player.remove_all_effect().await;
+            for (effect_type, duration, amplifier) in effects {
+                player
+                    .add_effect(Self::death_protection_effect(
+                        effect_type,
+                        duration,
+                        amplifier,
+                    ))
                     .await;
-                return true;
+            }
+        } else {
+            let active: Vec<_> = self.active_effects.lock().await.keys().copied().collect();
+            for effect_type in active {
+                self.remove_effect(effect_type).await;
+            }
+            for (effect_type, duration, amplifier) in effects {
+                self.add_effect(Self::death_protection_effect(
+                    effect_type,
+                    duration,
+                    amplifier,
+                ))
+                .await;
             }
         }
 
-        false
+        // The client plays the totem sound and animation when receiving this status
+        self.entity
+            .world
+            .send_entity_status(&self.entity, EntityStatus::UseTotemOfUndying)
+            .await;
+        true
+    }
+
+    fn death_protection_effect(
+        effect_type: &'static StatusEffect,
+        duration: i32,
+        amplifier: u8,
+    ) -> Effect {
+        Effect {
+            effect_type,
+            duration,
+            amplifier,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+            blend: false,
+        }
     }
 
     pub async fn held_item(&self, caller: &Arc<dyn EntityBase>) -> Arc<Mutex<ItemStack>> {
@@ -922,6 +1756,7 @@ impl NBTStorage for LivingEntity {
         self.entity.write_nbt(nbt).await;
         nbt.put("Health", NbtTag::Float(self.health.load()));
         nbt.put("fall_distance", NbtTag::Float(self.fall_distance.load()));
+        self.attribute_manager.write_nbt(nbt);
         {
             let effects = self.active_effects.lock().await;
             if !effects.is_empty() {
@@ -944,6 +1779,10 @@ impl NBTStorage for LivingEntity {
         self.health.store(nbt.get_float("Health").unwrap_or(0.0));
         self.fall_distance
             .store(nbt.get_float("fall_distance").unwrap_or(0.0));
+        self.attribute_manager.read_nbt(nbt);
+        // Scaled entities keep their size
+        self.entity
+            .set_scale(self.attribute_manager.get_modified(&Attributes::SCALE, []) as f32);
         {
             let mut active_effects = self.active_effects.lock().await;
             let nbt_effects = nbt.get_list("active_effects");
@@ -968,6 +1807,7 @@ impl NBTStorage for LivingEntity {
 
 #[async_trait]
 impl EntityBase for LivingEntity {
+    #[allow(clippy::too_many_lines)]
     async fn damage_with_context(
         &self,
         caller: Arc<dyn EntityBase>,
@@ -996,6 +1836,26 @@ impl EntityBase for LivingEntity {
             return false; // Fire resistance
         }
 
+        let amount = if PLUGIN_MANAGER.has_handlers::<EntityDamageEvent>().await {
+            let event = PLUGIN_MANAGER
+                .fire(EntityDamageEvent::new(caller.clone(), amount, damage_type))
+                .await;
+            let Some(amount) = event.get_final_damage() else {
+                return false;
+            };
+            amount
+        } else {
+            amount
+        };
+
+        let blocked = self
+            .block_damage(&caller, amount, damage_type, position, source)
+            .await;
+        if blocked > 0.0 && amount - blocked <= 0.0 {
+            return false; // Fully blocked
+        }
+        let amount = amount - blocked;
+
         let world = &self.entity.world;
 
         let last_damage = self.last_damage_taken.load();
@@ -1050,61 +1910,97 @@ impl EntityBase for LivingEntity {
         let new_health = self.health.load() - damage_amount;
         if damage_amount > 0.0 {
             self.on_actually_hurt(damage_amount, damage_type).await;
+        }
+
+        // A totem cancels the lethal damage, unless it's from the void or /kill
+        if new_health <= 0.0
+            && damage_type != DamageType::GENERIC_KILL
+            && damage_type != DamageType::OUT_OF_WORLD
+            && self.try_use_death_protector(&caller).await
+        {
+            return true;
+        }
+
+        if new_health <= 0.0
+            && PLUGIN_MANAGER.has_handlers::<EntityDeathEvent>().await
+            && PLUGIN_MANAGER
+                .fire(EntityDeathEvent::new(caller.clone(), damage_type))
+                .await
+                .cancelled
+        {
+            return false;
+        }
+
+        if damage_amount > 0.0 {
             self.set_health(new_health).await;
         }
 
-        if new_health <= 0.0 && !self.try_use_death_protector(&caller).await {
-            self.on_death(damage_type, source, cause).await;
+        // Thorns only react to direct hits, not to projectiles
+        if damage_type != DamageType::THORNS
+            && let (Some(attacker), Some(direct)) = (source, cause)
+            && attacker.get_entity().entity_id == direct.get_entity().entity_id
+            && attacker.get_entity().entity_id != self.entity.entity_id
+        {
+            self.apply_thorns(&caller, attacker).await;
+        }
+
+        if new_health <= 0.0 {
+            self.die(damage_type, source, cause).await;
         }
 
         true
     }
 
+    async fn interact(&self, player: &Arc<Player>, hand: Hand) -> InteractionResult {
+        let held = if hand == Hand::Left {
+            player.inventory.held_item()
+        } else {
+            player.inventory.off_hand_item().await
+        };
+        let mut stack = held.lock().await;
+        if self.entity.entity_type.saveable
+            && let Some(name) = Self::name_tag_name(&stack)
+        {
+            self.entity.set_custom_name(TextComponent::text(name)).await;
+            stack.decrement_unless_creative(player.gamemode.load(), 1);
+            return InteractionResult::Success;
+        }
+        InteractionResult::Pass
+    }
+
     fn get_gravity(&self) -> f64 {
-        const GRAVITY: f64 = 0.08;
-        GRAVITY
+        LIVING_GRAVITY
     }
 
     async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
-        self.entity.tick(caller.clone(), server).await;
+        self.entity.base_tick(caller.clone(), server).await;
+        if self.entity.is_alive() {
+            self.tick_air(&caller).await;
+        }
         self.tick_movement(server, caller.clone()).await;
         // TODO
-        if caller.get_player().is_none() {
+        if caller.get_mob().is_some() {
+            // Mobs face where their body is turned, not where they look
+            self.entity.send_pos().await;
+            self.entity.send_body_rotation().await;
+            self.entity.send_velocity_if_dirty().await;
+        } else if caller.get_player().is_none() {
             self.entity.send_pos_rot().await;
-            self.entity.send_velocity().await;
+            self.entity.send_velocity_if_dirty().await;
         }
         self.tick_effects().await;
         // Current active item
-        {
-            let item_in_use = self.item_in_use.lock().await.clone();
-            if let Some(item) = item_in_use.as_ref()
-                && self.item_use_time.fetch_sub(1, Ordering::Relaxed) <= 0
-            {
-                // Consume item
-                if let Some(food) = item.get_data_component::<FoodImpl>()
-                    && let Some(player) = caller.get_player()
-                {
-                    player
-                        .hunger_manager
-                        .eat(player, food.nutrition as u8, food.saturation)
-                        .await;
-                }
-                if let Some(player) = caller.get_player() {
-                    player
-                        .inventory
-                        .held_item()
-                        .lock()
-                        .await
-                        .decrement_unless_creative(player.gamemode.load(), 1);
-                }
-
-                self.clear_active_hand().await;
-            }
+        let item_in_use = self.item_in_use.lock().await.clone();
+        if let Some(item) = item_in_use {
+            self.tick_active_item(caller.as_ref(), &item).await;
         }
 
         if self.hurt_cooldown.load(Relaxed) > 0 {
             self.hurt_cooldown.fetch_sub(1, Relaxed);
         }
+        if self.blocking_cooldown.load(Relaxed) > 0 {
+            self.blocking_cooldown.fetch_sub(1, Relaxed);
+        }
         if self.health.load() <= 0.0 {
             let time = self.death_time.fetch_add(1, Relaxed);
             if time == 20 {
@@ -1130,3 +2026,479 @@ impl EntityBase for LivingEntity {
         self
     }
 }
+
+/// How long an axe hit disables blocking, vanilla's `disable_blocking_for_seconds` of axes
+const AXE_DISABLE_BLOCKING_SECONDS: f32 = 5.0;
+
+fn sound_from_name(name: &str) -> Option<Sound> {
+    Sound::from_name(name.strip_prefix("minecraft:").unwrap_or(name))
+}
+
+/// Whether the damage type is in the given damage type tag, e.g. `#minecraft:bypasses_shield`
+fn is_damage_type_in(damage_type: DamageType, tag: &str) -> bool {
+    let tag = tag.strip_prefix('#').unwrap_or(tag);
+    get_tag_values(RegistryKey::DamageType, tag).is_some_and(|names| {
+        names
+            .iter()
+            .any(|name| DamageType::from_name(name).is_some_and(|t| t == damage_type))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::damage::DamageType;
+    use pumpkin_data::data_component::DataComponent;
+    use pumpkin_data::data_component_impl::{
+        BlocksAttacksImpl, CustomNameImpl, DamageImpl, DataComponentImpl, EquipmentSlot,
+    };
+    use pumpkin_data::item::Item;
+    use pumpkin_data::{Block, Enchantment};
+    use pumpkin_util::Hand;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_world::item::ItemStack;
+
+    use super::{LivingEntity, is_damage_type_in};
+    use crate::entity::Entity;
+    use crate::entity::MAX_AIR_SUPPLY;
+    use crate::entity::physics::LIVING_GRAVITY;
+
+    #[tokio::test]
+    async fn scaling_resizes_the_hitbox() {
+        use pumpkin_data::attributes::Attributes;
+        use pumpkin_data::entity::EntityType;
+
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let zombie = LivingEntity::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            pos,
+            &EntityType::ZOMBIE,
+            false,
+        ));
+
+        assert!(zombie.attribute_manager.set_base(&Attributes::SCALE, 2.0));
+        zombie.update_attribute(&Attributes::SCALE).await;
+        let size = zombie.entity.bounding_box_size.load();
+        assert!((size.height - EntityType::ZOMBIE.dimension[1] * 2.0).abs() < 1e-6);
+        let bounding_box = zombie.entity.bounding_box.load();
+        assert!((bounding_box.max.y - bounding_box.min.y - f64::from(size.height)).abs() < 1e-6);
+
+        let packet = zombie.attribute_packet(&Attributes::SCALE);
+        assert_eq!(packet.attributes.len(), 1);
+        assert_eq!(packet.attributes[0].id.0, i32::from(Attributes::SCALE.id));
+        assert!((packet.attributes[0].base - 2.0).abs() < f64::EPSILON);
+
+        // Nothing to send if the size stays the same
+        assert!(!zombie.entity.set_scale(2.0));
+    }
+
+    #[tokio::test]
+    async fn chorus_fruit_teleports_onto_the_ground() {
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // A floor high up in the air, below every spot a 16 block teleport can reach
+        for x in -8..=8 {
+            for z in -8..=8 {
+                world
+                    .set_block_state(
+                        &BlockPos::new(x, 299, z),
+                        Block::STONE.default_state.id,
+                        BlockFlags::FORCE_STATE,
+                    )
+                    .await;
+            }
+        }
+        let zombie = LivingEntity::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 309.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ));
+
+        for _ in 0..8 {
+            let target = zombie
+                .random_teleport_target(16.0)
+                .await
+                .expect("the floor has room everywhere");
+            assert!((target.x - 0.5).abs() <= 8.0 && (target.z - 0.5).abs() <= 8.0);
+            assert!((300.0..301.0).contains(&target.y));
+            assert!(
+                world
+                    .get_block_state(&target.to_block_pos().down())
+                    .await
+                    .is_solid()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn scaled_up_entities_step_higher_and_are_pushed_less() {
+        use pumpkin_data::entity::EntityType;
+
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let new_zombie = || {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                pos,
+                &EntityType::ZOMBIE,
+                false,
+            )
+        };
+        let normal = new_zombie();
+        let giant = new_zombie();
+        assert!(giant.set_scale(2.0));
+        assert!((giant.step_height.load() - normal.step_height.load() * 2.0).abs() < 1e-6);
+
+        normal.knockback(0.4, 1.0, 0.0);
+        giant.knockback(0.4, 1.0, 0.0);
+        let pushed = normal.velocity.load().x;
+        let pushed_giant = giant.velocity.load().x;
+        assert!(pushed < 0.0);
+        assert!((pushed_giant - pushed / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn falling_in_water_is_slower_than_in_air() {
+        let in_air = -LIVING_GRAVITY;
+        let in_water = LivingEntity::fluid_falling_speed(0.0, LIVING_GRAVITY, true, false);
+        assert!(in_water < 0.0);
+        assert!(in_water > in_air);
+        // Swimming keeps the height
+        assert!(
+            LivingEntity::fluid_falling_speed(0.0, LIVING_GRAVITY, true, true).abs() < f64::EPSILON
+        );
+
+        // Items and experience orbs float up instead
+        assert!(Entity::apply_gravity(0.0, 0.04, true) > 0.0);
+        assert!((Entity::apply_gravity(0.0, 0.04, false) + 0.04).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn fall_damage_from_ten_blocks() {
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering;
+
+        use pumpkin_data::attributes::Attributes;
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        use crate::world::World;
+
+        /// Lets a zombie land on `block` after falling 10 blocks, returns the damage it took
+        async fn land_on(
+            world: &Arc<World>,
+            block: &Block,
+            sneaking: bool,
+            safe_fall_distance: f64,
+        ) -> f32 {
+            world
+                .set_block_state(
+                    &BlockPos::new(0, 299, 0),
+                    block.default_state.id,
+                    BlockFlags::FORCE_STATE,
+                )
+                .await;
+            let zombie = Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )));
+            zombie.entity.sneaking.store(sneaking, Ordering::Relaxed);
+            assert!(
+                zombie
+                    .attribute_manager
+                    .set_base(&Attributes::SAFE_FALL_DISTANCE, safe_fall_distance)
+            );
+            let health = zombie.health.load();
+            zombie.fall_distance.store(10.0);
+
+            zombie
+                .update_fall_distance(zombie.clone(), 0.0, true, false)
+                .await;
+            assert!(zombie.fall_distance.load().abs() < f32::EPSILON);
+            health - zombie.health.load()
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+
+        assert!((land_on(&world, &Block::STONE, false, 3.0).await - 7.0).abs() < f32::EPSILON);
+        // A higher safe fall distance, e.g. from jump boost, saves a block per level
+        assert!((land_on(&world, &Block::STONE, false, 4.0).await - 6.0).abs() < f32::EPSILON);
+
+        assert!(land_on(&world, &Block::SLIME_BLOCK, false, 3.0).await.abs() < f32::EPSILON);
+        assert!((land_on(&world, &Block::SLIME_BLOCK, true, 3.0).await - 7.0).abs() < f32::EPSILON);
+        assert!((land_on(&world, &Block::HAY_BLOCK, false, 3.0).await - 2.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn only_one_totem_is_used() {
+        let totem = ItemStack::new(1, &Item::TOTEM_OF_UNDYING);
+        let bread = ItemStack::new(1, &Item::BREAD);
+
+        assert_eq!(
+            LivingEntity::get_death_protector_hand(&totem, &totem),
+            Some(Hand::Right)
+        );
+        assert_eq!(
+            LivingEntity::get_death_protector_hand(&bread, &totem),
+            Some(Hand::Left)
+        );
+        assert_eq!(LivingEntity::get_death_protector_hand(&bread, &bread), None);
+    }
+
+    #[test]
+    fn jump_velocity_uses_jump_strength() {
+        let jump_strength = pumpkin_data::attributes::Attributes::JUMP_STRENGTH.default_value;
+        let velocity = LivingEntity::jump_velocity(jump_strength, 1.0, None);
+        assert!((velocity - 0.42).abs() < 1e-6);
+
+        // Honey blocks halve the jump, jump boost II adds 0.2
+        let velocity = LivingEntity::jump_velocity(jump_strength, 0.5, Some(1));
+        assert!((velocity - 0.41).abs() < 1e-6);
+    }
+
+    #[test]
+    fn shield_blocks_attacks_from_the_front() {
+        let shield = ItemStack::new(1, &Item::SHIELD);
+        let blocks_attacks = shield
+            .get_data_component::<BlocksAttacksImpl>()
+            .expect("shields block attacks");
+        let pos = Vector3::new(0.0, 64.0, 0.0);
+
+        // A yaw of 0 looks towards positive z
+        let front = LivingEntity::horizontal_angle_to(pos, 0.0, Vector3::new(0.5, 64.0, 3.0));
+        let behind = LivingEntity::horizontal_angle_to(pos, 0.0, Vector3::new(0.0, 64.0, -3.0));
+        assert!((blocks_attacks.resolve_blocked_damage(6.0, front) - 6.0).abs() < f32::EPSILON);
+        assert!(blocks_attacks.resolve_blocked_damage(6.0, behind).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn shield_is_bypassed_by_fire() {
+        let shield = ItemStack::new(1, &Item::SHIELD);
+        let tag = shield
+            .get_data_component::<BlocksAttacksImpl>()
+            .and_then(|blocks_attacks| blocks_attacks.bypassed_by)
+            .expect("shields are bypassed by some damage");
+        assert!(is_damage_type_in(DamageType::ON_FIRE, tag));
+        assert!(!is_damage_type_in(DamageType::PLAYER_ATTACK, tag));
+    }
+
+    #[test]
+    fn air_runs_out_underwater_then_drowns() {
+        let mut air_supply = MAX_AIR_SUPPLY;
+        let mut ticks = 0;
+        let drowned = loop {
+            let (next, drown) = LivingEntity::next_air_underwater(air_supply, 0);
+            ticks += 1;
+            if drown {
+                break next;
+            }
+            assert!(next < air_supply);
+            air_supply = next;
+        };
+        // 15 seconds of air, then another second before the first drowning damage
+        assert_eq!(ticks, MAX_AIR_SUPPLY + 20);
+        assert_eq!(drowned, 0);
+
+        assert_eq!(LivingEntity::next_air_on_land(0), 4);
+        assert_eq!(
+            LivingEntity::next_air_on_land(MAX_AIR_SUPPLY - 1),
+            MAX_AIR_SUPPLY
+        );
+    }
+
+    #[tokio::test]
+    async fn thorns_armor_hurts_the_attacker() {
+        use std::sync::Arc;
+
+        use pumpkin_data::entity::EntityType;
+
+        use crate::entity::EntityBase;
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_zombie = |x| {
+            Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )))
+        };
+        let attacker = new_zombie(1.5);
+        world
+            .spawn_entity(attacker.clone() as Arc<dyn EntityBase>)
+            .await;
+        let target = new_zombie(0.5);
+
+        let mut chestplate = ItemStack::new(1, &Item::DIAMOND_CHESTPLATE);
+        // Thorns VII always triggers
+        chestplate.enchant(&Enchantment::THORNS, 7);
+        target
+            .set_equipment(&[(EquipmentSlot::CHEST, chestplate)])
+            .await;
+
+        let health = attacker.health.load();
+        assert!(
+            target
+                .damage_with_context(
+                    target.clone(),
+                    1.0,
+                    DamageType::MOB_ATTACK,
+                    None,
+                    Some(attacker.as_ref()),
+                    Some(attacker.as_ref()),
+                )
+                .await
+        );
+        assert!((1.0..=4.0).contains(&(health - attacker.health.load())));
+        let chestplate = target
+            .entity_equipment
+            .lock()
+            .await
+            .get(&EquipmentSlot::CHEST);
+        assert_eq!(
+            chestplate
+                .lock()
+                .await
+                .get_data_component::<DamageImpl>()
+                .map(|data| data.damage),
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn food_is_eaten_once_its_consume_time_is_over() {
+        use pumpkin_data::data_component_impl::FoodImpl;
+        use pumpkin_util::GameMode;
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        use crate::entity::{EntityBase, player::Player};
+        use crate::net::java::JavaClient;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let (player, mut packets) = Player::for_test(&world, GameMode::Survival).await;
+        // Standing on a platform high up in the air, away from any terrain
+        world
+            .set_block_state(
+                &BlockPos::new(0, 299, 0),
+                Block::STONE.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        player.get_entity().set_pos(Vector3::new(0.5, 300.0, 0.5));
+        player.hunger_manager.level.store(10);
+        let held = player.inventory.held_item();
+        *held.lock().await = ItemStack::new(2, &Item::BREAD);
+        let bread = held.lock().await.clone();
+        let consume_ticks = bread.get_max_use_time();
+        let nutrition = bread.get_data_component::<FoodImpl>().unwrap().nutrition;
+        let living = &player.living_entity;
+
+        living.set_active_hand(Hand::Left, bread.clone()).await;
+        for _ in 1..consume_ticks {
+            player.tick(&server).await;
+            JavaClient::queued_packet_ids(&mut packets);
+        }
+        assert_eq!(held.lock().await.item_count, 2);
+        assert_eq!(player.hunger_manager.level.load(), 10);
+
+        player.tick(&server).await;
+        assert_eq!(held.lock().await.item_count, 1);
+        assert_eq!(
+            i32::from(player.hunger_manager.level.load()),
+            10 + nutrition
+        );
+        assert!(living.item_in_use.lock().await.is_none());
+
+        // Switching to another slot halfway through stops eating
+        let level = player.hunger_manager.level.load();
+        living.set_active_hand(Hand::Left, bread).await;
+        for _ in 0..consume_ticks / 2 {
+            player.tick(&server).await;
+            JavaClient::queued_packet_ids(&mut packets);
+        }
+        player
+            .inventory
+            .set_selected_slot(player.inventory.get_selected_slot() + 1);
+        for _ in 0..consume_ticks {
+            player.tick(&server).await;
+            JavaClient::queued_packet_ids(&mut packets);
+        }
+        assert_eq!(held.lock().await.item_count, 1);
+        assert_eq!(player.hunger_manager.level.load(), level);
+        assert!(living.item_in_use.lock().await.is_none());
+    }
+
+    #[test]
+    fn only_renamed_name_tags_name_entities() {
+        let mut name_tag = ItemStack::new(1, &Item::NAME_TAG);
+        assert_eq!(LivingEntity::name_tag_name(&name_tag), None);
+
+        let custom_name = || Some(CustomNameImpl { name: "Dinnerbone" }.to_dyn());
+        name_tag
+            .patch
+            .push((DataComponent::CustomName, custom_name()));
+        assert_eq!(LivingEntity::name_tag_name(&name_tag), Some("Dinnerbone"));
+
+        let mut bread = ItemStack::new(1, &Item::BREAD);
+        bread.patch.push((DataComponent::CustomName, custom_name()));
+        assert_eq!(LivingEntity::name_tag_name(&bread), None);
+    }
+
+    #[test]
+    fn only_occupied_equipment_is_sent_to_new_observers() {
+        let occupied = LivingEntity::occupied_equipment(vec![
+            (EquipmentSlot::HEAD, ItemStack::new(1, &Item::IRON_HELMET)),
+            (EquipmentSlot::CHEST, ItemStack::EMPTY.clone()),
+            (
+                EquipmentSlot::MAIN_HAND,
+                ItemStack::new(1, &Item::IRON_SWORD),
+            ),
+        ]);
+        // Both items end up in the same packet, the empty chestplate slot is left out
+        assert_eq!(occupied.len(), 2);
+        assert_eq!(occupied[0].0, EquipmentSlot::MAIN_HAND);
+        assert_eq!(occupied[1].0, EquipmentSlot::HEAD);
+
+        let serialized = LivingEntity::serialize_equipment(&occupied);
+        assert_eq!(
+            serialized.iter().map(|(slot, _)| *slot).collect::<Vec<_>>(),
+            vec![
+                EquipmentSlot::MAIN_HAND.discriminant(),
+                EquipmentSlot::HEAD.discriminant()
+            ]
+        );
+
+        assert!(
+            LivingEntity::occupied_equipment(vec![(EquipmentSlot::FEET, ItemStack::EMPTY.clone())])
+                .is_empty()
+        );
+    }
+}
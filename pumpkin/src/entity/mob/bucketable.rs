@@ -0,0 +1,210 @@
+use super::Mob;
+use crate::entity::{InteractionResult, NBTStorage, player::Player};
+use pumpkin_data::data_component::DataComponent;
+use pumpkin_data::data_component_impl::{BucketEntityDataImpl, CustomNameImpl, DataComponentImpl};
+use pumpkin_data::entity::EntityType;
+use pumpkin_data::item::Item;
+use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::{GameMode, Hand};
+use pumpkin_world::item::ItemStack;
+
+/// Data every mob keeps in the bucket, like vanilla's `Bucketable.copyDataToStack`. The custom
+/// name goes into the stack's `custom_name` component instead.
+const BUCKET_NBT_KEYS: [&str; 6] = [
+    "NoAI",
+    "Silent",
+    "NoGravity",
+    "Glowing",
+    "Invulnerable",
+    "Health",
+];
+
+/// Data only some mobs keep in the bucket on top of [`BUCKET_NBT_KEYS`].
+fn extra_bucket_nbt_keys(entity_type: &EntityType) -> &'static [&'static str] {
+    if entity_type.id == EntityType::TROPICAL_FISH.id {
+        &["BucketVariantTag"]
+    } else if entity_type.id == EntityType::AXOLOTL.id {
+        &["Variant", "Age", "HuntingCooldown"]
+    } else {
+        &[]
+    }
+}
+
+/// The bucket a mob of `entity_type` is caught in, if it can be caught at all.
+#[must_use]
+pub fn bucket_item(entity_type: &EntityType) -> Option<&'static Item> {
+    let item = match entity_type.id {
+        id if id == EntityType::COD.id => &Item::COD_BUCKET,
+        id if id == EntityType::SALMON.id => &Item::SALMON_BUCKET,
+        id if id == EntityType::TROPICAL_FISH.id => &Item::TROPICAL_FISH_BUCKET,
+        id if id == EntityType::PUFFERFISH.id => &Item::PUFFERFISH_BUCKET,
+        id if id == EntityType::AXOLOTL.id => &Item::AXOLOTL_BUCKET,
+        id if id == EntityType::TADPOLE.id => &Item::TADPOLE_BUCKET,
+        _ => return None,
+    };
+    Some(item)
+}
+
+fn fill_sound(entity_type: &EntityType) -> Sound {
+    if entity_type.id == EntityType::AXOLOTL.id {
+        Sound::ItemBucketFillAxolotl
+    } else if entity_type.id == EntityType::TADPOLE.id {
+        Sound::ItemBucketFillTadpole
+    } else {
+        Sound::ItemBucketFillFish
+    }
+}
+
+/// Picks the data kept in the bucket out of the data of a mob of `entity_type`.
+#[must_use]
+pub fn bucket_nbt(entity_type: &EntityType, mob_nbt: &NbtCompound) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+    for &key in BUCKET_NBT_KEYS
+        .iter()
+        .chain(extra_bucket_nbt_keys(entity_type))
+    {
+        if let Some(tag) = mob_nbt.get(key) {
+            nbt.put(key, tag.clone());
+        }
+    }
+    nbt
+}
+
+/// Catches `mob` in a water bucket: removes it and returns the bucket holding its data, or
+/// `None` if it can't be caught.
+pub async fn catch(mob: &dyn Mob) -> Option<ItemStack> {
+    let entity = mob.get_entity();
+    let bucket = bucket_item(entity.entity_type)?;
+    if !entity.is_alive() {
+        return None;
+    }
+
+    let mut nbt = NbtCompound::new();
+    mob.write_nbt(&mut nbt).await;
+    let data = BucketEntityDataImpl {
+        nbt: bucket_nbt(entity.entity_type, &nbt),
+    };
+    let mut components = vec![(DataComponent::BucketEntityData, Some(data.to_dyn()))];
+    let custom_name = entity.custom_name.lock().unwrap().clone();
+    if let Some(custom_name) = custom_name {
+        let name = custom_name.get_text().leak();
+        components.push((
+            DataComponent::CustomName,
+            Some(CustomNameImpl { name }.to_dyn()),
+        ));
+    }
+    entity
+        .world
+        .play_sound(
+            fill_sound(entity.entity_type),
+            SoundCategory::Neutral,
+            &entity.pos.load(),
+        )
+        .await;
+    entity.discard().await;
+
+    Some(ItemStack::new_with_component(1, bucket, components))
+}
+
+/// Uses up one item of `held` for `result`, like vanilla's `ItemUsage.exchangeStack`.
+/// Returns `result` again if it doesn't take the place of `held` and has to go into the
+/// inventory instead.
+#[must_use]
+pub fn exchange_stack(
+    held: &mut ItemStack,
+    result: ItemStack,
+    gamemode: GameMode,
+) -> Option<ItemStack> {
+    held.decrement_unless_creative(gamemode, 1);
+    if held.is_empty() {
+        *held = result;
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// Catches `mob` when `player` uses a water bucket on it.
+pub async fn interact(mob: &dyn Mob, player: &Player, hand: Hand) -> InteractionResult {
+    let held = if hand == Hand::Left {
+        player.inventory.held_item()
+    } else {
+        player.inventory.off_hand_item().await
+    };
+    let mut stack = held.lock().await;
+    if stack.item.id != Item::WATER_BUCKET.id {
+        return InteractionResult::Pass;
+    }
+    let Some(bucket) = catch(mob).await else {
+        return InteractionResult::Pass;
+    };
+
+    if let Some(mut bucket) = exchange_stack(&mut stack, bucket, player.gamemode.load()) {
+        drop(stack);
+        if !player.inventory.insert_stack_anywhere(&mut bucket).await {
+            player.drop_item(bucket).await;
+        }
+    }
+    InteractionResult::Success
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::data_component_impl::{BucketEntityDataImpl, CustomNameImpl};
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_data::item::Item;
+    use pumpkin_util::GameMode;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_util::text::TextComponent;
+    use pumpkin_world::item::ItemStack;
+
+    use super::{bucket_item, catch, exchange_stack};
+    use crate::entity::mob::Mob;
+    use crate::entity::mob::water_creature::WaterCreature;
+    use crate::entity::{Entity, EntityBase};
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn water_bucket_catches_fish_with_their_data() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::COD,
+            false,
+        );
+        let cod = WaterCreature::make(entity).await;
+        cod.get_mob_entity().living_entity.health.store(2.0);
+        cod.get_entity()
+            .set_custom_name(TextComponent::text("Wanda"))
+            .await;
+
+        let bucket = catch(cod.as_ref()).await.unwrap();
+        assert!(cod.get_mob_entity().living_entity.entity.is_removed());
+        assert_eq!(bucket.item.id, Item::COD_BUCKET.id);
+        let data = bucket.get_data_component::<BucketEntityDataImpl>().unwrap();
+        assert_eq!(data.nbt.get_float("Health"), Some(2.0));
+        // Where it was caught doesn't matter
+        assert!(data.nbt.get("Pos").is_none());
+        // The name is shown on the bucket itself
+        assert!(data.nbt.get("CustomName").is_none());
+        let custom_name = bucket.get_data_component::<CustomNameImpl>().unwrap();
+        assert_eq!(custom_name.name, "Wanda");
+
+        // The last water bucket turns into the filled one
+        let mut held = ItemStack::new(1, &Item::WATER_BUCKET);
+        assert!(exchange_stack(&mut held, bucket.clone(), GameMode::Survival).is_none());
+        assert_eq!(held.item.id, Item::COD_BUCKET.id);
+        assert!(held.get_data_component::<BucketEntityDataImpl>().is_some());
+
+        // In creative the water bucket stays and the filled one goes into the inventory
+        let mut held = ItemStack::new(1, &Item::WATER_BUCKET);
+        assert!(exchange_stack(&mut held, bucket, GameMode::Creative).is_some());
+        assert_eq!(held.item.id, Item::WATER_BUCKET.id);
+
+        assert!(bucket_item(&EntityType::ZOMBIE).is_none());
+    }
+}
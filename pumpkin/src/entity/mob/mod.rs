@@ -1,18 +1,34 @@
-use super::{Entity, EntityBase, NBTStorage, ai::path::Navigator, living::LivingEntity};
+use super::{
+    Entity, EntityBase, InteractionResult, NBTStorage, ai::path::Navigator, living::LivingEntity,
+    player::Player,
+};
+use crate::entity::ai::control::jump_control::JumpControl;
 use crate::entity::ai::control::look_control::LookControl;
 use crate::entity::ai::goal::goal_selector::GoalSelector;
+use crate::entity::r#type::from_type;
 use crate::server::Server;
 use crate::world::World;
+use crate::world::loot::LootContextParameters;
 use async_trait::async_trait;
 use crossbeam::atomic::AtomicCell;
+use pumpkin_config::advanced_config;
+use pumpkin_data::BlockDirection;
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::entity::{EntityType, MobCategory};
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
+use pumpkin_util::math::wrap_degrees;
+use rand::Rng;
 use std::sync::Arc;
-use std::sync::atomic::AtomicI32;
 use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicBool, AtomicI32};
 use tokio::sync::Mutex;
+use uuid::Uuid;
 
+pub mod bucketable;
+pub mod water_creature;
 pub mod zombie;
 
 pub struct MobEntity {
@@ -22,8 +38,15 @@ pub struct MobEntity {
     pub navigator: Mutex<Navigator>,
     pub target: Mutex<Option<Arc<dyn EntityBase>>>,
     pub look_control: Mutex<LookControl>,
+    pub jump_control: JumpControl,
     pub position_target: AtomicCell<BlockPos>,
     pub position_target_range: AtomicI32,
+    /// Persistent mobs never despawn, saved as `PersistenceRequired`
+    pub persistent: AtomicBool,
+    /// Ticks the mob has been away from players, see [`should_despawn`]
+    pub despawn_counter: AtomicI32,
+    /// Ticks left until the mob converts into another mob, -1 if it is not converting
+    pub conversion_time: AtomicI32,
 }
 
 impl MobEntity {
@@ -36,10 +59,128 @@ impl MobEntity {
             navigator: Mutex::new(Navigator::default()),
             target: Mutex::new(None),
             look_control: Mutex::new(LookControl::default()),
+            jump_control: JumpControl::default(),
             position_target: AtomicCell::new(BlockPos::ZERO),
             position_target_range: AtomicI32::new(-1),
+            persistent: AtomicBool::new(false),
+            despawn_counter: AtomicI32::new(0),
+            conversion_time: AtomicI32::new(-1),
         }
     }
+
+    pub fn start_conversion(&self, ticks: i32) {
+        self.conversion_time.store(ticks, Relaxed);
+    }
+
+    pub fn is_converting(&self) -> bool {
+        self.conversion_time.load(Relaxed) >= 0
+    }
+
+    /// Counts down a running conversion. Returns whether the mob should convert now.
+    pub fn tick_conversion(&self) -> bool {
+        let (conversion_time, convert) = tick_conversion(self.conversion_time.load(Relaxed));
+        self.conversion_time.store(conversion_time, Relaxed);
+        convert
+    }
+
+    /// Replaces the mob with a new mob of `entity_type`, like vanilla's `MobEntity.convertTo`.
+    ///
+    /// The new mob takes over the position, rotation, custom name and equipment of the old one,
+    /// everything else (e.g. health) starts fresh.
+    pub async fn convert_to(&self, entity_type: &'static EntityType) -> Arc<dyn EntityBase> {
+        let entity = &self.living_entity.entity;
+        let world = entity.world.clone();
+        let converted = from_type(entity_type, entity.pos.load(), &world, Uuid::new_v4()).await;
+
+        let mut old_nbt = NbtCompound::new();
+        self.write_nbt(&mut old_nbt).await;
+        let mut new_nbt = NbtCompound::new();
+        converted.write_nbt(&mut new_nbt).await;
+        converted
+            .read_nbt_non_mut(&conversion_nbt(&old_nbt, new_nbt))
+            .await;
+
+        if let Some(living) = converted.get_living_entity() {
+            let old_equipment = self.living_entity.entity_equipment.lock().await;
+            let mut new_equipment = living.entity_equipment.lock().await;
+            for (slot, stack) in &old_equipment.equipment {
+                let stack = stack.lock().await.clone();
+                new_equipment.put(slot, stack).await;
+            }
+        }
+
+        entity.discard().await;
+        world.spawn_entity(converted.clone()).await;
+        converted
+    }
+
+    /// Whether the mob stays however far away players are, because it was made persistent, got a
+    /// name or is of a persistent category, like vanilla's `MobEntity.cannotDespawn`.
+    #[must_use]
+    pub fn cannot_despawn(&self) -> bool {
+        let entity = &self.living_entity.entity;
+        self.persistent.load(Relaxed)
+            || entity.custom_name.lock().unwrap().is_some()
+            || entity.entity_type.category.is_persistent
+    }
+
+    /// Removes the mob when it is far away from every player. Returns whether it despawned.
+    pub async fn check_despawn(&self) -> bool {
+        let entity = &self.living_entity.entity;
+        let idle_ticks = self.despawn_counter.fetch_add(1, Relaxed) + 1;
+        let category = entity.entity_type.category;
+        if self.cannot_despawn() {
+            self.despawn_counter.store(0, Relaxed);
+            return false;
+        }
+
+        let pos = entity.pos.load();
+        let Some(player) = entity.world.get_closest_player(pos, f64::MAX).await else {
+            self.despawn_counter.store(0, Relaxed);
+            return false;
+        };
+        let distance_squared = player
+            .living_entity
+            .entity
+            .pos
+            .load()
+            .squared_distance_to_vec(pos);
+
+        if should_despawn(
+            distance_squared,
+            f64::from(category.despawn_distance),
+            idle_ticks,
+            rand::rng().random_range(0..DESPAWN_CHANCE),
+        ) {
+            entity.discard().await;
+            return true;
+        }
+        if distance_squared < NO_DESPAWN_DISTANCE * NO_DESPAWN_DISTANCE {
+            self.despawn_counter.store(0, Relaxed);
+        }
+        false
+    }
+
+    /// Whether a player is close enough for the mob to run its AI, see the `activation_range`
+    /// config. Mobs outside of it still move and despawn. Mobs with a target keep chasing it.
+    pub async fn is_active(&self) -> bool {
+        let range = advanced_config().mob.activation_range;
+        let entity = &self.living_entity.entity;
+        range == 0
+            || self.target.lock().await.is_some()
+            || entity
+                .world
+                .get_closest_player(entity.pos.load(), f64::from(range))
+                .await
+                .is_some()
+    }
+
+    /// Keeps the mob within `range` blocks of `target`, a range of -1 lets it roam freely
+    pub fn set_position_target(&self, target: BlockPos, range: i32) {
+        self.position_target.store(target);
+        self.position_target_range.store(range, Relaxed);
+    }
+
     pub fn is_in_position_target_range(&self) -> bool {
         self.is_in_position_target_range_pos(self.living_entity.entity.block_pos.load())
     }
@@ -59,7 +200,152 @@ impl MobEntity {
     }
 }
 
+/// Counts down `conversion_time` by a tick, -1 means no conversion is running.
+/// Returns the new time and whether the conversion finished.
+#[must_use]
+pub fn tick_conversion(conversion_time: i32) -> (i32, bool) {
+    if conversion_time < 0 {
+        return (conversion_time, false);
+    }
+    let conversion_time = conversion_time - 1;
+    if conversion_time < 0 {
+        (-1, true)
+    } else {
+        (conversion_time, false)
+    }
+}
+
+/// Data a mob keeps when it converts into another mob.
+const CONVERSION_NBT_KEYS: [&str; 8] = [
+    "Pos",
+    "Motion",
+    "Rotation",
+    "CustomName",
+    "PersistenceRequired",
+    "Invulnerable",
+    "Glowing",
+    "Invisible",
+];
+
+/// Merges the data kept from the old mob into the data of the converted mob.
+#[must_use]
+pub fn conversion_nbt(old: &NbtCompound, new: NbtCompound) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+    for key in CONVERSION_NBT_KEYS {
+        if let Some(tag) = old.get(key) {
+            nbt.put(key, tag.clone());
+        }
+    }
+    // Keys which are already set are skipped
+    for (key, tag) in new.child_tags {
+        nbt.put(&key, tag);
+    }
+    nbt
+}
+
+/// Whether the mob reevaluates all its goals this tick instead of only ticking the running ones.
+/// Mobs are spread over `interval` buckets by their id, so each one updates on a fixed 1-in-`interval` schedule.
+#[must_use]
+pub fn should_update_goals(age: i32, entity_id: i32, interval: u32) -> bool {
+    let interval = i64::from(interval.max(1));
+    age <= 1 || (i64::from(age) + i64::from(entity_id)).rem_euclid(interval) == 0
+}
+
+/// Ticks the goals, navigation and looking around of `mob`. Returns whether the AI ran, which it
+/// doesn't while the mob is out of every player's activation range.
+async fn tick_ai(mob: &dyn Mob) -> bool {
+    let mob_entity = mob.get_mob_entity();
+    if !mob_entity.is_active().await {
+        mob_entity.living_entity.jumping.store(false, Relaxed);
+        return false;
+    }
+
+    let age = mob_entity.living_entity.entity.age.load(Relaxed);
+    let interval = advanced_config().mob.goal_tick_interval.get();
+    if should_update_goals(age, mob_entity.living_entity.entity.entity_id, interval) {
+        mob_entity.target_selector.tick(mob).await;
+        mob_entity.goals_selector.tick(mob).await;
+    } else {
+        mob_entity.target_selector.tick_goals(mob, false).await;
+        mob_entity.goals_selector.tick_goals(mob, false).await;
+    }
+
+    let mut navigator = mob_entity.navigator.lock().await;
+    navigator
+        .tick(&mob_entity.living_entity, &mob_entity.jump_control)
+        .await;
+    drop(navigator);
+
+    let look_control = mob_entity.look_control.lock().await;
+    look_control.tick(mob).await;
+    drop(look_control);
+
+    // Picked up by the next movement tick
+    mob_entity
+        .living_entity
+        .jumping
+        .store(mob_entity.jump_control.tick(), Relaxed);
+
+    let entity = &mob_entity.living_entity.entity;
+    let velocity = entity.velocity.load();
+    let head_yaw = entity.head_yaw.load();
+    // Walking mobs face where they go, standing ones turn towards where they look
+    let target_yaw = if velocity.horizontal_length_squared() > 0.0025 {
+        velocity.z.atan2(velocity.x).to_degrees() as f32 - 90.0
+    } else {
+        head_yaw
+    };
+    let body_yaw = entity.body_yaw.load();
+    let new_body_yaw = turn_body(
+        body_yaw,
+        target_yaw,
+        head_yaw,
+        mob.get_max_head_rotation() as f32,
+    );
+    entity.body_yaw.store(new_body_yaw);
+    // The head is clamped and the body turned, only what clients don't know yet is sent
+    entity.send_body_rotation().await;
+    true
+}
+
+/// Turns the body a step towards `target_yaw`, then drags it along if the head is turned further
+/// than `max_head_rotation` away from it, like vanilla's `LivingEntity.tickHeadTurn`.
+#[must_use]
+pub fn turn_body(body_yaw: f32, target_yaw: f32, head_yaw: f32, max_head_rotation: f32) -> f32 {
+    let body_yaw = body_yaw + wrap_degrees(target_yaw - body_yaw) * 0.3;
+    let head_offset = wrap_degrees(head_yaw - body_yaw);
+    if head_offset.abs() > max_head_rotation {
+        body_yaw + head_offset - head_offset.signum() * max_head_rotation
+    } else {
+        body_yaw
+    }
+}
+
+/// Mobs closer than this to a player never despawn.
+const NO_DESPAWN_DISTANCE: f64 = 32.0;
+/// Ticks a mob has to be away from players before it may randomly despawn.
+const DESPAWN_IDLE_TICKS: i32 = 600;
+/// After the idle ticks, a far away mob despawns with a chance of 1 in this per tick.
+const DESPAWN_CHANCE: u32 = 800;
+
+/// Whether a mob `distance_squared` away from the closest player despawns, like vanilla's
+/// `MobEntity.checkDespawn`. Beyond `immediate_despawn_distance` mobs always despawn, beyond
+/// [`NO_DESPAWN_DISTANCE`] only randomly after being idle. `roll` is random in `0..DESPAWN_CHANCE`.
+#[must_use]
+pub fn should_despawn(
+    distance_squared: f64,
+    immediate_despawn_distance: f64,
+    idle_ticks: i32,
+    roll: u32,
+) -> bool {
+    distance_squared > immediate_despawn_distance * immediate_despawn_distance
+        || (idle_ticks > DESPAWN_IDLE_TICKS
+            && roll == 0
+            && distance_squared > NO_DESPAWN_DISTANCE * NO_DESPAWN_DISTANCE)
+}
+
 // This trait contains all overridable functions
+#[async_trait]
 pub trait Mob: EntityBase + Send + Sync {
     fn get_random(&self) -> rand::rngs::ThreadRng {
         rand::rng()
@@ -82,6 +368,19 @@ pub trait Mob: EntityBase + Send + Sync {
     fn get_path_aware_entity(&self) -> Option<&dyn PathAwareEntity> {
         None
     }
+
+    /// Mob specific logic, ticked before the AI. The mob may remove itself here, e.g. by converting.
+    async fn mob_tick(&self) {}
+
+    /// Drops the mob's items when it dies, by default the loot table of its type
+    async fn drop_loot(&self, cause: Option<&dyn EntityBase>) {
+        let params = LootContextParameters {
+            killed_by_player: cause
+                .map(|cause| cause.get_entity().entity_type == &EntityType::PLAYER),
+            ..Default::default()
+        };
+        self.get_mob_entity().living_entity.drop_loot(params).await;
+    }
 }
 
 #[async_trait]
@@ -92,23 +391,15 @@ where
     async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
         let mob_entity = self.get_mob_entity();
         mob_entity.living_entity.tick(caller, server).await;
-
-        let age = mob_entity.living_entity.entity.age.load(Relaxed);
-        if (age + mob_entity.living_entity.entity.entity_id) % 2 != 0 && age > 1 {
-            mob_entity.target_selector.tick_goals(self, false).await;
-            mob_entity.goals_selector.tick_goals(self, false).await;
-        } else {
-            mob_entity.target_selector.tick(self).await;
-            mob_entity.goals_selector.tick(self).await;
+        if mob_entity.check_despawn().await {
+            return;
+        }
+        self.mob_tick().await;
+        if mob_entity.living_entity.entity.is_removed() {
+            return;
         }
 
-        let mut navigator = mob_entity.navigator.lock().await;
-        navigator.tick(&mob_entity.living_entity).await;
-        drop(navigator);
-
-        let look_control = mob_entity.look_control.lock().await;
-        look_control.tick(self).await;
-        drop(look_control);
+        tick_ai(self).await;
     }
 
     async fn damage_with_context(
@@ -126,6 +417,24 @@ where
             .await
     }
 
+    async fn on_death(&self, cause: Option<&dyn EntityBase>) {
+        self.get_mob_entity().living_entity.on_death(cause).await;
+        self.drop_loot(cause).await;
+    }
+
+    async fn interact(&self, player: &Arc<Player>, hand: Hand) -> InteractionResult {
+        if bucketable::interact(self, player, hand).await == InteractionResult::Success {
+            return InteractionResult::Success;
+        }
+        let mob_entity = self.get_mob_entity();
+        let result = mob_entity.living_entity.interact(player, hand).await;
+        // Mobs named with a name tag never despawn
+        if result == InteractionResult::Success {
+            mob_entity.persistent.store(true, Relaxed);
+        }
+        result
+    }
+
     fn get_entity(&self) -> &Entity {
         &self.get_mob_entity().living_entity.entity
     }
@@ -134,6 +443,10 @@ where
         Some(&self.get_mob_entity().living_entity)
     }
 
+    fn get_mob(&self) -> Option<&MobEntity> {
+        Some(self.get_mob_entity())
+    }
+
     fn as_nbt_storage(&self) -> &dyn NBTStorage {
         self
     }
@@ -143,6 +456,74 @@ where
     }
 }
 
+#[async_trait]
+impl NBTStorage for MobEntity {
+    async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        self.living_entity.write_nbt(nbt).await;
+        nbt.put_bool("PersistenceRequired", self.persistent.load(Relaxed));
+    }
+
+    async fn read_nbt_non_mut(&self, nbt: &NbtCompound) {
+        self.living_entity.read_nbt_non_mut(nbt).await;
+        self.persistent.store(
+            nbt.get_bool("PersistenceRequired").unwrap_or(false),
+            Relaxed,
+        );
+    }
+}
+
+/// Why a mob is spawned, vanilla's `SpawnReason`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnReason {
+    Natural,
+    ChunkGeneration,
+    Spawner,
+    Structure,
+    Breeding,
+    MobSummoned,
+    Jockey,
+    Event,
+    Conversion,
+    Reinforcement,
+    Triggered,
+    Bucket,
+    SpawnItemUse,
+    Command,
+    Dispenser,
+    Patrol,
+    TrialSpawner,
+    Load,
+    DimensionTravel,
+}
+
+impl SpawnReason {
+    /// Mobs placed on purpose, e.g. with a spawn egg or `/summon`, don't need darkness.
+    #[must_use]
+    pub const fn checks_light(self) -> bool {
+        !matches!(
+            self,
+            Self::SpawnItemUse | Self::Command | Self::Dispenser | Self::TrialSpawner
+        )
+    }
+}
+
+/// Whether it is dark enough for a monster to spawn, like vanilla's `HostileEntity.isSpawnDark`.
+/// The sky light counts dimmed by `ambient_darkness` and there may be at most
+/// `block_light_limit` block light, which the dimension decides. `sky_roll` is random in `0..32`
+/// and `light_roll` is picked from the dimension's monster spawn light levels.
+#[must_use]
+pub fn is_spawn_dark(
+    (sky_light, block_light): (u8, u8),
+    ambient_darkness: u8,
+    block_light_limit: u8,
+    sky_roll: u8,
+    light_roll: u8,
+) -> bool {
+    sky_light <= sky_roll
+        && (block_light_limit >= 15 || block_light <= block_light_limit)
+        && sky_light.saturating_sub(ambient_darkness).max(block_light) <= light_roll
+}
+
 #[allow(dead_code)]
 const DEFAULT_PATHFINDING_FAVOR: f32 = 0.0;
 #[async_trait]
@@ -151,12 +532,44 @@ pub trait PathAwareEntity: Mob + Send + Sync {
         0.0
     }
 
-    // TODO: missing SpawnReason attribute
-    fn can_spawn(&self, world: Arc<World>) -> bool {
-        self.get_pathfinding_favor(
-            self.get_mob_entity().living_entity.entity.block_pos.load(),
-            world,
-        ) >= 0.0
+    /// Whether the mob can spawn where it is: on a solid block, with room for its bounding box and,
+    /// for monsters, in darkness.
+    async fn can_spawn(&self, world: Arc<World>, reason: SpawnReason) -> bool {
+        let entity = &self.get_mob_entity().living_entity.entity;
+        let block_pos = entity.block_pos.load();
+        if self.get_pathfinding_favor(block_pos, world.clone()) < 0.0 {
+            return false;
+        }
+        if !world
+            .get_block_state(&block_pos.down())
+            .await
+            .is_side_solid(BlockDirection::Up)
+        {
+            return false;
+        }
+        if !world.is_space_empty(entity.bounding_box.load()).await {
+            return false;
+        }
+
+        if reason.checks_light() && entity.entity_type.category == &MobCategory::MONSTER {
+            let light_levels = world.get_light_levels(&block_pos).await;
+            // Thunderstorms are always dark enough
+            let ambient_darkness = if world.weather.lock().await.thundering {
+                10
+            } else {
+                world.ambient_darkness().await
+            };
+            let dimension = world.dimension_type;
+            let mut random = self.get_random();
+            return is_spawn_dark(
+                light_levels,
+                ambient_darkness,
+                dimension.monster_spawn_block_light_limit(),
+                random.random_range(0..32),
+                random.random_range(dimension.monster_spawn_light_level()),
+            );
+        }
+        true
     }
 
     async fn is_navigation(&self) -> bool {
@@ -185,3 +598,372 @@ pub trait PathAwareEntity: Mob + Send + Sync {
         1.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+
+    use super::{SpawnReason, is_spawn_dark, should_despawn, should_update_goals, turn_body};
+
+    #[tokio::test]
+    async fn killing_a_mob_drops_its_loot() {
+        use std::sync::atomic::Ordering;
+
+        use pumpkin_util::math::vector3::Vector3;
+
+        use super::Mob;
+        use crate::entity::mob::water_creature::WaterCreature;
+        use crate::entity::{Entity, EntityBase};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let cod = WaterCreature::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::COD,
+            false,
+        ))
+        .await;
+        world.spawn_entity(cod.clone()).await;
+
+        cod.kill(cod.clone()).await;
+        let living = &cod.get_mob_entity().living_entity;
+        assert!(living.dead.load(Ordering::Relaxed));
+        assert!(living.health.load() <= 0.0);
+        // A cod always drops itself
+        assert!(
+            world
+                .entities
+                .read()
+                .await
+                .values()
+                .any(|entity| entity.get_entity().entity_type == &EntityType::ITEM)
+        );
+    }
+
+    #[tokio::test]
+    async fn looking_behind_turns_the_head_only_as_far_as_it_can() {
+        use pumpkin_util::math::{subtract_angles, vector3::Vector3};
+
+        use super::Mob;
+        use crate::entity::Entity;
+        use crate::entity::mob::zombie::Zombie;
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        let mob_entity = zombie.get_mob_entity();
+        let entity = &mob_entity.living_entity.entity;
+
+        // Right behind the zombie, which faces south
+        let look_control = mob_entity.look_control.lock().await;
+        look_control.look_at_with_range(0.5, entity.get_eye_y(), -9.5, 180.0, 40.0);
+        look_control.tick(zombie.as_ref()).await;
+        drop(look_control);
+
+        let max_head_rotation = zombie.get_max_head_rotation() as f32;
+        let head_offset = subtract_angles(entity.body_yaw.load(), entity.head_yaw.load());
+        assert!((head_offset.abs() - max_head_rotation).abs() < 1e-3);
+        // Only the head turned so far
+        assert!(entity.body_yaw.load().abs() < 1e-4);
+        assert!(entity.yaw.load().abs() < 1e-4);
+    }
+
+    #[tokio::test]
+    async fn turning_mobs_send_their_body_yaw_and_head_yaw_apart() {
+        use pumpkin_util::math::vector3::Vector3;
+
+        use super::Mob;
+        use crate::entity::mob::zombie::Zombie;
+        use crate::entity::{Entity, EntityBase};
+        use crate::server::Server;
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().join("server")).await;
+        let world = World::for_test(temp_dir.path().join("world"));
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        let entity = &zombie.get_mob_entity().living_entity.entity;
+        entity.yaw.store(0.0);
+        entity.body_yaw.store(90.0);
+        entity.head_yaw.store(120.0);
+
+        zombie.tick(zombie.clone(), &server).await;
+
+        // What was sent last is what the mob looks like now, so nothing is left to send
+        let body_yaw = Entity::angle_to_byte(entity.body_yaw.load());
+        let pitch = Entity::angle_to_byte(entity.pitch.load());
+        let on_ground = entity.on_ground.load(std::sync::atomic::Ordering::Relaxed);
+        assert!(!entity.sent_rotation.update((body_yaw, pitch, on_ground)));
+        assert!(
+            !entity
+                .sent_head_yaw
+                .update(Entity::angle_to_byte(entity.head_yaw.load()))
+        );
+        assert_ne!(body_yaw, Entity::angle_to_byte(entity.head_yaw.load()));
+    }
+
+    #[test]
+    fn goals_update_on_a_fixed_schedule() {
+        let entity_id = 7;
+        let updates: Vec<_> = (2..22)
+            .filter(|&age| should_update_goals(age, entity_id, 4))
+            .collect();
+        assert_eq!(updates, vec![5, 9, 13, 17, 21]);
+
+        // Other mobs fall into the other buckets
+        let buckets: Vec<_> = (0..4)
+            .map(|id| (2..6).find(|&age| should_update_goals(age, id, 4)))
+            .collect();
+        assert_eq!(buckets, vec![Some(4), Some(3), Some(2), Some(5)]);
+
+        // Freshly spawned mobs always pick their goals right away
+        assert!(should_update_goals(0, entity_id, 4));
+        assert!(should_update_goals(1, entity_id, 4));
+    }
+
+    #[test]
+    fn body_turns_gradually_and_follows_the_head() {
+        // Eases towards the target instead of snapping
+        let body_yaw = turn_body(0.0, 90.0, 0.0, 75.0);
+        assert!((body_yaw - 27.0).abs() < 1e-4);
+
+        // Takes the short way around
+        let body_yaw = turn_body(170.0, -170.0, 170.0, 75.0);
+        assert!((body_yaw - 176.0).abs() < 1e-4);
+
+        // A head turned past the cap drags the body along
+        let body_yaw = turn_body(0.0, 0.0, 120.0, 75.0);
+        assert!((body_yaw - 45.0).abs() < 1e-4);
+        let body_yaw = turn_body(0.0, 0.0, -120.0, 75.0);
+        assert!((body_yaw + 45.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn far_away_mobs_despawn() {
+        let immediate = f64::from(EntityType::ZOMBIE.category.despawn_distance);
+        assert!(!EntityType::ZOMBIE.category.is_persistent);
+
+        // Beyond the immediate despawn distance, right away
+        assert!(should_despawn(200.0 * 200.0, immediate, 0, 799));
+
+        // Between 32 and 128 blocks only after being idle, and by chance
+        let distance_squared = 64.0 * 64.0;
+        assert!(!should_despawn(distance_squared, immediate, 600, 0));
+        assert!(!should_despawn(distance_squared, immediate, 601, 1));
+        assert!(should_despawn(distance_squared, immediate, 601, 0));
+
+        // Never close to a player
+        assert!(!should_despawn(16.0 * 16.0, immediate, 10_000, 0));
+    }
+
+    #[tokio::test]
+    async fn named_and_persistent_mobs_are_kept() {
+        use pumpkin_nbt::compound::NbtCompound;
+        use pumpkin_util::math::vector3::Vector3;
+        use pumpkin_util::text::TextComponent;
+
+        use super::Mob;
+        use crate::entity::{Entity, NBTStorage, mob::zombie::Zombie};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_zombie = || {
+            let world = world.clone();
+            async move {
+                Zombie::make(Entity::new(
+                    uuid::Uuid::new_v4(),
+                    world,
+                    Vector3::new(0.5, 300.0, 0.5),
+                    &EntityType::ZOMBIE,
+                    false,
+                ))
+                .await
+            }
+        };
+
+        let zombie = new_zombie().await;
+        assert!(!zombie.get_mob_entity().cannot_despawn());
+
+        let named = new_zombie().await;
+        let entity = &named.get_mob_entity().living_entity.entity;
+        *entity.custom_name.lock().unwrap() = Some(TextComponent::text("Bob"));
+        assert!(named.get_mob_entity().cannot_despawn());
+        assert!(!named.get_mob_entity().check_despawn().await);
+        assert!(entity.is_alive());
+
+        let persistent = new_zombie().await;
+        let mut nbt = NbtCompound::new();
+        nbt.put_bool("PersistenceRequired", true);
+        persistent.get_mob_entity().read_nbt_non_mut(&nbt).await;
+        assert!(persistent.get_mob_entity().cannot_despawn());
+    }
+
+    #[test]
+    fn monsters_spawn_in_darkness() {
+        assert!(is_spawn_dark((0, 0), 0, 0, 0, 0));
+        // Any block light prevents it in the overworld
+        assert!(!is_spawn_dark((0, 1), 0, 0, 31, 7));
+        // Sky light has to pass both rolls
+        assert!(is_spawn_dark((5, 0), 0, 0, 10, 7));
+        assert!(!is_spawn_dark((5, 0), 0, 0, 4, 7));
+        assert!(!is_spawn_dark((5, 0), 0, 0, 10, 4));
+        assert!(!is_spawn_dark((15, 0), 0, 0, 31, 7));
+        // Unless it is night, which dims the sky light
+        assert!(is_spawn_dark((15, 0), 11, 0, 31, 4));
+        // The nether lets monsters spawn in block light up to its light level
+        assert!(is_spawn_dark((0, 7), 0, 15, 0, 7));
+        assert!(!is_spawn_dark((0, 8), 0, 15, 0, 7));
+
+        assert!(SpawnReason::Natural.checks_light());
+        assert!(!SpawnReason::Command.checks_light());
+        assert!(!SpawnReason::SpawnItemUse.checks_light());
+    }
+
+    #[tokio::test]
+    async fn killing_a_zombie_spawns_experience_orbs() {
+        use pumpkin_data::damage::DamageType;
+        use pumpkin_util::math::vector3::Vector3;
+
+        use crate::entity::{Entity, r#type::spawn_from_type};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, pos)
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        let killer = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            pos,
+            &EntityType::PLAYER,
+            false,
+        );
+
+        assert!(
+            zombie
+                .damage_with_context(
+                    zombie.clone(),
+                    f32::MAX,
+                    DamageType::PLAYER_ATTACK,
+                    None,
+                    Some(&killer),
+                    Some(&killer),
+                )
+                .await
+        );
+        let experience_orbs = world
+            .entities
+            .read()
+            .await
+            .values()
+            .filter(|entity| entity.get_entity().entity_type == &EntityType::EXPERIENCE_ORB)
+            .count();
+        assert!(experience_orbs > 0);
+    }
+
+    #[tokio::test]
+    async fn only_mobs_near_players_or_chasing_a_target_run_their_ai() {
+        use async_trait::async_trait;
+        use pumpkin_util::GameMode;
+        use pumpkin_util::math::vector3::Vector3;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicU32, Ordering::Relaxed};
+
+        use super::{Mob, tick_ai, zombie::Zombie};
+        use crate::entity::ai::goal::{Control, Goal, GoalControl};
+        use crate::entity::player::Player;
+        use crate::entity::{Entity, EntityBase};
+        use crate::world::World;
+
+        struct CountingGoal {
+            goal_control: GoalControl,
+            checks: AtomicU32,
+        }
+
+        #[async_trait]
+        impl Goal for CountingGoal {
+            async fn can_start(&self, _mob: &dyn Mob) -> bool {
+                self.checks.fetch_add(1, Relaxed);
+                false
+            }
+
+            async fn should_continue(&self, _mob: &dyn Mob) -> bool {
+                false
+            }
+
+            async fn start(&self, _mob: &dyn Mob) {}
+
+            async fn stop(&self, _mob: &dyn Mob) {}
+
+            async fn tick(&self, _mob: &dyn Mob) {}
+
+            fn get_goal_control(&self) -> &GoalControl {
+                &self.goal_control
+            }
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_zombie = || {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )
+        };
+        let zombie = Zombie::make(new_zombie()).await;
+        let goal = Arc::new(CountingGoal {
+            goal_control: GoalControl::from_array(&[Control::Move]),
+            checks: AtomicU32::new(0),
+        });
+        let mob_entity = zombie.get_mob_entity();
+        mob_entity.goals_selector.add_goal(0, goal.clone()).await;
+        mob_entity.living_entity.jumping.store(true, Relaxed);
+
+        // No player is anywhere near, so the goals aren't even considered
+        assert!(!mob_entity.is_active().await);
+        for _ in 0..10 {
+            assert!(!tick_ai(zombie.as_ref()).await);
+        }
+        assert_eq!(goal.checks.load(Relaxed), 0);
+        assert!(!mob_entity.living_entity.jumping.load(Relaxed));
+
+        // A player within the zombie's follow range wakes it up
+        let (player, _packets) = Player::for_test(&world, GameMode::Survival).await;
+        player.get_entity().set_pos(Vector3::new(20.5, 300.0, 0.5));
+        assert!(mob_entity.is_active().await);
+        assert!(tick_ai(zombie.as_ref()).await);
+        assert!(goal.checks.load(Relaxed) > 0);
+
+        // Once chasing something, it keeps going even without players around
+        world.players.write().await.clear();
+        assert!(!mob_entity.is_active().await);
+        let target: Arc<dyn EntityBase> = Arc::new(new_zombie());
+        *mob_entity.target.lock().await = Some(target);
+        assert!(tick_ai(zombie.as_ref()).await);
+    }
+}
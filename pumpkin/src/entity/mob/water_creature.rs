@@ -0,0 +1,115 @@
+use super::{Mob, MobEntity};
+use crate::entity::ai::goal::swim_around_goal::SwimAroundGoal;
+use crate::entity::ai::path::Navigator;
+use crate::entity::{Entity, NBTStorage};
+use async_trait::async_trait;
+use pumpkin_nbt::compound::NbtCompound;
+use std::sync::Arc;
+
+/// Fish, squids and other mobs living in water. They swim around, float while submerged and drown
+/// on land, like vanilla's `WaterCreatureEntity`.
+pub struct WaterCreature {
+    mob_entity: MobEntity,
+}
+
+impl WaterCreature {
+    pub async fn make(entity: Entity) -> Arc<Self> {
+        let mob_entity = MobEntity::new(entity);
+        *mob_entity.navigator.lock().await = Navigator::swimming();
+        let mob_arc = Arc::new(Self { mob_entity });
+
+        mob_arc
+            .mob_entity
+            .goals_selector
+            .add_goal(4, Arc::new(SwimAroundGoal::new(0.1, 40)))
+            .await;
+
+        mob_arc
+    }
+}
+
+#[async_trait]
+impl NBTStorage for WaterCreature {
+    async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        self.mob_entity.write_nbt(nbt).await;
+    }
+
+    async fn read_nbt_non_mut(&self, nbt: &NbtCompound) {
+        self.mob_entity.read_nbt_non_mut(nbt).await;
+    }
+}
+
+#[async_trait]
+impl Mob for WaterCreature {
+    fn get_mob_entity(&self) -> &MobEntity {
+        &self.mob_entity
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::Ordering;
+
+    use pumpkin_data::Block;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_world::world::BlockFlags;
+
+    use crate::entity::living::LivingEntity;
+    use crate::entity::{Entity, EntityBase, MAX_AIR_SUPPLY, r#type::from_type};
+    use crate::server::Server;
+
+    #[tokio::test]
+    async fn cod_floats_underwater_and_drowns_on_land() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        world
+            .set_block_state(
+                &BlockPos::new(0, 299, 0),
+                Block::STONE.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        let cod = from_type(
+            &EntityType::COD,
+            Vector3::new(0.5, 300.0, 0.5),
+            &world,
+            uuid::Uuid::new_v4(),
+        )
+        .await;
+        assert!(cod.is_aquatic());
+        assert!(cod.get_mob().is_some());
+        let living = cod.get_living_entity().unwrap();
+
+        // Submerged, it rises instead of sinking
+        let entity = cod.get_entity();
+        entity.push_by_buoyancy(true);
+        let velocity_y = entity.velocity.load().y;
+        assert!(velocity_y > 0.0);
+        assert!(LivingEntity::fluid_falling_speed(velocity_y, 0.0, false, false) > 0.0);
+        entity.velocity.store(Vector3::default());
+
+        // On land, it runs out of air and then drowns
+        let health = living.health.load();
+        for _ in 0..MAX_AIR_SUPPLY + 19 {
+            living.tick(cod.clone(), &server).await;
+        }
+        assert!((living.health.load() - health).abs() < f32::EPSILON);
+        living.tick(cod.clone(), &server).await;
+        assert!((health - living.health.load() - 2.0).abs() < f32::EPSILON);
+
+        // Water refills its air right away
+        world
+            .set_block_state(
+                &BlockPos::new(0, 300, 0),
+                Block::WATER.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        living.tick(cod.clone(), &server).await;
+        assert_eq!(entity.air_supply.load(Ordering::Relaxed), MAX_AIR_SUPPLY);
+        assert!(!Entity::is_aquatic_type(&EntityType::ZOMBIE));
+    }
+}
@@ -13,18 +13,31 @@ use async_trait::async_trait;
 use pumpkin_data::Block;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::sound::{Sound, SoundCategory};
+use pumpkin_data::world::WorldEvent;
+use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::math::position::BlockPos;
 use rand::{Rng, rng};
+use std::sync::atomic::{AtomicI32, Ordering::Relaxed};
 use std::sync::{Arc, Weak};
 
+/// Ticks a zombie has to be underwater before it starts converting into a drowned.
+const DROWNED_CONVERSION_DELAY: i32 = 600;
+/// Ticks a zombie takes to convert into a drowned.
+const DROWNED_CONVERSION_TIME: i32 = 300;
+
 pub struct Zombie {
     mob_entity: MobEntity,
+    /// Ticks spent underwater, -1 if not underwater
+    in_water_time: AtomicI32,
 }
 
 impl Zombie {
     pub async fn make(entity: Entity) -> Arc<Self> {
         let mob_entity = MobEntity::new(entity);
-        let zombie = Self { mob_entity };
+        let zombie = Self {
+            mob_entity,
+            in_water_time: AtomicI32::new(0),
+        };
         let mob_arc = Arc::new(zombie);
         let mob_weak: Weak<dyn Mob> = {
             let mob_arc: Arc<dyn Mob> = mob_arc.clone();
@@ -66,14 +79,82 @@ impl Zombie {
 
         mob_arc
     }
+
+    /// Counts the ticks spent underwater. Returns the new time and whether the zombie has been
+    /// underwater long enough to start converting.
+    fn tick_in_water_time(in_water_time: i32, submerged: bool) -> (i32, bool) {
+        if submerged {
+            let in_water_time = in_water_time + 1;
+            (in_water_time, in_water_time >= DROWNED_CONVERSION_DELAY)
+        } else {
+            (-1, false)
+        }
+    }
+
+    async fn tick_drowning(&self) {
+        let mob_entity = &self.mob_entity;
+        let entity = &mob_entity.living_entity.entity;
+        // Drowned and husks are zombies too, but only zombies drown
+        if entity.entity_type != &EntityType::ZOMBIE {
+            return;
+        }
+        if mob_entity.is_converting() {
+            if mob_entity.tick_conversion() {
+                mob_entity.convert_to(&EntityType::DROWNED).await;
+                entity
+                    .world
+                    .sync_world_event(
+                        WorldEvent::ZombieConvertsToDrowned,
+                        entity.block_pos.load(),
+                        0,
+                    )
+                    .await;
+            }
+            return;
+        }
+        let (in_water_time, convert) = Self::tick_in_water_time(
+            self.in_water_time.load(Relaxed),
+            entity.is_eye_in_water().await,
+        );
+        self.in_water_time.store(in_water_time, Relaxed);
+        if convert {
+            mob_entity.start_conversion(DROWNED_CONVERSION_TIME);
+        }
+    }
 }
 
-impl NBTStorage for Zombie {}
+#[async_trait]
+impl NBTStorage for Zombie {
+    async fn write_nbt(&self, nbt: &mut NbtCompound) {
+        self.mob_entity.write_nbt(nbt).await;
+        nbt.put_int("InWaterTime", self.in_water_time.load(Relaxed));
+        nbt.put_int(
+            "DrownedConversionTime",
+            self.mob_entity.conversion_time.load(Relaxed),
+        );
+    }
+
+    async fn read_nbt_non_mut(&self, nbt: &NbtCompound) {
+        self.mob_entity.read_nbt_non_mut(nbt).await;
+        self.in_water_time
+            .store(nbt.get_int("InWaterTime").unwrap_or(0), Relaxed);
+        self.mob_entity.start_conversion(
+            nbt.get_int("DrownedConversionTime")
+                .filter(|&time| time > -1)
+                .unwrap_or(-1),
+        );
+    }
+}
 
+#[async_trait]
 impl Mob for Zombie {
     fn get_mob_entity(&self) -> &MobEntity {
         &self.mob_entity
     }
+
+    async fn mob_tick(&self) {
+        self.tick_drowning().await;
+    }
 }
 
 pub struct DestroyEggGoal {
@@ -170,3 +251,52 @@ impl MoveToTargetPos for DestroyEggGoal {
         1.14
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_nbt::compound::NbtCompound;
+
+    use super::{DROWNED_CONVERSION_TIME, Zombie};
+    use crate::entity::mob::{conversion_nbt, tick_conversion};
+
+    #[test]
+    fn zombie_underwater_converts_into_drowned_with_its_name() {
+        let mut in_water_time = 0;
+        let mut conversion_time = -1;
+        let mut ticks = 0;
+        loop {
+            ticks += 1;
+            if conversion_time >= 0 {
+                let (time, convert) = tick_conversion(conversion_time);
+                conversion_time = time;
+                if convert {
+                    break;
+                }
+            } else {
+                let (time, start) = Zombie::tick_in_water_time(in_water_time, true);
+                in_water_time = time;
+                if start {
+                    conversion_time = DROWNED_CONVERSION_TIME;
+                }
+            }
+            assert!(ticks < 2000, "zombie never converted");
+        }
+        assert_eq!(ticks, 600 + 301);
+
+        // Surfacing resets the time underwater
+        assert_eq!(Zombie::tick_in_water_time(400, false), (-1, false));
+
+        let mut zombie = NbtCompound::new();
+        zombie.put_string("id", "minecraft:zombie".to_string());
+        zombie.put_string("CustomName", "Bob".to_string());
+        zombie.put_float("Health", 3.0);
+        let mut drowned = NbtCompound::new();
+        drowned.put_string("id", "minecraft:drowned".to_string());
+        drowned.put_float("Health", 20.0);
+
+        let converted = conversion_nbt(&zombie, drowned);
+        assert_eq!(converted.get_string("id"), Some("minecraft:drowned"));
+        assert_eq!(converted.get_string("CustomName"), Some("Bob"));
+        assert_eq!(converted.get_float("Health"), Some(20.0));
+    }
+}
@@ -1,19 +1,28 @@
+use crate::entity::attributes::AttributeManager;
+use crate::entity::data_tracker::{DataTracker, LastSent};
 use crate::entity::item::ItemEntity;
+use crate::entity::mob::MobEntity;
 use crate::world::World;
-use crate::{server::Server, world::portal::PortalManager};
+use crate::{
+    server::Server,
+    world::portal::{PortalManager, nether::NetherPortal},
+};
 use async_trait::async_trait;
-use bytes::BufMut;
 use crossbeam::atomic::AtomicCell;
 use living::LivingEntity;
 use player::Player;
 use pumpkin_data::BlockState;
-use pumpkin_data::block_properties::{EnumVariants, Integer0To15};
-use pumpkin_data::fluid::Fluid;
+use pumpkin_data::block_properties::{
+    BlockProperties, BubbleColumnLikeProperties, EnumVariants, Integer0To15,
+};
+use pumpkin_data::fluid::{Fluid, FluidState};
+use pumpkin_data::tag;
+use pumpkin_data::tag::Taggable;
 use pumpkin_data::{Block, BlockDirection};
 use pumpkin_data::{
     block_properties::{Facing, HorizontalFacing},
     damage::DamageType,
-    entity::{EntityPose, EntityType},
+    entity::{EntityPose, EntityStatus, EntityType, MobCategory},
     sound::{Sound, SoundCategory},
 };
 use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
@@ -24,14 +33,15 @@ use pumpkin_protocol::{
         CEntityPositionSync, CEntityVelocity, CHeadRot, CSetEntityMetadata, CSpawnEntity,
         CUpdateEntityRot, MetaDataType, Metadata,
     },
-    ser::serializer::Serializer,
 };
 use pumpkin_registry::VanillaDimensionType;
+use pumpkin_util::Hand;
 use pumpkin_util::math::vector3::Axis;
 use pumpkin_util::math::{
     boundingbox::{BoundingBox, EntityDimensions},
     get_section_cord,
     position::BlockPos,
+    subtract_angles,
     vector2::Vector2,
     vector3::Vector3,
     wrap_degrees,
@@ -50,6 +60,8 @@ use std::sync::{
 use tokio::sync::Mutex;
 
 pub mod ai;
+pub mod attributes;
+pub mod data_tracker;
 pub mod decoration;
 pub mod effect;
 pub mod experience_orb;
@@ -58,6 +70,7 @@ pub mod hunger;
 pub mod item;
 pub mod living;
 pub mod mob;
+pub mod physics;
 pub mod player;
 pub mod projectile;
 pub mod tnt;
@@ -66,6 +79,11 @@ pub mod r#type;
 mod combat;
 pub mod predicate;
 
+use physics::{
+    AIR_DRAG, AQUATIC_BUOYANCY, FLOAT_SPEED, LAVA_PUSH_SPEED, MAX_FLOAT_SPEED, MAX_VELOCITY,
+    NETHER_LAVA_PUSH_SPEED, POSITION_DELTA_SCALE, WATER_PUSH_SPEED,
+};
+
 #[async_trait]
 pub trait EntityBase: Send + Sync + NBTStorage {
     /// Called every tick for this entity.
@@ -83,17 +101,28 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         }
     }
 
-    async fn init_data_tracker(&self) {}
+    /// Registers the baseline metadata entries in the entity's [`DataTracker`], before it is
+    /// sent to players that start seeing the entity.
+    async fn init_data_tracker(&self) {
+        if let Some(living) = self.get_living_entity() {
+            living.init_tracked_data();
+        } else {
+            self.get_entity().init_tracked_data();
+        }
+    }
 
+    /// Moves the entity to `position`. The velocity is reset like vanilla's `/tp` does, unless
+    /// `keep_velocity` is set, e.g. when going through a portal.
     async fn teleport(
         self: Arc<Self>,
         position: Vector3<f64>,
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        keep_velocity: bool,
     ) {
         self.get_entity()
-            .teleport(position, yaw, pitch, world)
+            .teleport(position, yaw, pitch, world, keep_velocity)
             .await;
     }
 
@@ -120,8 +149,23 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         false
     }
 
-    fn is_collidable(&self, _entity: Option<Box<dyn EntityBase>>) -> bool {
-        false
+    /// Spectators and other entities without collision pass through portals
+    fn can_use_portals(&self) -> bool {
+        let entity = self.get_entity();
+        Entity::type_can_use_portals(entity.entity_type)
+            && !entity.no_clip.load(Relaxed)
+            && !self.is_spectator()
+    }
+
+    /// Whether `entity` collides with this entity instead of moving through it. Boats, minecarts
+    /// and shulkers block others like a solid block, but never themselves.
+    fn is_collidable(&self, entity: Option<&dyn EntityBase>) -> bool {
+        let this = self.get_entity();
+        let entity_type = this.entity_type;
+        (entity_type == &EntityType::SHULKER
+            || tag::EntityType::MINECRAFT_BOAT.1.contains(&entity_type.id)
+            || tag::EntityType::C_MINECARTS.1.contains(&entity_type.id))
+            && entity.is_none_or(|entity| entity.get_entity().entity_id != this.entity_id)
     }
 
     fn can_hit(&self) -> bool {
@@ -132,6 +176,35 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         false
     }
 
+    /// Called once when the entity dies, `cause` being the entity that killed it. Plays the death
+    /// sound and drops the experience of the entity's type if a player killed it.
+    async fn on_death(&self, cause: Option<&dyn EntityBase>) {
+        let entity = self.get_entity();
+        entity
+            .world
+            .send_entity_status(
+                entity,
+                EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
+            )
+            .await;
+        if cause.is_some_and(|cause| cause.get_entity().entity_type == &EntityType::PLAYER) {
+            let experience = LivingEntity::experience_reward(entity.entity_type);
+            if experience > 0 {
+                entity
+                    .world
+                    .spawn_experience_orbs(entity.pos.load(), experience)
+                    .await;
+            }
+        }
+    }
+
+    /// Aquatic mobs swim through water, float while submerged and drown on land
+    fn is_aquatic(&self) -> bool {
+        Entity::is_aquatic_type(self.get_entity().entity_type)
+    }
+
+    /// Implementations should return `false` right away if
+    /// [`Entity::is_invulnerable_to`] the damage type.
     async fn damage_with_context(
         &self,
         _caller: Arc<dyn EntityBase>,
@@ -147,13 +220,37 @@ pub trait EntityBase: Send + Sync + NBTStorage {
 
     /// Called when a player collides with a entity
     async fn on_player_collision(&self, _player: &Arc<Player>) {}
+
+    /// Called when a player right clicks the entity. If the interaction doesn't consume the
+    /// action, the item in the player's hand is used on the entity instead.
+    async fn interact(&self, _player: &Arc<Player>, _hand: Hand) -> InteractionResult {
+        InteractionResult::Pass
+    }
+
     fn get_entity(&self) -> &Entity;
     fn get_living_entity(&self) -> Option<&LivingEntity>;
 
+    /// The attributes of living entities, `None` for every other entity
+    fn get_attribute_manager(&self) -> Option<&AttributeManager> {
+        self.get_living_entity()
+            .map(|living| &living.attribute_manager)
+    }
+
     fn get_item_entity(self: Arc<Self>) -> Option<Arc<ItemEntity>> {
         None
     }
 
+    /// The AI of mobs, `None` for every other entity
+    fn get_mob(&self) -> Option<&MobEntity> {
+        None
+    }
+
+    /// Whether the entity gets written to its chunk, `false` for entities that never persist like
+    /// players and fishing bobbers
+    fn should_save(&self) -> bool {
+        self.get_entity().entity_type.saveable
+    }
+
     fn get_player(&self) -> Option<&Player> {
         None
     }
@@ -163,6 +260,8 @@ pub trait EntityBase: Send + Sync + NBTStorage {
         let entity = self.get_entity();
         entity
             .custom_name
+            .lock()
+            .unwrap()
             .clone()
             .unwrap_or(TextComponent::translate(
                 format!("entity.minecraft.{}", entity.entity_type.resource_name),
@@ -172,13 +271,16 @@ pub trait EntityBase: Send + Sync + NBTStorage {
     async fn get_display_name(&self) -> TextComponent {
         // TODO: team color
         let entity = self.get_entity();
-        let mut name = entity
-            .custom_name
-            .clone()
-            .unwrap_or(TextComponent::translate(
-                format!("entity.minecraft.{}", entity.entity_type.resource_name),
-                [],
-            ));
+        let mut name =
+            entity
+                .custom_name
+                .lock()
+                .unwrap()
+                .clone()
+                .unwrap_or(TextComponent::translate(
+                    format!("entity.minecraft.{}", entity.entity_type.resource_name),
+                    [],
+                ));
         let name_clone = name.clone();
         name = name.hover_event(HoverEvent::show_entity(
             entity.entity_uuid.to_string(),
@@ -191,9 +293,9 @@ pub trait EntityBase: Send + Sync + NBTStorage {
 
     /// Kills the Entity.
     async fn kill(&self, caller: Arc<dyn EntityBase>) {
-        if let Some(living) = self.get_living_entity() {
-            living
-                .damage(caller, f32::MAX, DamageType::GENERIC_KILL)
+        if self.get_living_entity().is_some() {
+            // Through our own damage path, so players get the death screen and mobs drop their loot
+            self.damage(caller, f32::MAX, DamageType::GENERIC_KILL)
                 .await;
         } else {
             // TODO this should be removed once all entities are implemented
@@ -205,6 +307,24 @@ pub trait EntityBase: Send + Sync + NBTStorage {
     fn as_nbt_storage(&self) -> &dyn NBTStorage;
 }
 
+// ActionResult.java
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InteractionResult {
+    /// Interaction was successful and the player swings their hand | Same as SUCCESS in vanilla
+    Success,
+    /// Interaction was handled without swinging the hand | Same as CONSUME in vanilla
+    Consume,
+    /// Nothing happened, the held item is used instead | Same as PASS in vanilla
+    Pass,
+}
+
+impl InteractionResult {
+    #[must_use]
+    pub fn consumes_action(&self) -> bool {
+        matches!(self, Self::Success | Self::Consume)
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum RemovalReason {
     Killed,
@@ -234,8 +354,36 @@ impl RemovalReason {
     }
 }
 
+/// The result of [`Entity::collide_movement`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MovementCollision {
+    /// How far the entity can move
+    pub movement: Vector3<f64>,
+    pub on_ground: bool,
+    /// The block the entity lands on, `None` when standing on an entity or in the air
+    pub supporting_block_pos: Option<BlockPos>,
+    pub horizontal_collision: bool,
+}
+
 static CURRENT_ID: AtomicI32 = AtomicI32::new(0);
 
+/// The smallest width and height of an entity's bounding box
+pub const MIN_DIMENSION: f32 = 0.01;
+
+/// How long entities burn after touching lava
+pub const LAVA_FIRE_SECONDS: f32 = 15.0;
+/// Damage dealt by lava every time the entity can be hurt again
+pub const LAVA_DAMAGE: f32 = 4.0;
+
+/// The air supply of entities that aren't underwater
+pub const MAX_AIR_SUPPLY: i32 = 300;
+
+/// Entities frozen for this many ticks take freeze damage
+pub const MIN_FREEZE_DAMAGE_TICKS: i32 = 140;
+
+/// Entities further away than this many blocks can't be seen
+pub const MAX_SIGHT_DISTANCE: f64 = 128.0;
+
 /// Represents a non-living Entity (e.g. Item, Egg, Snowball...)
 pub struct Entity {
     /// A unique identifier for the entity
@@ -260,8 +408,12 @@ pub struct Entity {
     pub sneaking: AtomicBool,
     /// Indicates whether the entity is sprinting
     pub sprinting: AtomicBool,
+    /// Indicates whether the entity is swimming, see [`Entity::update_swimming`]
+    pub swimming: AtomicBool,
     /// Indicates whether the entity is flying due to a fall
     pub fall_flying: AtomicBool,
+    /// Indicates whether the entity is invisible, see [`Entity::set_invisible`]
+    pub invisible: AtomicBool,
     /// The entity's current velocity vector, aka knockback
     pub velocity: AtomicCell<Vector3<f64>>,
     /// Tracks a horizontal collision
@@ -292,12 +444,20 @@ pub struct Entity {
     pub bounding_box: AtomicCell<BoundingBox>,
     ///The size (width and height) of the bounding box
     pub bounding_box_size: AtomicCell<EntityDimensions>,
+    /// Multiplies the size of the bounding box, set by the scale attribute of living entities
+    pub scale: AtomicCell<f32>,
+    /// How far the bounding box is moved away from the entity's position, see [`Entity::default_bounding_box_offset`]
+    pub bounding_box_offset: AtomicCell<Vector3<f64>>,
     /// Whether this entity is invulnerable to all damage
     pub invulnerable: AtomicBool,
     /// List of damage types this entity is immune to
     pub damage_immunities: Vec<DamageType>,
     pub fire_ticks: AtomicI32,
+    /// The entity that set this entity on fire, credited for the fire damage until the fire goes out
+    pub fire_attacker: AtomicCell<Option<i32>>,
     pub has_visual_fire: AtomicBool,
+    /// Whether this entity is outlined, see [`Entity::set_glowing`]
+    pub glowing: AtomicBool,
     pub removal_reason: AtomicCell<Option<RemovalReason>>,
     // The passengers that entity has
     pub passengers: Mutex<Vec<Arc<dyn EntityBase>>>,
@@ -305,21 +465,37 @@ pub struct Entity {
     pub vehicle: Mutex<Option<Arc<dyn EntityBase>>>,
     pub age: AtomicI32,
 
-    pub first_loaded_chunk_position: AtomicCell<Option<Vector3<i32>>>,
+    /// The chunk whose entity data holds this entity, see [`World::update_entity_chunk`]
+    pub saved_chunk_position: AtomicCell<Option<Vector2<i32>>>,
 
     pub portal_cooldown: AtomicU32,
 
     pub portal_manager: Mutex<Option<Mutex<PortalManager>>>,
-    /// Custom name for the entity
-    pub custom_name: Option<TextComponent>,
-    /// Indicates whether the entity's custom name is visible
-    pub custom_name_visible: bool,
+    /// Custom name for the entity, saved as `CustomName`
+    pub custom_name: std::sync::Mutex<Option<TextComponent>>,
+    /// Whether the custom name is always shown instead of only when looking at the entity, saved as `CustomNameVisible`.
+    /// Hiding names further away is up to the client.
+    pub custom_name_visible: AtomicBool,
+    /// The metadata entries of the entity, see [`Entity::send_meta_data`]
+    pub data_tracker: DataTracker,
+    /// The yaw, pitch and on ground state clients last got, see [`Entity::send_rotation`]
+    pub sent_rotation: LastSent<(u8, u8, bool)>,
+    /// The head yaw clients last got
+    pub sent_head_yaw: LastSent<u8>,
     /// The data send in the Entity Spawn packet
     pub data: AtomicI32,
     /// If true, the entity cannot collide with anything (e.g. spectator)
     pub no_clip: AtomicBool,
     /// Multiplies movement for one tick before being reset
     pub movement_multiplier: AtomicCell<Vector3<f64>>,
+    /// Whether the entity touched powder snow this tick
+    pub in_powder_snow: AtomicBool,
+    /// How long the entity has been freezing in powder snow, see [`MIN_FREEZE_DAMAGE_TICKS`]
+    pub frozen_ticks: AtomicI32,
+    /// How many ticks the entity can stay underwater before drowning, see [`MAX_AIR_SUPPLY`]
+    pub air_supply: AtomicI32,
+    /// The maximum height this entity can walk up without jumping (e.g. slabs and stairs)
+    pub step_height: AtomicCell<f32>,
     /// Determines whether the entity's velocity needs to be sent
     pub velocity_dirty: AtomicBool,
     /// Set when an Entity is to be removed but could still be referenced
@@ -338,10 +514,8 @@ impl Entity {
         let floor_y = position.y.floor() as i32;
         let floor_z = position.z.floor() as i32;
 
-        let bounding_box_size = EntityDimensions {
-            width: entity_type.dimension[0],
-            height: entity_type.dimension[1],
-        };
+        let bounding_box_size = Self::default_dimensions(entity_type);
+        let bounding_box_offset = Self::default_bounding_box_offset(entity_type);
 
         Self {
             entity_id: CURRENT_ID.fetch_add(1, Relaxed),
@@ -364,7 +538,9 @@ impl Entity {
             sneaking: AtomicBool::new(false),
             world,
             sprinting: AtomicBool::new(false),
+            swimming: AtomicBool::new(false),
             fall_flying: AtomicBool::new(false),
+            invisible: AtomicBool::new(false),
             yaw: AtomicCell::new(0.0),
             head_yaw: AtomicCell::new(0.0),
             body_yaw: AtomicCell::new(0.0),
@@ -372,34 +548,189 @@ impl Entity {
             velocity: AtomicCell::new(Vector3::new(0.0, 0.0, 0.0)),
             standing_eye_height: entity_type.eye_height,
             pose: AtomicCell::new(EntityPose::Standing),
-            first_loaded_chunk_position: AtomicCell::new(None),
-            bounding_box: AtomicCell::new(BoundingBox::new_from_pos(
-                position.x,
-                position.y,
-                position.z,
+            saved_chunk_position: AtomicCell::new(None),
+            bounding_box: AtomicCell::new(Self::bounding_box_at(
+                position,
                 &bounding_box_size,
+                bounding_box_offset,
             )),
             bounding_box_size: AtomicCell::new(bounding_box_size),
+            scale: AtomicCell::new(1.0),
+            bounding_box_offset: AtomicCell::new(bounding_box_offset),
             invulnerable: AtomicBool::new(invulnerable),
             damage_immunities: Vec::new(),
             data: AtomicI32::new(0),
             fire_ticks: AtomicI32::new(-1),
+            fire_attacker: AtomicCell::new(None),
             has_visual_fire: AtomicBool::new(false),
+            glowing: AtomicBool::new(false),
             removal_reason: AtomicCell::new(None),
             passengers: Mutex::new(Vec::new()),
             vehicle: Mutex::new(None),
             age: AtomicI32::new(0),
             portal_cooldown: AtomicU32::new(0),
             portal_manager: Mutex::new(None),
-            custom_name: None,
-            custom_name_visible: false,
+            custom_name: std::sync::Mutex::new(None),
+            custom_name_visible: AtomicBool::new(false),
+            data_tracker: DataTracker::default(),
+            sent_rotation: LastSent::default(),
+            sent_head_yaw: LastSent::default(),
             no_clip: AtomicBool::new(false),
             movement_multiplier: AtomicCell::new(Vector3::default()),
+            in_powder_snow: AtomicBool::new(false),
+            frozen_ticks: AtomicI32::new(0),
+            air_supply: AtomicI32::new(MAX_AIR_SUPPLY),
+            step_height: AtomicCell::new(Self::default_step_height(entity_type)),
             velocity_dirty: AtomicBool::new(true),
             removed: AtomicBool::new(false),
         }
     }
 
+    /// Reads a list of 3 doubles, replacing missing or non-finite components (e.g. from a corrupt
+    /// save) with `0.0`.
+    fn read_nbt_vector(nbt: &NbtCompound, name: &str) -> Vector3<f64> {
+        let list = nbt.get_list(name).unwrap_or_default();
+        let component = |index: usize| {
+            list.get(index)
+                .and_then(NbtTag::extract_double)
+                .filter(|value| value.is_finite())
+                .unwrap_or(0.0)
+        };
+        Vector3::new(component(0), component(1), component(2))
+    }
+
+    /// Keeps a loaded position inside the world border limits, so converting it to block and chunk
+    /// coordinates can't overflow.
+    fn clamp_loaded_pos(pos: Vector3<f64>) -> Vector3<f64> {
+        const MAX_HORIZONTAL: f64 = 30_000_000.0;
+        const MAX_VERTICAL: f64 = 20_000_000.0;
+        Vector3::new(
+            pos.x.clamp(-MAX_HORIZONTAL, MAX_HORIZONTAL),
+            pos.y.clamp(-MAX_VERTICAL, MAX_VERTICAL),
+            pos.z.clamp(-MAX_HORIZONTAL, MAX_HORIZONTAL),
+        )
+    }
+
+    /// Limits a velocity to [`MAX_VELOCITY`], keeping its direction.
+    #[must_use]
+    pub fn clamp_velocity(velocity: Vector3<f64>) -> Vector3<f64> {
+        let length = velocity.length();
+        if length > MAX_VELOCITY {
+            velocity * (MAX_VELOCITY / length)
+        } else {
+            velocity
+        }
+    }
+
+    /// The bounding box size an entity of the given type starts with.
+    ///
+    /// Types without a size (e.g. markers and displays) or with invalid dimensions get
+    /// [`MIN_DIMENSION`] so their bounding box is never degenerate.
+    #[must_use]
+    pub fn default_dimensions(entity_type: &EntityType) -> EntityDimensions {
+        let validate = |value: f32| {
+            if value.is_finite() {
+                value.max(MIN_DIMENSION)
+            } else {
+                MIN_DIMENSION
+            }
+        };
+        EntityDimensions {
+            width: validate(entity_type.dimension[0]),
+            height: validate(entity_type.dimension[1]),
+        }
+    }
+
+    /// The size of a player in the given pose, [`None`] for other entities which keep their size.
+    #[must_use]
+    pub fn pose_dimensions(entity_type: &EntityType, pose: EntityPose) -> Option<EntityDimensions> {
+        if entity_type != &EntityType::PLAYER {
+            return None;
+        }
+        let (width, height) = match pose {
+            EntityPose::Sleeping | EntityPose::Dying => (0.2, 0.2),
+            EntityPose::FallFlying | EntityPose::Swimming | EntityPose::SpinAttack => (0.6, 0.6),
+            EntityPose::Crouching => (0.6, 1.5),
+            _ => return Some(Self::default_dimensions(entity_type)),
+        };
+        Some(EntityDimensions { width, height })
+    }
+
+    /// The offset of the bounding box from the position an entity of the given type starts with.
+    ///
+    /// Bounding boxes are centered on x/z and start at the entity's feet. Hanging entities
+    /// (paintings, item frames) are positioned at their center instead, so their box is moved
+    /// down by half its height.
+    #[must_use]
+    pub fn default_bounding_box_offset(entity_type: &EntityType) -> Vector3<f64> {
+        if entity_type == &EntityType::PAINTING
+            || entity_type == &EntityType::ITEM_FRAME
+            || entity_type == &EntityType::GLOW_ITEM_FRAME
+        {
+            let height = Self::default_dimensions(entity_type).height;
+            Vector3::new(0.0, -f64::from(height) / 2.0, 0.0)
+        } else {
+            Vector3::default()
+        }
+    }
+
+    /// Builds the bounding box of an entity at `position`, moved by `offset`.
+    #[must_use]
+    pub fn bounding_box_at(
+        position: Vector3<f64>,
+        size: &EntityDimensions,
+        offset: Vector3<f64>,
+    ) -> BoundingBox {
+        let center = position.add(&offset);
+        BoundingBox::new_from_pos(center.x, center.y, center.z, size)
+    }
+
+    /// Changes the size and offset of the bounding box, e.g. when a hanging entity is rotated.
+    pub fn set_bounding_box(&self, size: EntityDimensions, offset: Vector3<f64>) {
+        self.bounding_box_size.store(size);
+        self.bounding_box_offset.store(offset);
+        self.bounding_box
+            .store(Self::bounding_box_at(self.pos.load(), &size, offset));
+    }
+
+    /// Resizes the bounding box and step height by `scale`. Returns whether the size changed,
+    /// clients only need to know about it then.
+    pub fn set_scale(&self, scale: f32) -> bool {
+        self.scale.store(scale);
+        self.step_height
+            .store(Self::default_step_height(self.entity_type) * scale);
+        let size = Self::pose_dimensions(self.entity_type, self.pose.load())
+            .unwrap_or_else(|| Self::default_dimensions(self.entity_type))
+            .scaled(scale);
+        if size == self.bounding_box_size.load() {
+            return false;
+        }
+        self.set_bounding_box(size, self.bounding_box_offset.load());
+        true
+    }
+
+    /// The step height an entity of the given type starts with.
+    ///
+    /// Living entities can step up 0.6 blocks, horses a full block and everything else
+    /// (items, projectiles, boats...) can't step at all.
+    #[must_use]
+    pub fn default_step_height(entity_type: &EntityType) -> f32 {
+        if entity_type == &EntityType::CAMEL {
+            1.5
+        } else if entity_type == &EntityType::HORSE
+            || entity_type == &EntityType::DONKEY
+            || entity_type == &EntityType::MULE
+            || entity_type == &EntityType::SKELETON_HORSE
+            || entity_type == &EntityType::ZOMBIE_HORSE
+        {
+            1.0
+        } else if entity_type == &EntityType::PLAYER || entity_type.max_health.is_some() {
+            0.6
+        } else {
+            0.0
+        }
+    }
+
     pub async fn set_velocity(&self, velocity: Vector3<f64>) {
         self.velocity.store(velocity);
         self.send_velocity().await;
@@ -407,21 +738,72 @@ impl Entity {
 
     /// Sets a custom name for the entity, typically used with nametags
     pub async fn set_custom_name(&self, name: TextComponent) {
-        self.send_meta_data(&[Metadata::new(
-            2,
-            MetaDataType::OptionalTextComponent,
-            Some(name),
-        )])
-        .await;
+        *self.custom_name.lock().unwrap() = Some(name.clone());
+        Self::track_custom_name(&self.data_tracker, name);
+        self.send_dirty_meta_data().await;
+    }
+
+    /// Sets whether the custom name is always shown, not just when looking at the entity
+    pub async fn set_custom_name_visible(&self, visible: bool) {
+        self.custom_name_visible.store(visible, Relaxed);
+        self.data_tracker.track(3, MetaDataType::Boolean, visible);
+        self.send_dirty_meta_data().await;
+    }
+
+    /// Tracks a new custom name for the next metadata update
+    fn track_custom_name(tracker: &DataTracker, name: TextComponent) {
+        tracker.track(2, MetaDataType::OptionalTextComponent, Some(name));
+        // Resend the visibility too, so the client shows the name right away if it is always visible
+        tracker.mark_dirty(3);
+    }
+
+    /// Registers the metadata entries every entity has
+    pub fn init_tracked_data(&self) {
+        let tracker = &self.data_tracker;
+        tracker.track(0, MetaDataType::Byte, self.flags());
+        tracker.track(
+            1,
+            MetaDataType::Integer,
+            VarInt(self.air_supply.load(Relaxed)),
+        );
+        if let Some(name) = self.custom_name.lock().unwrap().clone() {
+            Self::track_custom_name(tracker, name);
+        }
+        tracker.track(
+            3,
+            MetaDataType::Boolean,
+            self.custom_name_visible.load(Relaxed),
+        );
+        tracker.track(6, MetaDataType::EntityPose, VarInt(self.pose.load() as i32));
+        tracker.track(
+            7,
+            MetaDataType::Integer,
+            VarInt(self.frozen_ticks.load(Relaxed)),
+        );
     }
 
+    /// Sends the velocity right away, e.g. after knockback
     pub async fn send_velocity(&self) {
+        self.velocity_dirty.store(false, Ordering::SeqCst);
         let velocity = self.velocity.load();
         self.world
             .broadcast_packet_all(&CEntityVelocity::new(self.entity_id.into(), velocity))
             .await;
     }
 
+    /// Sends the velocity only if something like knockback or a jump marked it dirty. Clients
+    /// predict gravity and drag themselves, so per tick changes don't need a packet.
+    pub async fn send_velocity_if_dirty(&self) {
+        if Self::take_dirty(&self.velocity_dirty) {
+            self.send_velocity().await;
+        }
+    }
+
+    /// Returns whether the flag was set and clears it
+    fn take_dirty(flag: &AtomicBool) -> bool {
+        flag.swap(false, Ordering::SeqCst)
+    }
+
     /// Updates the entity's position, block position, and chunk position.
     ///
     /// This function calculates the new position, block position, and chunk position based on the provided coordinates. If any of these values change, the corresponding fields are updated.
@@ -429,11 +811,10 @@ impl Entity {
         let pos = self.pos.load();
         if pos != new_position {
             self.pos.store(new_position);
-            self.bounding_box.store(BoundingBox::new_from_pos(
-                new_position.x,
-                new_position.y,
-                new_position.z,
+            self.bounding_box.store(Self::bounding_box_at(
+                new_position,
                 &self.bounding_box_size.load(),
+                self.bounding_box_offset.load(),
             ));
 
             let floor_x = new_position.x.floor() as i32;
@@ -476,50 +857,154 @@ impl Entity {
         .normalize()
     }
 
-    /// Changes this entity's pitch and yaw to look at target
-    pub async fn look_at(&self, target: Vector3<f64>) {
-        let position = self.pos.load();
+    /// The yaw and pitch needed to look from `position` at `target`
+    #[must_use]
+    pub fn rotation_towards(position: Vector3<f64>, target: Vector3<f64>) -> (f32, f32) {
         let delta = target.sub(&position);
         let root = delta.x.hypot(delta.z);
         let pitch = wrap_degrees(-delta.y.atan2(root) as f32 * 180.0 / std::f32::consts::PI);
         let yaw =
             wrap_degrees((delta.z.atan2(delta.x) as f32 * 180.0 / std::f32::consts::PI) - 90.0);
-        self.pitch.store(pitch);
+        (yaw, pitch)
+    }
+
+    /// Turns the `(yaw, pitch)` rotation towards `target` by at most the given amount of degrees
+    #[must_use]
+    pub fn step_rotation(
+        (yaw, pitch): (f32, f32),
+        (target_yaw, target_pitch): (f32, f32),
+        max_yaw_change: f32,
+        max_pitch_change: f32,
+    ) -> (f32, f32) {
+        let yaw_change = subtract_angles(yaw, target_yaw).clamp(-max_yaw_change, max_yaw_change);
+        let pitch_change =
+            subtract_angles(pitch, target_pitch).clamp(-max_pitch_change, max_pitch_change);
+        (wrap_degrees(yaw + yaw_change), pitch + pitch_change)
+    }
+
+    /// Changes this entity's pitch and yaw to look at target instantly, e.g. for commands.
+    /// Mobs should use [`Entity::look_at_gradually`] through their `LookControl` instead.
+    ///
+    /// Returns whether the rotation was broadcast, which it only is if it changed enough for
+    /// clients to notice.
+    pub async fn look_at(&self, target: Vector3<f64>) -> bool {
+        let (yaw, pitch) = Self::rotation_towards(self.get_eye_pos(), target);
         self.yaw.store(yaw);
+        self.body_yaw.store(yaw);
+        self.head_yaw.store(yaw);
+        self.set_pitch(pitch);
 
-        self.send_rotation().await;
+        self.send_rotation().await
     }
 
-    pub async fn send_rotation(&self) {
-        let yaw = self.yaw.load();
-        let pitch = self.pitch.load();
+    /// Turns this entity's head towards target by at most `max_yaw_change` and `max_pitch_change`
+    /// degrees. Called every tick this lets entities turn smoothly, the body catches up on its
+    /// own. Nothing is broadcast, the caller sends the rotation once it is final.
+    ///
+    /// Returns whether the entity now looks at the target.
+    pub fn look_at_gradually(
+        &self,
+        target: Vector3<f64>,
+        max_yaw_change: f32,
+        max_pitch_change: f32,
+    ) -> bool {
+        let wanted = Self::rotation_towards(self.get_eye_pos(), target);
+        let current = (self.head_yaw.load(), self.pitch.load());
+        let (head_yaw, pitch) =
+            Self::step_rotation(current, wanted, max_yaw_change, max_pitch_change);
 
-        // Broadcast the update packet.
+        self.head_yaw.store(head_yaw);
+        self.set_pitch(pitch);
 
-        // TODO: Do caching to only send the packet when needed.
+        subtract_angles(head_yaw, wanted.0).abs() < f32::EPSILON
+            && subtract_angles(pitch, wanted.1).abs() < f32::EPSILON
+    }
 
-        let yaw = (yaw * 256.0 / 360.0).rem_euclid(256.0);
+    /// Turns this entity towards the eyes of `target`
+    pub async fn look_at_entity(&self, target: &Self) {
+        self.look_at(target.get_eye_pos()).await;
+    }
 
-        let yaw = (yaw * 256.0 / 360.0).rem_euclid(256.0) as u8;
+    /// Like [`Self::look_at_gradually`], but towards the eyes of `target`
+    pub fn look_at_entity_gradually(
+        &self,
+        target: &Self,
+        max_yaw_change: f32,
+        max_pitch_change: f32,
+    ) -> bool {
+        self.look_at_gradually(target.get_eye_pos(), max_yaw_change, max_pitch_change)
+    }
 
-        let pitch = (pitch * 256.0 / 360.0).rem_euclid(256.0);
+    /// Whether no solid block is between the eyes of this entity and those of `target`, like
+    /// vanilla's `LivingEntity.canSee`. Nothing further away than 128 blocks is seen.
+    pub async fn can_see(&self, target: &Self) -> bool {
+        if !Arc::ptr_eq(&self.world, &target.world) {
+            return false;
+        }
+        let start = self.get_eye_pos();
+        let end = target.get_eye_pos();
+        if start.squared_distance_to_vec(end) > MAX_SIGHT_DISTANCE * MAX_SIGHT_DISTANCE {
+            return false;
+        }
+        self.world.raycast_blocks(start, end).await.is_none()
+    }
 
-        self.world
-            .broadcast_packet_all(&CUpdateEntityRot::new(
-                self.entity_id.into(),
-                yaw,
-                pitch as u8,
-                self.on_ground.load(Relaxed),
-            ))
+    /// Broadcasts the yaw and pitch, unless clients already know them. Returns whether they were
+    /// broadcast. Mobs turn their body on their own and use [`Self::send_body_rotation`] instead.
+    pub async fn send_rotation(&self) -> bool {
+        let yaw = Self::angle_to_byte(self.yaw.load());
+        let pitch = Self::angle_to_byte(self.pitch.load());
+        let on_ground = self.on_ground.load(Relaxed);
+
+        let changed = self.sent_rotation.update((yaw, pitch, on_ground));
+        if changed {
+            self.world
+                .broadcast_packet_all(&CUpdateEntityRot::new(
+                    self.entity_id.into(),
+                    yaw,
+                    pitch,
+                    on_ground,
+                ))
+                .await;
+        }
+
+        self.send_head_rot(Self::angle_to_byte(self.head_yaw.load()))
+            .await;
+        changed
+    }
+
+    /// Broadcasts the body and head yaw of a mob. The body goes out as the entity's yaw, the head
+    /// only through [`CHeadRot`].
+    pub async fn send_body_rotation(&self) {
+        let body_yaw = Self::angle_to_byte(self.body_yaw.load());
+        let pitch = Self::angle_to_byte(self.pitch.load());
+        let on_ground = self.on_ground.load(Relaxed);
+        if self.sent_rotation.update((body_yaw, pitch, on_ground)) {
+            self.world
+                .broadcast_packet_all(&CUpdateEntityRot::new(
+                    self.entity_id.into(),
+                    body_yaw,
+                    pitch,
+                    on_ground,
+                ))
+                .await;
+        }
+        self.send_head_rot(Self::angle_to_byte(self.head_yaw.load()))
             .await;
+    }
 
-        self.send_head_rot(yaw).await;
+    /// Packs an angle in degrees into the 1/256th of a turn steps used by packets.
+    #[must_use]
+    pub fn angle_to_byte(angle: f32) -> u8 {
+        (angle * 256.0 / 360.0).rem_euclid(256.0) as u8
     }
 
     pub async fn send_head_rot(&self, head_yaw: u8) {
-        self.world
-            .broadcast_packet_all(&CHeadRot::new(self.entity_id.into(), head_yaw))
-            .await;
+        if self.sent_head_yaw.update(head_yaw) {
+            self.world
+                .broadcast_packet_all(&CHeadRot::new(self.entity_id.into(), head_yaw))
+                .await;
+        }
     }
 
     fn default_portal_cooldown(&self) -> u32 {
@@ -530,16 +1015,33 @@ impl Entity {
         }
     }
 
-    #[allow(clippy::float_cmp)]
+    /// Moves the entity's collision state along with `movement` and returns how far it can
+    /// actually move.
     async fn adjust_movement_for_collisions(&self, movement: Vector3<f64>) -> Vector3<f64> {
-        self.on_ground.store(false, Ordering::SeqCst);
-
-        self.supporting_block_pos.store(None);
+        let collision = self.collide_movement(movement).await;
+        self.on_ground.store(collision.on_ground, Ordering::SeqCst);
+        self.supporting_block_pos
+            .store(collision.supporting_block_pos);
+        self.horizontal_collision
+            .store(collision.horizontal_collision, Ordering::SeqCst);
+        collision.movement
+    }
 
-        self.horizontal_collision.store(false, Ordering::SeqCst);
+    /// Checks how far the entity can move by `movement` before running into blocks or
+    /// collidable entities, without changing the entity.
+    #[allow(clippy::float_cmp, clippy::too_many_lines)]
+    pub async fn collide_movement(&self, movement: Vector3<f64>) -> MovementCollision {
+        let was_on_ground = self.on_ground.load(Ordering::SeqCst);
+
+        let mut collision = MovementCollision {
+            movement,
+            on_ground: false,
+            supporting_block_pos: None,
+            horizontal_collision: false,
+        };
 
         if movement.length_squared() == 0.0 {
-            return movement;
+            return collision;
         }
 
         let bounding_box = self.bounding_box.load();
@@ -549,8 +1051,12 @@ impl Entity {
             .get_block_collisions(bounding_box.stretch(movement))
             .await;
 
-        if collisions.is_empty() {
-            return movement;
+        let entity_collisions = self
+            .get_entity_collisions(&bounding_box.stretch(movement))
+            .await;
+
+        if collisions.is_empty() && entity_collisions.is_empty() {
+            return collision;
         }
 
         let mut adjusted_movement = movement;
@@ -562,10 +1068,14 @@ impl Entity {
 
             let mut positions = block_positions.into_iter();
 
-            let (mut collisions_len, mut position) = positions.next().unwrap();
+            // Only entities may be in the way, then the block loop below doesn't run
+            let (mut collisions_len, mut position) =
+                positions.next().unwrap_or((0, BlockPos::ZERO));
 
             let mut supporting_block_pos = None;
 
+            let mut on_entity = false;
+
             for (i, inert_box) in collisions.iter().enumerate() {
                 if i == collisions_len {
                     (collisions_len, position) = positions.next().unwrap();
@@ -583,16 +1093,32 @@ impl Entity {
                 }
             }
 
+            // `max_time` already stops at the closest block, so only an entity in front of it
+            // takes over
+            for inert_box in &entity_collisions {
+                if let Some(collision_time) = bounding_box.calculate_collision_time(
+                    inert_box,
+                    adjusted_movement,
+                    Axis::Y,
+                    max_time,
+                ) {
+                    max_time = collision_time;
+
+                    supporting_block_pos = None;
+
+                    on_entity = true;
+                }
+            }
+
             if max_time != 1.0 {
                 let changed_component = adjusted_movement.get_axis(Axis::Y) * max_time;
 
                 adjusted_movement.set_axis(Axis::Y, changed_component);
             }
 
-            self.on_ground
-                .store(supporting_block_pos.is_some(), Ordering::SeqCst);
+            collision.on_ground = supporting_block_pos.is_some() || on_entity;
 
-            self.supporting_block_pos.store(supporting_block_pos);
+            collision.supporting_block_pos = supporting_block_pos;
         }
 
         let mut horizontal_collision = false;
@@ -604,7 +1130,7 @@ impl Entity {
 
             let mut max_time = 1.0;
 
-            for inert_box in &collisions {
+            for inert_box in collisions.iter().chain(&entity_collisions) {
                 if let Some(collision_time) = bounding_box.calculate_collision_time(
                     inert_box,
                     adjusted_movement,
@@ -624,10 +1150,146 @@ impl Entity {
             }
         }
 
-        self.horizontal_collision
-            .store(horizontal_collision, Ordering::SeqCst);
+        let step_height = f64::from(self.step_height.load());
+        let landed = movement.y < 0.0 && adjusted_movement.y != movement.y;
+
+        if horizontal_collision && step_height > 0.0 && (was_on_ground || landed) {
+            // Step from where the entity landed, like vanilla
+            let drop = if landed { adjusted_movement.y } else { 0.0 };
+            let step_box = bounding_box.shift(Vector3::new(0.0, drop, 0.0));
+            let mut step_area = step_box.stretch(Vector3::new(movement.x, step_height, movement.z));
+            if !landed {
+                // Include the block the entity is standing on
+                step_area = step_area.stretch(Vector3::new(0.0, -1.0e-5, 0.0));
+            }
+            let (mut step_collisions, _) = self.world.get_block_collisions(step_area).await;
+            step_collisions.extend(self.get_entity_collisions(&step_area).await);
+
+            if let Some(stepped) = Self::step_up_movement(
+                &step_box,
+                Vector3::new(movement.x, movement.y - drop, movement.z),
+                adjusted_movement,
+                step_height,
+                &step_collisions,
+            ) {
+                adjusted_movement = stepped + Vector3::new(0.0, drop, 0.0);
+                horizontal_collision = false;
+                collision.on_ground = true;
+            }
+        }
+
+        collision.movement = adjusted_movement;
+        collision.horizontal_collision = horizontal_collision;
+        collision
+    }
 
-        adjusted_movement
+    /// Bounding boxes of entities this entity can't move through, like boats and shulkers.
+    /// The vehicle and passengers of this entity never block it.
+    async fn get_entity_collisions(&self, area: &BoundingBox) -> Vec<BoundingBox> {
+        let vehicle = self
+            .vehicle
+            .lock()
+            .await
+            .as_ref()
+            .map(|vehicle| vehicle.get_entity().entity_id);
+        let passengers: Vec<i32> = self
+            .passengers
+            .lock()
+            .await
+            .iter()
+            .map(|passenger| passenger.get_entity().entity_id)
+            .collect();
+
+        self.world
+            .get_chunk_entities_at_box(area)
+            .await
+            .into_iter()
+            .filter(|other| {
+                let id = other.get_entity().entity_id;
+                vehicle != Some(id) && !passengers.contains(&id) && other.is_collidable(Some(self))
+            })
+            .map(|other| other.get_entity().bounding_box.load())
+            .collect()
+    }
+
+    /// Clips `movement` against `collisions` one axis at a time (Y first, then X and Z),
+    /// moving the box after every axis like vanilla's `Entity.adjustMovementForCollisions`.
+    fn collide_with_shapes(
+        bounding_box: &BoundingBox,
+        movement: Vector3<f64>,
+        collisions: &[BoundingBox],
+    ) -> Vector3<f64> {
+        let mut bounding_box = *bounding_box;
+        let mut adjusted = Vector3::default();
+
+        for axis in [Axis::Y, Axis::X, Axis::Z] {
+            let component = movement.get_axis(axis);
+            if component == 0.0 {
+                continue;
+            }
+
+            let mut axis_movement = Vector3::default();
+            axis_movement.set_axis(axis, component);
+
+            let mut max_time = 1.0;
+            for inert_box in collisions {
+                if let Some(collision_time) =
+                    bounding_box.calculate_collision_time(inert_box, axis_movement, axis, max_time)
+                {
+                    max_time = collision_time;
+                }
+            }
+
+            axis_movement.set_axis(axis, component * max_time);
+            adjusted.set_axis(axis, component * max_time);
+            bounding_box = bounding_box.shift(axis_movement);
+        }
+
+        adjusted
+    }
+
+    /// Tries to walk up a step no higher than `step_height` after `collided` was stopped by a
+    /// horizontal collision. Returns the stepped movement if it gets further than `collided`.
+    fn step_up_movement(
+        bounding_box: &BoundingBox,
+        movement: Vector3<f64>,
+        collided: Vector3<f64>,
+        step_height: f64,
+        collisions: &[BoundingBox],
+    ) -> Option<Vector3<f64>> {
+        let horizontal = Vector3::new(movement.x, 0.0, movement.z);
+
+        let mut stepped = Self::collide_with_shapes(
+            bounding_box,
+            Vector3::new(movement.x, step_height, movement.z),
+            collisions,
+        );
+
+        let rise = Self::collide_with_shapes(
+            &bounding_box.stretch(horizontal),
+            Vector3::new(0.0, step_height, 0.0),
+            collisions,
+        );
+
+        if rise.y < step_height {
+            let across =
+                Self::collide_with_shapes(&bounding_box.shift(rise), horizontal, collisions) + rise;
+            if across.horizontal_length_squared() > stepped.horizontal_length_squared() {
+                stepped = across;
+            }
+        }
+
+        if stepped.horizontal_length_squared() <= collided.horizontal_length_squared() {
+            return None;
+        }
+
+        let settle = Self::collide_with_shapes(
+            &bounding_box.shift(stepped),
+            Vector3::new(0.0, movement.y - stepped.y, 0.0),
+            collisions,
+        );
+
+        Some(stepped + settle)
     }
 
     /// Applies knockback to the entity, following vanilla Minecraft's mechanics.
@@ -635,6 +1297,7 @@ impl Entity {
     /// This function calculates the entity's new velocity based on the specified knockback strength and direction.
     pub fn apply_knockback(&self, strength: f64, mut x: f64, mut z: f64) {
         // TODO: strength *= 1 - Entity attribute knockback resistance
+        let strength = Self::scaled_knockback(strength, self.scale.load());
 
         if strength <= 0.0 {
             return;
@@ -789,7 +1452,7 @@ impl Entity {
                         &bounding_box,
                         pos,
                         state,
-                        !suffocating && state.is_solid(),
+                        !suffocating && state.suffocates(),
                         |collision_shape: &BoundingBox| {
                             suffocating = collision_shape.intersects(&eye_level_box);
                         },
@@ -815,39 +1478,30 @@ impl Entity {
         suffocating
     }
 
+    /// Broadcasts the movement since the last sent position together with the rotation. Only the
+    /// rotation is sent if the entity didn't move.
     pub async fn send_pos_rot(&self) {
-        let old = self.update_last_pos();
-
-        let new = self.pos.load();
-
-        let converted = Vector3::new(
-            new.x.mul_add(4096.0, -(old.x * 4096.0)) as i16,
-            new.y.mul_add(4096.0, -(old.y * 4096.0)) as i16,
-            new.z.mul_add(4096.0, -(old.z * 4096.0)) as i16,
-        );
-
-        let yaw = self.yaw.load();
-
-        let pitch = self.pitch.load();
-
-        // Broadcast the update packet.
-
-        // TODO: Do caching to only send the packet when needed.
-
-        let yaw = (yaw * 256.0 / 360.0).rem_euclid(256.0) as u8;
+        let Some(delta) = self.take_pos_delta() else {
+            self.send_rotation().await;
+            return;
+        };
 
-        let pitch = (pitch * 256.0 / 360.0).rem_euclid(256.0);
+        let yaw = Self::angle_to_byte(self.yaw.load());
+        let pitch = Self::angle_to_byte(self.pitch.load());
+        let on_ground = self.on_ground.load(Relaxed);
+        self.sent_rotation.update((yaw, pitch, on_ground));
 
         self.world
             .broadcast_packet_all(&CUpdateEntityPosRot::new(
                 self.entity_id.into(),
-                Vector3::new(converted.x, converted.y, converted.z),
+                delta,
                 yaw,
-                pitch as u8,
-                self.on_ground.load(Relaxed),
+                pitch,
+                on_ground,
             ))
             .await;
-        self.send_head_rot(yaw).await;
+        self.send_head_rot(Self::angle_to_byte(self.head_yaw.load()))
+            .await;
     }
 
     pub fn update_last_pos(&self) -> Vector3<f64> {
@@ -858,20 +1512,37 @@ impl Entity {
         old
     }
 
-    pub async fn send_pos(&self) {
-        let old = self.update_last_pos();
-        let new = self.pos.load();
+    /// The movement since the last sent position in packet units, or [`None`] if the entity
+    /// moved less than one unit. The last position is only updated when the movement gets sent,
+    /// so small movements add up.
+    fn take_pos_delta(&self) -> Option<Vector3<i16>> {
+        let delta = Self::pos_delta(self.last_pos.load(), self.pos.load())?;
+        self.update_last_pos();
+        Some(delta)
+    }
 
-        let converted = Vector3::new(
-            new.x.mul_add(4096.0, -(old.x * 4096.0)) as i16,
-            new.y.mul_add(4096.0, -(old.y * 4096.0)) as i16,
-            new.z.mul_add(4096.0, -(old.z * 4096.0)) as i16,
+    fn pos_delta(old: Vector3<f64>, new: Vector3<f64>) -> Option<Vector3<i16>> {
+        let delta = Vector3::new(
+            new.x
+                .mul_add(POSITION_DELTA_SCALE, -(old.x * POSITION_DELTA_SCALE)) as i16,
+            new.y
+                .mul_add(POSITION_DELTA_SCALE, -(old.y * POSITION_DELTA_SCALE)) as i16,
+            new.z
+                .mul_add(POSITION_DELTA_SCALE, -(old.z * POSITION_DELTA_SCALE)) as i16,
         );
+        (delta != Vector3::default()).then_some(delta)
+    }
+
+    /// Broadcasts the movement since the last sent position, unless the entity didn't move
+    pub async fn send_pos(&self) {
+        let Some(delta) = self.take_pos_delta() else {
+            return;
+        };
 
         self.world
             .broadcast_packet_all(&CUpdateEntityPos::new(
                 self.entity_id.into(),
-                Vector3::new(converted.x, converted.y, converted.z),
+                delta,
                 self.on_ground.load(Relaxed),
             ))
             .await;
@@ -902,6 +1573,9 @@ impl Entity {
 
         let mut fluid_height: [f64; 2] = [0.0, 0.0];
 
+        // The highest bubble column the entity is in and whether it drags down
+        let mut bubble_column: Option<(BlockPos, bool)> = None;
+
         let bounding_box = self.bounding_box.load().expand(-0.001, -0.001, -0.001);
 
         let min = bounding_box.min_block_pos();
@@ -913,13 +1587,22 @@ impl Entity {
                 for z in min.0.z..=max.0.z {
                     let pos = BlockPos::new(x, y, z);
 
-                    let (fluid, state) = self.world.get_fluid_and_fluid_state(&pos).await;
+                    let state_id = self.world.get_block_state_id(&pos).await;
 
-                    if fluid.id != Fluid::EMPTY.id {
-                        let marginal_height =
-                            f64::from(state.height) + f64::from(y) - bounding_box.min.y;
+                    let (fluid, state) = World::fluid_and_fluid_state_from_id(state_id);
+
+                    let block = Block::from_state_id(state_id);
+
+                    if block == &Block::BUBBLE_COLUMN {
+                        let drag = BubbleColumnLikeProperties::from_state_id(state_id, block).drag;
+
+                        bubble_column = Some((pos, drag));
+                    }
 
-                        if marginal_height >= 0.0 {
+                    if fluid.id != Fluid::EMPTY.id {
+                        if let Some(marginal_height) =
+                            Self::submerged_height(&state, y, bounding_box.min.y)
+                        {
                             let i = usize::from(
                                 fluid.id == Fluid::FLOWING_LAVA.id || fluid.id == Fluid::LAVA.id,
                             );
@@ -962,21 +1645,41 @@ impl Entity {
         }
 
         let lava_speed = if self.world.dimension_type == VanillaDimensionType::TheNether {
-            0.007
+            NETHER_LAVA_PUSH_SPEED
         } else {
-            0.002_333_333
+            LAVA_PUSH_SPEED
         };
 
-        self.push_by_fluid(0.014, fluid_push[0], fluid_n[0]);
+        self.push_by_fluid(WATER_PUSH_SPEED, fluid_push[0], fluid_n[0]);
+
+        if caller.is_aquatic() {
+            self.push_by_buoyancy(self.is_eye_in_water().await);
+        }
 
         self.push_by_fluid(lava_speed, fluid_push[1], fluid_n[1]);
 
-        let water_height = fluid_height[0];
+        if let Some((pos, drag)) = bubble_column {
+            let surface = self.world.get_block_state(&pos.up()).await.is_air();
 
-        let in_water = in_fluid[0];
+            let is_boat = tag::EntityType::MINECRAFT_BOAT
+                .1
+                .contains(&self.entity_type.id);
 
-        if in_water {
-            if let Some(living) = caller.get_living_entity() {
+            let velocity = self.velocity.load();
+
+            self.velocity.store(Vector3::new(
+                velocity.x,
+                Self::bubble_column_velocity_y(velocity.y, drag, surface, is_boat),
+                velocity.z,
+            ));
+        }
+
+        let water_height = fluid_height[0];
+
+        let in_water = in_fluid[0];
+
+        if in_water {
+            if let Some(living) = caller.get_living_entity() {
                 living.fall_distance.store(0.0);
             }
 
@@ -1007,6 +1710,31 @@ impl Entity {
         self.touching_lava.store(in_lava, Ordering::SeqCst);
     }
 
+    /// How deep a box starting at `min_y` reaches into the fluid in the block at `block_y`,
+    /// or `None` if the fluid surface is below the box
+    #[must_use]
+    pub fn submerged_height(state: &FluidState, block_y: i32, min_y: f64) -> Option<f64> {
+        let marginal_height = f64::from(state.height) + f64::from(block_y) - min_y;
+
+        (marginal_height >= 0.0).then_some(marginal_height)
+    }
+
+    /// Returns the vertical velocity after being pushed by a bubble column. `drag` columns above
+    /// magma pull down, the others above soul sand push up. At the `surface` (air above the
+    /// column) the push is stronger and boats get launched or sunk.
+    #[must_use]
+    pub fn bubble_column_velocity_y(velocity_y: f64, drag: bool, surface: bool, boat: bool) -> f64 {
+        match (surface, drag) {
+            // TODO: Boats with a player passenger get launched to 2.7, and drag removes passengers
+            (true, true) if boat => velocity_y - 0.7,
+            (true, false) if boat => 0.6,
+            (true, true) => (velocity_y - 0.03).max(-0.9),
+            (true, false) => (velocity_y + 0.1).min(1.8),
+            (false, true) => (velocity_y - 0.03).max(-0.3),
+            (false, false) => (velocity_y + 0.06).min(0.7),
+        }
+    }
+
     fn push_by_fluid(&self, speed: f64, mut push: Vector3<f64>, n: usize) {
         if push.length_squared() != 0.0 {
             if n > 0 {
@@ -1030,6 +1758,15 @@ impl Entity {
         }
     }
 
+    /// Floats a submerged aquatic mob up, in place of the gravity it doesn't feel in water.
+    pub(crate) fn push_by_buoyancy(&self, submerged: bool) {
+        if submerged {
+            self.push_by_fluid(AQUATIC_BUOYANCY, Vector3::new(0.0, 1.0, 0.0), 1);
+        }
+    }
+
+    /// `Entity.getPosWithYOffset` in yarn. Prefers the block the entity is standing on over the block
+    /// under its center, so e.g. friction comes from the ice it is supported by.
     async fn get_pos_with_y_offset(
         &self,
         offset: f64,
@@ -1038,37 +1775,45 @@ impl Entity {
         Option<&'static Block>,
         Option<&'static BlockState>,
     ) {
-        if let Some(mut supporting_block) = self.supporting_block_pos.load() {
+        let y = self.pos.load().y;
+        if let Some(supporting_block) = self.supporting_block_pos.load() {
             if offset > 1.0e-5 {
                 let (block, state) = self.world.get_block_and_state(&supporting_block).await;
-
-                // if let Some(props) = block.properties(state.id) {
-                //     let name = props.;
-
-                //     if offset <= 0.5
-                //         && (name == "OakFenceLikeProperties"
-                //             || name == "ResinBrickWallLikeProperties"
-                //             || name == "OakFenceGateLikeProperties"
-                //                 && OakFenceGateLikeProperties::from_state_id(state.id, &block)
-                //                     .r#open)
-                //     {
-                //         return (supporting_block, Some(block), Some(state));
-                //     }
-                // }
-
-                supporting_block.0.y = (self.pos.load().y - offset).floor() as i32;
-
-                return (supporting_block, Some(block), Some(state));
+                if Self::keeps_supporting_pos(block, offset) {
+                    return (supporting_block, Some(block), Some(state));
+                }
             }
 
-            return (supporting_block, None, None);
+            return (
+                Self::pos_with_y_offset(supporting_block, y, offset),
+                None,
+                None,
+            );
         }
 
-        let mut block_pos = self.block_pos.load();
+        (
+            Self::pos_with_y_offset(self.block_pos.load(), y, offset),
+            None,
+            None,
+        )
+    }
 
-        block_pos.0.y = (self.pos.load().y - offset).floor() as i32;
+    /// Fences, walls and fence gates are taller than a block, so the entity stands on them even
+    /// though the position below its feet is air.
+    fn keeps_supporting_pos(block: &Block, offset: f64) -> bool {
+        (offset <= 0.5 && block.is_tagged_with_by_tag(&tag::Block::MINECRAFT_FENCES))
+            || block.is_tagged_with_by_tag(&tag::Block::MINECRAFT_WALLS)
+            || block.is_tagged_with_by_tag(&tag::Block::MINECRAFT_FENCE_GATES)
+    }
 
-        (block_pos, None, None)
+    #[must_use]
+    fn pos_with_y_offset(pos: BlockPos, y: f64, offset: f64) -> BlockPos {
+        if offset <= 1.0e-5 {
+            return pos;
+        }
+        let mut pos = pos;
+        pos.0.y = (y - offset).floor() as i32;
+        pos
     }
 
     async fn get_block_with_y_offset(
@@ -1124,16 +1869,26 @@ impl Entity {
     }
 
     #[allow(clippy::float_cmp)]
-    async fn get_velocity_multiplier(&self) -> f32 {
+    async fn get_velocity_multiplier(&self, soul_speed: bool) -> f32 {
         let block = self.world.get_block(&self.block_pos.load()).await;
 
-        let multiplier = block.velocity_multiplier;
+        let multiplier = Self::block_velocity_multiplier(block, soul_speed);
 
         if multiplier != 1.0 || block == &Block::WATER || block == &Block::BUBBLE_COLUMN {
             multiplier
         } else {
             let (_pos, block, _state) = self.get_block_with_y_offset(0.500_001).await;
 
+            Self::block_velocity_multiplier(block, soul_speed)
+        }
+    }
+
+    /// The velocity multiplier of a block, soul speed boots cancel the slowdown of soul sand and soul soil
+    #[must_use]
+    pub fn block_velocity_multiplier(block: &Block, soul_speed: bool) -> f32 {
+        if soul_speed && block.is_tagged_with_by_tag(&tag::Block::MINECRAFT_SOUL_SPEED_BLOCKS) {
+            1.0
+        } else {
             block.velocity_multiplier
         }
     }
@@ -1167,6 +1922,13 @@ impl Entity {
             return;
         }
 
+        // Fluid pushes, knockback and explosions add up, too fast entities would tunnel through blocks
+        let clamped = Self::clamp_velocity(motion);
+        if clamped != motion {
+            motion = clamped;
+            self.velocity.store(clamped);
+        }
+
         if self.no_clip.load(Ordering::Relaxed) {
             self.move_pos(motion);
 
@@ -1175,12 +1937,8 @@ impl Entity {
 
         let movement_multiplier = self.movement_multiplier.swap(Vector3::default());
 
-        if movement_multiplier.length_squared() > 1.0e-7 {
-            motion = motion.multiply(
-                movement_multiplier.x,
-                movement_multiplier.y,
-                movement_multiplier.z,
-            );
+        if let Some(slowed) = Self::slow_motion(motion, movement_multiplier) {
+            motion = slowed;
 
             self.velocity.store(Vector3::default());
         }
@@ -1189,7 +1947,11 @@ impl Entity {
 
         self.move_pos(final_move);
 
-        let velocity_multiplier = f64::from(self.get_velocity_multiplier().await);
+        let soul_speed = match caller.get_living_entity() {
+            Some(living) => living.get_soul_speed_level().await > 0,
+            None => false,
+        };
+        let velocity_multiplier = f64::from(self.get_velocity_multiplier(soul_speed).await);
 
         self.velocity.store(final_move * velocity_multiplier);
 
@@ -1205,59 +1967,168 @@ impl Entity {
         }
     }
 
-    pub async fn push_out_of_blocks(&self, center_pos: Vector3<f64>) {
-        let block_pos = BlockPos::floored_v(center_pos);
+    /// Slows down the movement of the next move, e.g. when inside a cobweb
+    pub fn slow_movement(&self, multiplier: Vector3<f64>) {
+        self.movement_multiplier.store(multiplier);
+    }
 
-        let delta = center_pos.sub(&block_pos.0.to_f64());
+    /// Applies a movement multiplier set by [`Entity::slow_movement`] to the motion.
+    /// Returns `None` if the entity isn't slowed.
+    #[must_use]
+    pub fn slow_motion(motion: Vector3<f64>, multiplier: Vector3<f64>) -> Option<Vector3<f64>> {
+        (multiplier.length_squared() > 1.0e-7)
+            .then(|| motion.multiply(multiplier.x, multiplier.y, multiplier.z))
+    }
 
-        let mut min_dist = f64::MAX;
+    pub async fn set_frozen_ticks(&self, frozen_ticks: i32) {
+        if self.frozen_ticks.swap(frozen_ticks, Relaxed) != frozen_ticks {
+            self.send_frozen_ticks(frozen_ticks).await;
+        }
+    }
 
-        let mut direction = BlockDirection::Up;
+    /// The client covers the screen in ice and slows down frozen entities based on this
+    async fn send_frozen_ticks(&self, frozen_ticks: i32) {
+        self.send_meta_data(&[Metadata::new(
+            7,
+            MetaDataType::Integer,
+            VarInt(frozen_ticks),
+        )])
+        .await;
+    }
 
-        for dir in BlockDirection::all() {
-            if dir == BlockDirection::Down {
-                continue;
-            }
+    pub async fn set_air_supply(&self, air_supply: i32) {
+        if self.air_supply.swap(air_supply, Relaxed) != air_supply {
+            self.send_meta_data(&[Metadata::new(1, MetaDataType::Integer, VarInt(air_supply))])
+                .await;
+        }
+    }
+
+    /// Vertical velocity after one tick of gravity. Entities `floating` in a fluid slowly rise
+    /// instead of sinking with full gravity.
+    #[must_use]
+    pub fn apply_gravity(velocity_y: f64, gravity: f64, floating: bool) -> f64 {
+        if !floating {
+            velocity_y - gravity
+        } else if velocity_y < MAX_FLOAT_SPEED {
+            velocity_y + FLOAT_SPEED
+        } else {
+            velocity_y
+        }
+    }
 
-            let offset = dir.to_offset();
+    /// Whether the entity's eyes are below the surface of water
+    pub async fn is_eye_in_water(&self) -> bool {
+        let position = self.pos.load();
+        let eye_y = self.get_eye_y();
+        let eye_pos = BlockPos::floored(position.x, eye_y, position.z);
+        let (fluid, state) = self.world.get_fluid_and_fluid_state(&eye_pos).await;
+        (fluid.id == Fluid::WATER.id || fluid.id == Fluid::FLOWING_WATER.id)
+            && eye_y < f64::from(eye_pos.0.y) + f64::from(state.height)
+    }
 
-            if self
+    pub async fn push_out_of_blocks(&self, center_pos: Vector3<f64>) {
+        let block_pos = BlockPos::floored_v(center_pos);
+        let mut open_faces = Vec::new();
+        for dir in BlockDirection::all() {
+            if !self
                 .world
-                .get_block_state(&block_pos.offset(offset))
+                .get_block_state(&block_pos.offset(dir.to_offset()))
                 .await
                 .is_full_cube()
             {
+                open_faces.push(dir);
+            }
+        }
+
+        let amplitude = rand::random::<f64>() * 0.2 + 0.1;
+        let velo = Self::push_out_velocity(
+            self.velocity.load(),
+            center_pos.sub(&block_pos.0.to_f64()),
+            &open_faces,
+            amplitude,
+        );
+        self.velocity.store(velo);
+    }
+
+    /// Pushes towards the closest open face of the block, or upwards if every face is covered.
+    /// `delta` is the position relative to the block's minimum corner.
+    fn push_out_velocity(
+        velocity: Vector3<f64>,
+        delta: Vector3<f64>,
+        open_faces: &[BlockDirection],
+        amplitude: f64,
+    ) -> Vector3<f64> {
+        let mut min_dist = f64::MAX;
+        let mut direction = BlockDirection::Up;
+        for &dir in open_faces {
+            if dir == BlockDirection::Down {
                 continue;
             }
 
             let component = delta.get_axis(dir.to_axis().into());
-
             let dist = if dir.positive() {
                 1.0 - component
             } else {
                 component
             };
-
             if dist < min_dist {
                 min_dist = dist;
-
                 direction = dir;
             }
         }
 
-        let amplitude = rand::random::<f64>() * 0.2 + 0.1;
-
-        let axis = direction.to_axis().into();
-
         let sign = if direction.positive() { 1.0 } else { -1.0 };
+        let mut velo = velocity * 0.75;
+        velo.set_axis(direction.to_axis().into(), sign * amplitude);
+        velo
+    }
 
-        let mut velo = self.velocity.load();
-
-        velo = velo * 0.75;
+    /// Ejects the entity when its center ended up inside a full block, e.g. after spawning or being
+    /// pushed into a wall.
+    async fn push_out_if_stuck(&self) {
+        if self.no_clip.load(Ordering::Relaxed) {
+            return;
+        }
+        let pos = self.pos.load();
+        let bounding_box = self.bounding_box.load();
+        let center = Vector3::new(
+            pos.x,
+            f64::midpoint(bounding_box.min.y, bounding_box.max.y),
+            pos.z,
+        );
+        if self
+            .world
+            .get_block_state(&BlockPos::floored_v(center))
+            .await
+            .is_full_cube()
+        {
+            self.push_out_of_blocks(center).await;
+        }
+    }
 
-        velo.set_axis(axis, sign * amplitude);
+    /// Whether entities of the given type are ever teleported by portals, like vanilla's `canUsePortals` overrides
+    #[must_use]
+    pub fn type_can_use_portals(entity_type: &EntityType) -> bool {
+        ![
+            &EntityType::ENDER_DRAGON,
+            &EntityType::ITEM_FRAME,
+            &EntityType::GLOW_ITEM_FRAME,
+            &EntityType::PAINTING,
+            &EntityType::LEASH_KNOT,
+            &EntityType::FISHING_BOBBER,
+        ]
+        .contains(&entity_type)
+    }
 
-        self.velocity.store(velo);
+    /// Whether mobs of the given type live in water, like fish, squids and dolphins
+    #[must_use]
+    pub fn is_aquatic_type(entity_type: &EntityType) -> bool {
+        [
+            &MobCategory::WATER_CREATURE,
+            &MobCategory::WATER_AMBIENT,
+            &MobCategory::UNDERGROUND_WATER_CREATURE,
+        ]
+        .contains(&entity_type.category)
     }
 
     async fn tick_portal(&self, caller: &Arc<dyn EntityBase>) {
@@ -1265,6 +2136,10 @@ impl Entity {
             self.portal_cooldown.fetch_sub(1, Ordering::Relaxed);
         }
         let mut manager_guard = self.portal_manager.lock().await;
+        if !caller.can_use_portals() {
+            *manager_guard = None;
+            return;
+        }
         // I know this is ugly, but a quick fix because i can't modify the thing while using it
         let mut should_remove = false;
         if let Some(pmanager_mutex) = manager_guard.as_ref() {
@@ -1273,33 +2148,26 @@ impl Entity {
                 // reset cooldown
                 self.portal_cooldown
                     .store(self.default_portal_cooldown(), Ordering::Relaxed);
-                let pos = self.pos.load();
-                // TODO: this is bad
-                let scale_factor_new = if portal_manager.portal_world.dimension_type
-                    == VanillaDimensionType::TheNether
+                let target_world = portal_manager.portal_world.clone();
+                let pos = PortalManager::destination(
+                    self.pos.load(),
+                    self.world.dimension_type,
+                    target_world.dimension_type,
+                    &target_world.generation_settings().shape,
+                );
+                let position = if self.world.dimension_type == VanillaDimensionType::TheNether
+                    || target_world.dimension_type == VanillaDimensionType::TheNether
                 {
-                    8.0
+                    let radius = PortalManager::search_radius(target_world.dimension_type);
+                    let portal = NetherPortal::find_or_create(&target_world, pos, radius).await;
+                    let portal = portal.0.to_f64();
+                    Vector3::new(portal.x + 0.5, portal.y, portal.z + 0.5)
                 } else {
-                    1.0
+                    pos.0.to_f64()
                 };
-                // TODO: this is bad
-                let scale_factor_current =
-                    if self.world.dimension_type == VanillaDimensionType::TheNether {
-                        8.0
-                    } else {
-                        1.0
-                    };
-                let scale_factor = scale_factor_current / scale_factor_new;
-                // TODO
-                let pos = BlockPos::floored(pos.x * scale_factor, pos.y, pos.z * scale_factor);
                 caller
                     .clone()
-                    .teleport(
-                        pos.0.to_f64(),
-                        None,
-                        None,
-                        portal_manager.portal_world.clone(),
-                    )
+                    .teleport(position, None, None, target_world, true)
                     .await;
                 drop(portal_manager);
             } else if portal_manager.ticks_in_portal == 0 {
@@ -1334,17 +2202,65 @@ impl Entity {
     /// Extinguishes this entity.
     pub fn extinguish(&self) {
         self.fire_ticks.store(0, Ordering::Relaxed);
+        self.fire_attacker.store(None);
+    }
+
+    /// Returns the fire ticks after burning for one tick and whether the entity takes fire damage.
+    /// Entities in lava are already hurt by the lava itself.
+    #[must_use]
+    pub fn tick_fire(fire_ticks: i32, fire_immune: bool, in_lava: bool) -> (i32, bool) {
+        if fire_immune {
+            ((fire_ticks - 4).max(0), false)
+        } else {
+            (fire_ticks - 1, fire_ticks % 20 == 0 && !in_lava)
+        }
+    }
+
+    /// Sets entities touching lava on fire and hurts them. The fire keeps burning after leaving the lava.
+    async fn set_on_fire_from_lava(&self, caller: &Arc<dyn EntityBase>) {
+        if self.entity_type.fire_immune {
+            return;
+        }
+        self.set_on_fire_for(LAVA_FIRE_SECONDS).await;
+        if caller
+            .damage(caller.clone(), LAVA_DAMAGE, DamageType::LAVA)
+            .await
+        {
+            self.play_sound(Sound::EntityGenericBurn).await;
+        }
+        if let Some(living) = caller.get_living_entity() {
+            living
+                .fall_distance
+                .store(living.fall_distance.load() * 0.5);
+        }
     }
 
-    pub fn set_on_fire_for(&self, seconds: f32) {
-        self.set_on_fire_for_ticks((seconds * 20.0).floor() as u32);
+    pub async fn set_on_fire_for(&self, seconds: f32) {
+        self.set_on_fire_for_ticks((seconds * 20.0).floor() as u32)
+            .await;
+    }
+
+    /// Sets this entity on fire like [`Entity::set_on_fire_for`], crediting `attacker` for the fire damage
+    pub async fn set_on_fire_by(&self, seconds: f32, attacker: &Self) {
+        self.set_on_fire_for(seconds).await;
+        self.fire_attacker.store(Some(attacker.entity_id));
     }
 
-    pub fn set_on_fire_for_ticks(&self, ticks: u32) {
-        if self.fire_ticks.load(Ordering::Relaxed) < ticks as i32 {
-            self.fire_ticks.store(ticks as i32, Ordering::Relaxed);
+    /// Keeps the entity burning for at least `ticks`, a shorter duration never puts out fire early.
+    /// Catching fire thaws the entity.
+    pub async fn set_on_fire_for_ticks(&self, ticks: u32) {
+        self.fire_ticks
+            .fetch_max(i32::try_from(ticks).unwrap_or(i32::MAX), Ordering::Relaxed);
+        if self.frozen_ticks.swap(0, Ordering::Relaxed) != 0 {
+            self.send_frozen_ticks(0).await;
         }
-        // TODO: defrost
+    }
+
+    /// Sets the remaining fire ticks even if that is shorter than the current fire,
+    /// unlike [`Entity::set_on_fire_for_ticks`].
+    pub fn force_set_fire_ticks(&self, ticks: u32) {
+        self.fire_ticks
+            .store(i32::try_from(ticks).unwrap_or(i32::MAX), Ordering::Relaxed);
     }
 
     /// Sets the `Entity` yaw & pitch rotation
@@ -1363,6 +2279,12 @@ impl Entity {
         self.world.remove_entity(self).await;
     }
 
+    /// Removes the entity for good, it won't be saved
+    pub async fn discard(&self) {
+        self.removal_reason.store(Some(RemovalReason::Discarded));
+        self.remove().await;
+    }
+
     pub fn create_spawn_packet(&self) -> CSpawnEntity {
         let entity_loc = self.pos.load();
         let entity_vel = self.velocity.load();
@@ -1373,7 +2295,7 @@ impl Entity {
             entity_loc,
             self.pitch.load(),
             self.yaw.load(),
-            self.head_yaw.load(), // todo: head_yaw and yaw are swapped, find out why
+            self.head_yaw.load(),
             self.data.load(Relaxed).into(),
             entity_vel,
         )
@@ -1386,10 +2308,17 @@ impl Entity {
         self.bounding_box_size.load().height
     }
 
+    /// Knockback strength against an entity of the given scale, bigger entities are pushed less.
+    #[must_use]
+    pub fn scaled_knockback(strength: f64, scale: f32) -> f64 {
+        strength / f64::from(scale)
+    }
+
     /// Applies knockback to the entity, following vanilla Minecraft's mechanics.
     ///
     /// This function calculates the entity's new velocity based on the specified knockback strength and direction.
     pub fn knockback(&self, strength: f64, x: f64, z: f64) {
+        let strength = Self::scaled_knockback(strength, self.scale.load());
         // This has some vanilla magic
         let mut x = x;
         let mut z = z;
@@ -1412,9 +2341,12 @@ impl Entity {
     }
 
     pub async fn set_sneaking(&self, sneaking: bool) {
-        //assert!(self.sneaking.load(Relaxed) != sneaking);
-        self.sneaking.store(sneaking, Relaxed);
-        self.set_flag(Flag::Sneaking, sneaking).await;
+        if Self::swap_flag(&self.sneaking, sneaking) {
+            self.send_flags().await;
+        }
+        if self.swimming.load(Relaxed) {
+            return;
+        }
         if sneaking {
             self.set_pose(EntityPose::Crouching).await;
         } else {
@@ -1422,10 +2354,45 @@ impl Entity {
         }
     }
 
+    /// Sprinting in water makes an entity swim, which lasts until it stops sprinting or leaves the water.
+    async fn update_swimming(&self) {
+        let can_swim = self.sprinting.load(Relaxed) && self.vehicle.lock().await.is_none();
+        // Starting to swim takes the eyes being underwater, touching water keeps it going
+        let swimming = can_swim
+            && if self.swimming.load(Relaxed) {
+                self.touching_water.load(Ordering::SeqCst)
+            } else {
+                self.is_eye_in_water().await
+            };
+        if self.swimming.swap(swimming, Relaxed) == swimming {
+            return;
+        }
+        self.send_flags().await;
+        if swimming {
+            self.set_pose(EntityPose::Swimming).await;
+        } else if self.sneaking.load(Relaxed) {
+            self.set_pose(EntityPose::Crouching).await;
+        } else {
+            self.set_pose(EntityPose::Standing).await;
+        }
+    }
+
     pub async fn set_on_fire(&self, on_fire: bool) {
         if self.has_visual_fire.load(Ordering::Relaxed) != on_fire {
             self.has_visual_fire.store(on_fire, Ordering::Relaxed);
-            self.set_flag(Flag::OnFire, on_fire).await;
+            self.send_flags().await;
+        }
+    }
+
+    pub async fn set_glowing(&self, glowing: bool) {
+        if self.glowing.swap(glowing, Relaxed) != glowing {
+            self.send_flags().await;
+        }
+    }
+
+    pub async fn set_invisible(&self, invisible: bool) {
+        if self.invisible.swap(invisible, Relaxed) != invisible {
+            self.send_flags().await;
         }
     }
 
@@ -1554,9 +2521,9 @@ impl Entity {
     }
 
     pub async fn set_sprinting(&self, sprinting: bool) {
-        //assert!(self.sprinting.load(Relaxed) != sprinting);
-        self.sprinting.store(sprinting, Relaxed);
-        self.set_flag(Flag::Sprinting, sprinting).await;
+        if Self::swap_flag(&self.sprinting, sprinting) {
+            self.send_flags().await;
+        }
     }
 
     pub fn check_fall_flying(&self) -> bool {
@@ -1564,12 +2531,41 @@ impl Entity {
     }
 
     pub async fn set_fall_flying(&self, fall_flying: bool) {
-        assert!(self.fall_flying.load(Relaxed) != fall_flying);
-        self.fall_flying.store(fall_flying, Relaxed);
-        self.set_flag(Flag::FallFlying, fall_flying).await;
+        if Self::swap_flag(&self.fall_flying, fall_flying) {
+            self.send_flags().await;
+        }
+    }
+
+    /// Returns whether the flag changed, so unchanged flags aren't broadcast again
+    fn swap_flag(flag: &AtomicBool, value: bool) -> bool {
+        flag.swap(value, Relaxed) != value
+    }
+
+    /// Sends all flags at once, as the client replaces the whole byte.
+    pub async fn send_flags(&self) {
+        self.send_meta_data(&[Metadata::new(0, MetaDataType::Byte, self.flags())])
+            .await;
+    }
+
+    fn flags(&self) -> i8 {
+        Self::combine_flags(&[
+            (Flag::OnFire, self.has_visual_fire.load(Relaxed)),
+            (Flag::Sneaking, self.sneaking.load(Relaxed)),
+            (Flag::Sprinting, self.sprinting.load(Relaxed)),
+            (Flag::Swimming, self.swimming.load(Relaxed)),
+            (Flag::Invisible, self.invisible.load(Relaxed)),
+            (Flag::Glowing, self.glowing.load(Relaxed)),
+            (Flag::FallFlying, self.fall_flying.load(Relaxed)),
+        ])
     }
 
-    async fn set_flag(&self, flag: Flag, value: bool) {
+    fn combine_flags(flags: &[(Flag, bool)]) -> i8 {
+        flags
+            .iter()
+            .fold(0, |b, &(flag, value)| b | Self::flag_byte(flag, value))
+    }
+
+    fn flag_byte(flag: Flag, value: bool) -> i8 {
         let index = flag as u8;
         let mut b = 0i8;
         if value {
@@ -1577,8 +2573,7 @@ impl Entity {
         } else {
             b &= !(1 << index);
         }
-        self.send_meta_data(&[Metadata::new(0, MetaDataType::Byte, b)])
-            .await;
+        b
     }
 
     /// Plays sound at this entity's position with the entity's sound category
@@ -1588,27 +2583,56 @@ impl Entity {
             .await;
     }
 
+    /// Updates the entries in the [`DataTracker`] and sends the ones that changed
     pub async fn send_meta_data<T: Serialize>(&self, meta: &[Metadata<T>]) {
-        let mut buf = Vec::new();
         for meta in meta {
-            let mut serializer_buf = Vec::new();
-            let mut serializer = Serializer::new(&mut serializer_buf);
-            meta.serialize(&mut serializer).unwrap();
-            buf.extend(serializer_buf);
+            self.data_tracker.track_meta(meta);
         }
-        buf.put_u8(255);
-        self.world
-            .broadcast_packet_all(&CSetEntityMetadata::new(self.entity_id.into(), buf.into()))
-            .await;
+        self.send_dirty_meta_data().await;
+    }
+
+    /// Sends the entries of the [`DataTracker`] that changed since they were last sent
+    pub async fn send_dirty_meta_data(&self) {
+        if let Some(buf) = self.data_tracker.serialize_dirty() {
+            self.world
+                .broadcast_packet_all(&CSetEntityMetadata::new(self.entity_id.into(), buf))
+                .await;
+        }
+    }
+
+    /// All tracked metadata, for players that start seeing the entity
+    #[must_use]
+    pub fn tracked_data_packet(&self) -> Option<CSetEntityMetadata> {
+        self.data_tracker
+            .serialize_all()
+            .map(|buf| CSetEntityMetadata::new(self.entity_id.into(), buf))
     }
 
     pub async fn set_pose(&self, pose: EntityPose) {
         self.pose.store(pose);
+        if let Some(size) = Self::pose_dimensions(self.entity_type, pose) {
+            self.set_bounding_box(
+                size.scaled(self.scale.load()),
+                self.bounding_box_offset.load(),
+            );
+        }
         let pose = pose as i32;
         self.send_meta_data(&[Metadata::new(6, MetaDataType::EntityPose, VarInt(pose))])
             .await;
     }
 
+    /// Custom names are saved as JSON text.
+    fn custom_name_to_nbt(name: &TextComponent) -> String {
+        serde_json::to_string(&name.0).unwrap_or_else(|_| name.clone().get_text())
+    }
+
+    /// Reads a saved custom name, plain strings are taken as literal text.
+    fn custom_name_from_nbt(name: &str) -> TextComponent {
+        serde_json::from_str(name)
+            .map_or_else(|_| TextComponent::text(name.to_string()), TextComponent)
+    }
+
+    /// `/kill` gets through everything, other damage is blocked by the invulnerable flag and immunities.
     pub fn is_invulnerable_to(&self, damage_type: &DamageType) -> bool {
         *damage_type != DamageType::GENERIC_KILL
             && (self.invulnerable.load(Relaxed) || self.damage_immunities.contains(damage_type))
@@ -1685,24 +2709,57 @@ impl Entity {
         yaw: Option<f32>,
         pitch: Option<f32>,
         _world: Arc<World>,
+        keep_velocity: bool,
     ) {
         // TODO: handle world change
+        self.set_teleport_state(position, yaw, pitch, keep_velocity);
         self.world
             .broadcast_packet_all(&CEntityPositionSync::new(
                 self.entity_id.into(),
                 position,
-                Vector3::new(0.0, 0.0, 0.0),
-                yaw.unwrap_or(0.0),
-                pitch.unwrap_or(0.0),
+                self.velocity.load(),
+                self.yaw.load(),
+                self.pitch.load(),
                 self.on_ground.load(Ordering::SeqCst),
             ))
             .await;
     }
 
+    /// Moves the entity without sending anything. The velocity goes out with the position, so a
+    /// pending velocity change is dropped and the position counts as sent.
+    fn set_teleport_state(
+        &self,
+        position: Vector3<f64>,
+        yaw: Option<f32>,
+        pitch: Option<f32>,
+        keep_velocity: bool,
+    ) {
+        self.set_pos(position);
+        self.last_pos.store(position);
+        self.set_rotation(
+            yaw.unwrap_or(self.yaw.load()),
+            pitch.unwrap_or(self.pitch.load()),
+        );
+        self.sent_rotation.update((
+            Self::angle_to_byte(self.yaw.load()),
+            Self::angle_to_byte(self.pitch.load()),
+            self.on_ground.load(Relaxed),
+        ));
+        if !keep_velocity {
+            self.velocity.store(Vector3::new(0.0, 0.0, 0.0));
+        }
+        self.velocity_dirty.store(false, Ordering::SeqCst);
+    }
+
     pub fn get_eye_y(&self) -> f64 {
         self.pos.load().y + f64::from(self.standing_eye_height)
     }
 
+    pub fn get_eye_pos(&self) -> Vector3<f64> {
+        let pos = self.pos.load();
+        Vector3::new(pos.x, self.get_eye_y(), pos.z)
+    }
+
     pub fn is_removed(&self) -> bool {
         self.removal_reason.load().is_some()
     }
@@ -1729,10 +2786,78 @@ impl Entity {
         }
     }
 
+    /// Ticks everything every entity shares. Entities which move themselves call this instead of
+    /// [`EntityBase::tick`], which would also move them by their velocity.
+    pub async fn base_tick(&self, caller: Arc<dyn EntityBase>, _server: &Server) {
+        // Set again by the powder snow block when colliding with it
+        self.in_powder_snow.store(false, Relaxed);
+        self.tick_portal(&caller).await;
+        self.update_fluid_state(&caller).await;
+        self.update_swimming().await;
+        self.check_out_of_world(caller.clone()).await;
+        // Players move themselves, the client pushes them out of blocks
+        if caller.get_player().is_none() {
+            self.push_out_if_stuck().await;
+        }
+        let in_lava = self.touching_lava.load(Ordering::SeqCst);
+        let fire_ticks = self.fire_ticks.load(Ordering::Relaxed);
+        if fire_ticks > 0 {
+            let (fire_ticks, burn) =
+                Self::tick_fire(fire_ticks, self.entity_type.fire_immune, in_lava);
+            if burn {
+                let attacker = match self.fire_attacker.load() {
+                    Some(id) => self.world.get_entity_by_id(id).await,
+                    None => None,
+                };
+                // The fire itself is what hurts, the attacker is only the one who caused it
+                caller
+                    .damage_with_context(
+                        caller.clone(),
+                        1.0,
+                        DamageType::ON_FIRE,
+                        None,
+                        attacker.as_deref(),
+                        None,
+                    )
+                    .await;
+            }
+            self.fire_ticks.store(fire_ticks, Ordering::Relaxed);
+            // Catching fire again later isn't blamed on the old attacker
+            if fire_ticks <= 0 {
+                self.fire_attacker.store(None);
+            }
+        }
+        if in_lava {
+            self.set_on_fire_from_lava(&caller).await;
+        }
+        self.set_on_fire(self.fire_ticks.load(Ordering::Relaxed) > 0)
+            .await;
+    }
+
+    /// Moves entities without movement of their own by their velocity, slowed down by the air, and
+    /// sends where they ended up
+    async fn tick_move(&self, caller: Arc<dyn EntityBase>) {
+        if caller.get_player().is_some() || self.is_removed() {
+            return;
+        }
+        let velocity = self.velocity.load();
+        // Too slow to be worth moving and broadcasting
+        if velocity.length_squared() <= 1.0e-7 {
+            return;
+        }
+        self.move_entity(caller, velocity).await;
+        self.velocity.store(self.velocity.load() * AIR_DRAG);
+        self.send_pos().await;
+    }
+
     #[allow(clippy::unused_async)]
     pub async fn reset_state(&self) {
         self.pose.store(EntityPose::Standing);
+        if let Some(size) = Self::pose_dimensions(self.entity_type, EntityPose::Standing) {
+            self.set_bounding_box(size, self.bounding_box_offset.load());
+        }
         self.fall_flying.store(false, Relaxed);
+        self.swimming.store(false, Relaxed);
     }
 }
 
@@ -1776,36 +2901,55 @@ impl NBTStorage for Entity {
             NbtTag::List(vec![self.yaw.load().into(), self.pitch.load().into()]),
         );
         nbt.put_short("Fire", self.fire_ticks.load(Relaxed) as i16);
+        nbt.put_short("Air", self.air_supply.load(Relaxed) as i16);
+        nbt.put_int("TicksFrozen", self.frozen_ticks.load(Relaxed));
         nbt.put_bool("OnGround", self.on_ground.load(Relaxed));
         nbt.put_bool("Invulnerable", self.invulnerable.load(Relaxed));
         nbt.put_int("PortalCooldown", self.portal_cooldown.load(Relaxed) as i32);
         if self.has_visual_fire.load(Relaxed) {
             nbt.put_bool("HasVisualFire", true);
         }
+        if self.glowing.load(Relaxed) {
+            nbt.put_bool("Glowing", true);
+        }
+        if self.invisible.load(Relaxed) {
+            nbt.put_bool("Invisible", true);
+        }
+        if let Some(name) = self.custom_name.lock().unwrap().as_ref() {
+            nbt.put_string("CustomName", Self::custom_name_to_nbt(name));
+        }
+        if self.custom_name_visible.load(Relaxed) {
+            nbt.put_bool("CustomNameVisible", true);
+        }
 
         // todo more...
     }
 
     async fn read_nbt_non_mut(&self, nbt: &NbtCompound) {
-        let position = nbt.get_list("Pos").unwrap();
-        let x = position[0].extract_double().unwrap_or(0.0);
-        let y = position[1].extract_double().unwrap_or(0.0);
-        let z = position[2].extract_double().unwrap_or(0.0);
-        let pos = Vector3::new(x, y, z);
+        let pos = Self::clamp_loaded_pos(Self::read_nbt_vector(nbt, "Pos"));
         self.set_pos(pos);
-        self.first_loaded_chunk_position.store(Some(pos.to_i32()));
-        let velocity = nbt.get_list("Motion").unwrap();
-        let x = velocity[0].extract_double().unwrap_or(0.0);
-        let y = velocity[1].extract_double().unwrap_or(0.0);
-        let z = velocity[2].extract_double().unwrap_or(0.0);
-        self.velocity.store(Vector3::new(x, y, z));
-        let rotation = nbt.get_list("Rotation").unwrap();
-        let yaw = rotation[0].extract_float().unwrap_or(0.0);
-        let pitch = rotation[1].extract_float().unwrap_or(0.0);
+        self.saved_chunk_position.store(Some(self.chunk_pos.load()));
+        self.velocity
+            .store(Self::clamp_velocity(Self::read_nbt_vector(nbt, "Motion")));
+        let rotation = nbt.get_list("Rotation").unwrap_or_default();
+        let yaw = rotation
+            .first()
+            .and_then(NbtTag::extract_float)
+            .unwrap_or(0.0);
+        let pitch = rotation
+            .get(1)
+            .and_then(NbtTag::extract_float)
+            .unwrap_or(0.0);
         self.set_rotation(yaw, pitch);
         self.head_yaw.store(yaw);
         self.fire_ticks
             .store(i32::from(nbt.get_short("Fire").unwrap_or(0)), Relaxed);
+        self.air_supply.store(
+            i32::from(nbt.get_short("Air").unwrap_or(MAX_AIR_SUPPLY as i16)),
+            Relaxed,
+        );
+        self.frozen_ticks
+            .store(nbt.get_int("TicksFrozen").unwrap_or(0), Relaxed);
         self.on_ground
             .store(nbt.get_bool("OnGround").unwrap_or(false), Relaxed);
         self.invulnerable
@@ -1814,36 +2958,23 @@ impl NBTStorage for Entity {
             .store(nbt.get_int("PortalCooldown").unwrap_or(0) as u32, Relaxed);
         self.has_visual_fire
             .store(nbt.get_bool("HasVisualFire").unwrap_or(false), Relaxed);
+        self.glowing
+            .store(nbt.get_bool("Glowing").unwrap_or(false), Relaxed);
+        self.invisible
+            .store(nbt.get_bool("Invisible").unwrap_or(false), Relaxed);
+        *self.custom_name.lock().unwrap() =
+            nbt.get_string("CustomName").map(Self::custom_name_from_nbt);
+        self.custom_name_visible
+            .store(nbt.get_bool("CustomNameVisible").unwrap_or(false), Relaxed);
         // todo more...
     }
 }
 
 #[async_trait]
 impl EntityBase for Entity {
-    async fn tick(&self, caller: Arc<dyn EntityBase>, _server: &Server) {
-        self.tick_portal(&caller).await;
-        self.update_fluid_state(&caller).await;
-        self.check_out_of_world(caller.clone()).await;
-        let fire_ticks = self.fire_ticks.load(Ordering::Relaxed);
-        if fire_ticks > 0 {
-            if self.entity_type.fire_immune {
-                self.fire_ticks.store(fire_ticks - 4, Ordering::Relaxed);
-                if self.fire_ticks.load(Ordering::Relaxed) < 0 {
-                    self.extinguish();
-                }
-            } else {
-                if fire_ticks % 20 == 0 {
-                    caller
-                        .damage(caller.clone(), 1.0, DamageType::ON_FIRE)
-                        .await;
-                }
-
-                self.fire_ticks.store(fire_ticks - 1, Ordering::Relaxed);
-            }
-        }
-        self.set_on_fire(self.fire_ticks.load(Ordering::Relaxed) > 0)
-            .await;
-        // TODO: Tick
+    async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
+        self.base_tick(caller.clone(), server).await;
+        self.tick_move(caller).await;
     }
 
     async fn teleport(
@@ -1852,9 +2983,11 @@ impl EntityBase for Entity {
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        keep_velocity: bool,
     ) {
         // TODO: handle world change
-        self.teleport(position, yaw, pitch, world).await;
+        self.teleport(position, yaw, pitch, world, keep_velocity)
+            .await;
     }
 
     fn get_entity(&self) -> &Entity {
@@ -1913,3 +3046,1444 @@ pub enum Flag {
     /// Indicates if the entity is flying due to a fall.
     FallFlying = 7,
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::Block;
+    use pumpkin_data::entity::{EntityPose, EntityType};
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+    use pumpkin_util::math::{
+        boundingbox::{BoundingBox, EntityDimensions},
+        position::BlockPos,
+        vector3::Vector3,
+    };
+
+    use pumpkin_util::math::subtract_angles;
+
+    use pumpkin_protocol::java::client::play::MetaDataType;
+    use pumpkin_util::text::TextComponent;
+
+    use super::{Entity, Flag, LAVA_FIRE_SECONDS, MIN_FREEZE_DAMAGE_TICKS};
+    use crate::block::blocks::honey::HoneyBlock;
+    use crate::world::World;
+
+    fn zombie_box(x: f64, y: f64, z: f64) -> BoundingBox {
+        BoundingBox::new_from_pos(
+            x,
+            y,
+            z,
+            &EntityDimensions {
+                width: EntityType::ZOMBIE.dimension[0],
+                height: EntityType::ZOMBIE.dimension[1],
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn stuck_entity_is_pushed_towards_nearest_open_face() {
+        use pumpkin_data::BlockDirection;
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        // Buried in stone, only the east face is open
+        let stuck_in = BlockPos::new(0, 300, 0);
+        let mut blocks = vec![stuck_in];
+        for dir in BlockDirection::all() {
+            if dir != BlockDirection::East {
+                blocks.push(stuck_in.offset(dir.to_offset()));
+            }
+        }
+        for pos in &blocks {
+            world
+                .set_block_state(pos, Block::STONE.default_state.id, BlockFlags::FORCE_STATE)
+                .await;
+        }
+        // Closer to the west face, but that one is covered
+        let zombie = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.2, 299.5, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ));
+        zombie.base_tick(zombie.clone(), &server).await;
+
+        let velocity = zombie.velocity.load();
+        assert!(velocity.x >= 0.1);
+        assert!(velocity.y.abs() < f64::EPSILON);
+        assert!(velocity.z.abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn fire_is_only_extended_unless_forced() {
+        use std::sync::atomic::Ordering;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let fire_ticks = || zombie.fire_ticks.load(Ordering::Relaxed);
+
+        // Not burning yet
+        zombie.set_on_fire_for_ticks(u32::MAX).await;
+        assert_eq!(fire_ticks(), i32::MAX);
+
+        zombie.force_set_fire_ticks(100);
+        assert_eq!(fire_ticks(), 100);
+        zombie.set_on_fire_for_ticks(40).await;
+        assert_eq!(fire_ticks(), 100);
+        zombie.set_on_fire_for_ticks(160).await;
+        assert_eq!(fire_ticks(), 160);
+
+        zombie.force_set_fire_ticks(40);
+        assert_eq!(fire_ticks(), 40);
+    }
+
+    #[test]
+    fn only_opaque_full_blocks_suffocate() {
+        for block in [&Block::STONE, &Block::DIRT, &Block::SOUL_SAND] {
+            assert!(block.default_state.suffocates(), "{}", block.name);
+        }
+        for block in [&Block::GLASS, &Block::ICE, &Block::OAK_LEAVES, &Block::AIR] {
+            assert!(!block.default_state.suffocates(), "{}", block.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn glowing_sets_flag_bit() {
+        use super::NBTStorage;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let glowing_bit = 1 << Flag::Glowing as u8;
+
+        zombie.set_glowing(true).await;
+        assert_eq!(zombie.flags() & glowing_bit, glowing_bit);
+        let mut nbt = NbtCompound::new();
+        zombie.write_nbt(&mut nbt).await;
+        assert_eq!(nbt.get_bool("Glowing"), Some(true));
+
+        zombie.set_glowing(false).await;
+        assert_eq!(zombie.flags() & glowing_bit, 0);
+
+        // Loading the saved entity makes it glow again
+        zombie.read_nbt_non_mut(&nbt).await;
+        assert_eq!(zombie.flags() & glowing_bit, glowing_bit);
+    }
+
+    #[test]
+    fn swimming_player_is_smaller() {
+        let standing = Entity::pose_dimensions(&EntityType::PLAYER, EntityPose::Standing).unwrap();
+        let swimming = Entity::pose_dimensions(&EntityType::PLAYER, EntityPose::Swimming).unwrap();
+        assert!(swimming.height < standing.height);
+        assert!((swimming.height - 0.6).abs() < f32::EPSILON);
+
+        assert!(Entity::pose_dimensions(&EntityType::ZOMBIE, EntityPose::Swimming).is_none());
+    }
+
+    #[test]
+    fn flags_are_sent_together() {
+        let flags = Entity::combine_flags(&[
+            (Flag::OnFire, false),
+            (Flag::Invisible, true),
+            (Flag::Glowing, true),
+        ]);
+        assert_eq!(flags, (1 << 5) | (1 << 6));
+    }
+
+    #[tokio::test]
+    async fn sprinting_starts_swimming_only_with_the_eyes_underwater() {
+        use std::sync::atomic::Ordering;
+
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let set_block = |y: i32, block: &Block| {
+            let world = world.clone();
+            let state = block.default_state.id;
+            async move {
+                world
+                    .set_block_state(&BlockPos::new(0, y, 0), state, BlockFlags::FORCE_STATE)
+                    .await;
+            }
+        };
+        let player = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::PLAYER,
+            false,
+        );
+        player.sprinting.store(true, Ordering::Relaxed);
+        player.touching_water.store(true, Ordering::SeqCst);
+        let height = || {
+            let bounding_box = player.bounding_box.load();
+            bounding_box.max.y - bounding_box.min.y
+        };
+
+        // Wading through shallow water
+        set_block(300, &Block::WATER).await;
+        player.update_swimming().await;
+        assert!(!player.swimming.load(Ordering::Relaxed));
+
+        set_block(301, &Block::WATER).await;
+        player.update_swimming().await;
+        assert!(player.swimming.load(Ordering::Relaxed));
+        assert!((height() - 0.6).abs() < 1e-6);
+
+        // Keeps swimming at the surface
+        set_block(301, &Block::AIR).await;
+        player.update_swimming().await;
+        assert!(player.swimming.load(Ordering::Relaxed));
+
+        player.touching_water.store(false, Ordering::SeqCst);
+        player.update_swimming().await;
+        assert!(!player.swimming.load(Ordering::Relaxed));
+        assert!((height() - 1.8).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn invulnerable_entities_take_no_damage() {
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering;
+
+        use pumpkin_data::damage::DamageType;
+
+        use super::EntityBase;
+        use crate::entity::decoration::painting::PaintingEntity;
+
+        async fn hit(painting: Arc<PaintingEntity>, damage_type: DamageType) -> bool {
+            painting
+                .damage_with_context(painting.clone(), 1.0, damage_type, None, None, None)
+                .await
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_painting = |invulnerable: bool, damage_immunities: Vec<DamageType>| {
+            let mut entity = Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                &EntityType::PAINTING,
+                false,
+            );
+            entity.invulnerable.store(invulnerable, Ordering::Relaxed);
+            entity.damage_immunities = damage_immunities;
+            Arc::new(PaintingEntity::new(entity))
+        };
+
+        assert!(hit(new_painting(false, vec![]), DamageType::ARROW).await);
+        assert!(!hit(new_painting(true, vec![]), DamageType::ARROW).await);
+
+        let fireproof = || new_painting(false, vec![DamageType::LAVA]);
+        assert!(!hit(fireproof(), DamageType::LAVA).await);
+        assert!(hit(fireproof(), DamageType::ARROW).await);
+
+        // Killing still works
+        assert!(hit(new_painting(true, vec![]), DamageType::GENERIC_KILL).await);
+    }
+
+    #[test]
+    fn default_step_heights() {
+        assert!((Entity::default_step_height(&EntityType::ZOMBIE) - 0.6).abs() < f32::EPSILON);
+        assert!((Entity::default_step_height(&EntityType::HORSE) - 1.0).abs() < f32::EPSILON);
+        assert!(Entity::default_step_height(&EntityType::ITEM).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn mob_walks_onto_slab() {
+        let bounding_box = zombie_box(0.5, 64.0, 0.5);
+        let collisions = [
+            // The floor the zombie is standing on
+            BoundingBox::new_array([-2.0, 63.0, -2.0], [3.0, 64.0, 3.0]),
+            // A bottom slab right in front of it
+            BoundingBox::new_array([1.0, 64.0, 0.0], [2.0, 64.5, 1.0]),
+        ];
+        let movement = Vector3::new(0.3, -0.08, 0.0);
+
+        let collided = Entity::collide_with_shapes(&bounding_box, movement, &collisions);
+        assert!(collided.x < movement.x);
+
+        let stepped = Entity::step_up_movement(&bounding_box, movement, collided, 0.6, &collisions)
+            .expect("zombie should step onto the slab");
+        assert!((stepped.x - movement.x).abs() < 1e-9);
+        assert!((stepped.y - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mob_does_not_step_onto_full_block() {
+        let bounding_box = zombie_box(0.5, 64.0, 0.5);
+        let collisions = [
+            BoundingBox::new_array([-2.0, 63.0, -2.0], [3.0, 64.0, 3.0]),
+            BoundingBox::new_array([1.0, 64.0, 0.0], [2.0, 65.0, 1.0]),
+        ];
+        let movement = Vector3::new(0.3, -0.08, 0.0);
+
+        let collided = Entity::collide_with_shapes(&bounding_box, movement, &collisions);
+        assert!(
+            Entity::step_up_movement(&bounding_box, movement, collided, 0.6, &collisions).is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn checking_collisions_leaves_the_entity_alone() {
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::atomic::Ordering;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let floor = BlockPos::new(0, 299, 0);
+        world
+            .set_block_state(
+                &floor,
+                Block::STONE.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.05, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+
+        let collision = zombie.collide_movement(Vector3::new(0.0, -0.1, 0.0)).await;
+        assert!((collision.movement.y + 0.05).abs() < 1e-9);
+        assert!(collision.on_ground);
+        assert_eq!(collision.supporting_block_pos, Some(floor));
+        assert!(!zombie.on_ground.load(Ordering::Relaxed));
+        assert_eq!(zombie.supporting_block_pos.load(), None);
+
+        zombie
+            .adjust_movement_for_collisions(Vector3::new(0.0, -0.1, 0.0))
+            .await;
+        assert!(zombie.on_ground.load(Ordering::Relaxed));
+        assert_eq!(zombie.supporting_block_pos.load(), Some(floor));
+    }
+
+    #[tokio::test]
+    async fn falling_mob_steps_onto_slab_where_it_lands() {
+        use pumpkin_world::world::BlockFlags;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        for (pos, block) in [
+            (BlockPos::new(0, 299, 0), &Block::STONE),
+            (BlockPos::new(1, 299, 0), &Block::STONE),
+            (BlockPos::new(1, 300, 0), &Block::SMOOTH_STONE_SLAB),
+        ] {
+            world
+                .set_block_state(&pos, block.default_state.id, BlockFlags::FORCE_STATE)
+                .await;
+        }
+        // Just above the floor, walking into the slab
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.05, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+
+        let movement = zombie
+            .adjust_movement_for_collisions(Vector3::new(0.3, -0.1, 0.0))
+            .await;
+        assert!((movement.x - 0.3).abs() < 1e-9);
+        // Lands, then steps up from the floor onto the slab
+        assert!((movement.y - 0.45).abs() < 1e-9);
+        assert!(zombie.on_ground.load(std::sync::atomic::Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn loaded_motion_is_capped_and_nan_becomes_zero() {
+        use super::NBTStorage;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let load_motion = |motion: [f64; 3]| {
+            let mut nbt = NbtCompound::new();
+            nbt.put_list(
+                "Pos",
+                vec![
+                    NbtTag::Double(0.5),
+                    NbtTag::Double(300.0),
+                    NbtTag::Double(0.5),
+                ],
+            );
+            nbt.put_list("Motion", motion.map(NbtTag::Double).to_vec());
+            nbt
+        };
+
+        zombie
+            .read_nbt_non_mut(&load_motion([30.0, 0.0, 40.0]))
+            .await;
+        let velocity = zombie.velocity.load();
+        assert!((velocity.length() - 10.0).abs() < 1e-9);
+        assert!((velocity.x - 6.0).abs() < 1e-9);
+
+        zombie
+            .read_nbt_non_mut(&load_motion([f64::NAN, f64::NAN, f64::NAN]))
+            .await;
+        assert_eq!(zombie.velocity.load(), Vector3::new(0.0, 0.0, 0.0));
+    }
+
+    #[tokio::test]
+    async fn absurd_velocity_is_clamped_before_moving() {
+        use std::sync::Arc;
+
+        use super::physics::{AIR_DRAG, MAX_VELOCITY};
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let zombie = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ));
+        zombie.velocity.store(Vector3::new(1.0e6, 0.0, 0.0));
+
+        zombie.tick(zombie.clone(), &server).await;
+
+        let pos = zombie.pos.load();
+        assert!((pos.x - (0.5 + MAX_VELOCITY)).abs() < 1e-9);
+        assert!((pos.y - 300.0).abs() < 1e-9);
+        assert!((zombie.velocity.load().x - MAX_VELOCITY * AIR_DRAG).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn invalid_loaded_position_falls_back_to_safe_position() {
+        use super::NBTStorage;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        let mut nbt = NbtCompound::new();
+        nbt.put_list(
+            "Pos",
+            vec![
+                NbtTag::Double(f64::NAN),
+                NbtTag::Double(1e300),
+                NbtTag::Double(-f64::MAX),
+            ],
+        );
+
+        zombie.read_nbt_non_mut(&nbt).await;
+
+        let pos = zombie.pos.load();
+        assert_eq!(pos, Vector3::new(0.0, 20_000_000.0, -30_000_000.0));
+        assert!(World::is_valid(pos));
+        // Block and chunk coordinates must still be computable
+        assert_eq!(
+            zombie.block_pos.load(),
+            BlockPos::new(0, 20_000_000, -30_000_000)
+        );
+        assert_eq!(zombie.chunk_pos.load().y, -1_875_000);
+    }
+
+    #[test]
+    fn upward_bubble_column_pushes_entities_up() {
+        let inside = Entity::bubble_column_velocity_y(0.0, false, false, false);
+        assert!((inside - 0.06).abs() < 1e-9);
+        // Capped, except for the stronger push at the surface
+        assert!((Entity::bubble_column_velocity_y(0.7, false, false, false) - 0.7).abs() < 1e-9);
+        assert!((Entity::bubble_column_velocity_y(0.7, false, true, false) - 0.8).abs() < 1e-9);
+        assert!((Entity::bubble_column_velocity_y(0.0, false, true, true) - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn downward_bubble_column_drags_entities_down() {
+        let inside = Entity::bubble_column_velocity_y(0.0, true, false, false);
+        assert!((inside + 0.03).abs() < 1e-9);
+        assert!((Entity::bubble_column_velocity_y(-0.3, true, false, false) + 0.3).abs() < 1e-9);
+        assert!((Entity::bubble_column_velocity_y(-0.9, true, true, false) + 0.9).abs() < 1e-9);
+        assert!((Entity::bubble_column_velocity_y(0.0, true, true, true) + 0.7).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn bounding_box_matches_type_dimensions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_entity = |entity_type| {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                entity_type,
+                false,
+            )
+        };
+
+        let bounding_box = new_entity(&EntityType::ZOMBIE).bounding_box.load();
+        let width = bounding_box.max.x - bounding_box.min.x;
+        let depth = bounding_box.max.z - bounding_box.min.z;
+        let height = bounding_box.max.y - bounding_box.min.y;
+        assert!((width - f64::from(EntityType::ZOMBIE.dimension[0])).abs() < 1e-6);
+        assert!((depth - f64::from(EntityType::ZOMBIE.dimension[0])).abs() < 1e-6);
+        assert!((height - f64::from(EntityType::ZOMBIE.dimension[1])).abs() < 1e-6);
+        assert!((bounding_box.min.y - 300.0).abs() < 1e-6);
+
+        // Markers have no size of their own, but still get a box
+        let bounding_box = new_entity(&EntityType::MARKER).bounding_box.load();
+        assert!(bounding_box.max.x > bounding_box.min.x);
+        assert!(bounding_box.max.y > bounding_box.min.y);
+    }
+
+    #[tokio::test]
+    async fn cobweb_slows_down_movement() {
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+
+        use super::living::LivingEntity;
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        world
+            .set_block_state(
+                &BlockPos::new(0, 300, 0),
+                Block::COBWEB.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        // One walks into the cobweb, the other one walks the same way in the open air
+        let start = |x| Vector3::new(x, 300.0, 0.5);
+        let zombies = [0.5, 10.5].map(|x| {
+            let living = Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                start(x),
+                &EntityType::ZOMBIE,
+                false,
+            )));
+            living.entity.velocity.store(Vector3::new(0.3, 0.0, 0.0));
+            living
+        });
+        for _ in 0..3 {
+            for zombie in &zombies {
+                zombie.tick(zombie.clone(), &server).await;
+            }
+        }
+
+        let moved = |index: usize| {
+            zombies[index]
+                .entity
+                .pos
+                .load()
+                .sub(&start([0.5, 10.5][index]))
+        };
+        let (in_cobweb, in_air) = (moved(0), moved(1));
+        assert!(in_cobweb.x > 0.0);
+        assert!(in_cobweb.x < in_air.x);
+        // Falling is slowed down even more
+        assert!(in_cobweb.y < 0.0);
+        assert!(in_cobweb.y.abs() * 2.0 < in_air.y.abs());
+    }
+
+    #[tokio::test]
+    async fn falling_along_honey_wall_is_clamped() {
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+
+        use super::living::LivingEntity;
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        world
+            .set_block_state(
+                &BlockPos::new(0, 300, 0),
+                Block::HONEY_BLOCK.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        // One falls along the west side of the honey block, the other one in the open air
+        let zombies = [-0.25, 10.5].map(|x| {
+            let living = Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.2, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )));
+            living.entity.velocity.store(Vector3::new(0.0, -0.6, 0.0));
+            living.fall_distance.store(3.0);
+            living
+        });
+        for zombie in &zombies {
+            zombie.tick(zombie.clone(), &server).await;
+        }
+
+        let [sliding, falling] = &zombies;
+        assert!((sliding.entity.velocity.load().y - HoneyBlock::SLIDE_VELOCITY).abs() < 1e-9);
+        assert!(sliding.fall_distance.load() < f32::EPSILON);
+        assert!(falling.entity.velocity.load().y < -0.6);
+    }
+
+    #[tokio::test]
+    async fn soul_speed_cancels_soul_sand_slowdown() {
+        use pumpkin_data::Enchantment;
+        use pumpkin_data::data_component_impl::EquipmentSlot;
+        use pumpkin_data::item::Item;
+        use pumpkin_world::item::ItemStack;
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering;
+
+        use super::living::LivingEntity;
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let mut zombies = Vec::new();
+        for (x, soul_speed) in [(0, true), (10, false)] {
+            world
+                .set_block_state(
+                    &BlockPos::new(x, 299, 0),
+                    Block::SOUL_SAND.default_state.id,
+                    BlockFlags::FORCE_STATE,
+                )
+                .await;
+            let zombie = Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(f64::from(x) + 0.3, 299.875, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )));
+            if soul_speed {
+                let mut boots = ItemStack::new(1, &Item::DIAMOND_BOOTS);
+                boots.enchant(&Enchantment::SOUL_SPEED, 3);
+                let equipment = zombie.entity_equipment.lock().await;
+                *equipment.get(&EquipmentSlot::FEET).lock().await = boots;
+            }
+            for _ in 0..3 {
+                zombie.tick(zombie.clone(), &server).await;
+            }
+            assert!(zombie.entity.on_ground.load(Ordering::SeqCst));
+            zombie.entity.velocity.store(Vector3::new(0.1, 0.0, 0.0));
+            zombie.tick(zombie.clone(), &server).await;
+            zombies.push(zombie);
+        }
+
+        // Soul sand is as slippery as most blocks, but slows down to 40% without Soul Speed
+        let friction = 0.6 * 0.91;
+        let velocity = |index: usize| zombies[index].entity.velocity.load().x;
+        assert!((velocity(0) - 0.1 * friction).abs() < 1e-6);
+        assert!((velocity(1) - 0.1 * 0.4 * friction).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn spectators_are_not_teleported_by_portals() {
+        use std::sync::atomic::Ordering;
+
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        world
+            .set_block_state(
+                &BlockPos::new(0, 300, 0),
+                Block::NETHER_PORTAL.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        let new_zombie = || {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )
+        };
+
+        let zombie = new_zombie();
+        Entity::check_block_collision(&zombie, &server).await;
+        assert!(zombie.portal_manager.lock().await.is_some());
+
+        // Like spectators, entities without collision pass right through
+        let no_clip = new_zombie();
+        no_clip.no_clip.store(true, Ordering::Relaxed);
+        Entity::check_block_collision(&no_clip, &server).await;
+        assert!(no_clip.portal_manager.lock().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn portal_immune_types_stay_in_the_portal() {
+        use pumpkin_util::math::position::BlockPos;
+        use pumpkin_world::world::BlockFlags;
+
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        world
+            .set_block_state(
+                &BlockPos::new(0, 300, 0),
+                Block::NETHER_PORTAL.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        let in_portal = |entity_type| {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(0.5, 300.0, 0.5),
+                entity_type,
+                false,
+            )
+        };
+
+        for entity_type in [&EntityType::ENDER_DRAGON, &EntityType::ITEM_FRAME] {
+            let entity = in_portal(entity_type);
+            Entity::check_block_collision(&entity, &server).await;
+            assert!(entity.portal_manager.lock().await.is_none());
+        }
+
+        let item = in_portal(&EntityType::ITEM);
+        Entity::check_block_collision(&item, &server).await;
+        assert!(item.portal_manager.lock().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn only_passed_interactions_use_the_held_item() {
+        use pumpkin_data::data_component::DataComponent;
+        use pumpkin_data::data_component_impl::{CustomNameImpl, DataComponentImpl};
+        use pumpkin_data::item::Item;
+        use pumpkin_protocol::codec::var_int::VarInt;
+        use pumpkin_protocol::java::server::play::SInteract;
+        use pumpkin_util::GameMode;
+        use pumpkin_world::item::ItemStack;
+
+        use super::{EntityBase, player::Player, r#type::spawn_from_type};
+        use crate::net::ClientPlatform;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let (player, _packets) = Player::for_test(&world, GameMode::Survival).await;
+        player.set_client_loaded(true);
+        let ClientPlatform::Java(client) = &player.client else {
+            unreachable!("test players connect through the Java protocol");
+        };
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        // A right click with the main hand
+        let interact = || SInteract {
+            entity_id: VarInt(zombie.get_entity().entity_id),
+            r#type: VarInt(0),
+            target_position: None,
+            hand: Some(VarInt(0)),
+            sneaking: false,
+        };
+
+        // The zombie passes on name tags that weren't renamed, which have no use of their own
+        let held = player.inventory().held_item();
+        *held.lock().await = ItemStack::new(2, &Item::NAME_TAG);
+        client.handle_interact(&player, interact(), &server).await;
+        assert_eq!(held.lock().await.item_count, 2);
+        assert!(zombie.get_entity().custom_name.lock().unwrap().is_none());
+
+        held.lock().await.patch.push((
+            DataComponent::CustomName,
+            Some(CustomNameImpl { name: "Dinnerbone" }.to_dyn()),
+        ));
+        client.handle_interact(&player, interact(), &server).await;
+        assert_eq!(held.lock().await.item_count, 1);
+        assert!(zombie.get_entity().custom_name.lock().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn looking_at_an_entity_to_the_east() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let new_zombie = |x| {
+            let pos = Vector3::new(x, 64.0, 0.5);
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                pos,
+                &EntityType::ZOMBIE,
+                false,
+            )
+        };
+        let zombie = new_zombie(0.5);
+        let target = new_zombie(10.5);
+
+        // The head turns at most 10 degrees per tick, the body doesn't follow right away
+        assert!(!zombie.look_at_entity_gradually(&target, 10.0, 40.0));
+        assert!((zombie.head_yaw.load() + 10.0).abs() < 1e-4);
+        assert!(zombie.yaw.load().abs() < 1e-4);
+        assert!(zombie.body_yaw.load().abs() < 1e-4);
+
+        // East is -90 degrees, both eyes are at the same height so it looks straight ahead
+        zombie.look_at_entity(&target).await;
+        assert!((zombie.yaw.load() + 90.0).abs() < 1e-4);
+        assert!(zombie.pitch.load().abs() < 1e-4);
+        assert!(zombie.look_at_entity_gradually(&target, 10.0, 40.0));
+    }
+
+    #[tokio::test]
+    async fn looking_at_the_same_spot_is_broadcast_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 64.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+
+        let target = Vector3::new(10.5, 70.0, 3.5);
+        assert!(zombie.look_at(target).await);
+        for _ in 0..5 {
+            assert!(!zombie.look_at(target).await);
+        }
+        // Too small of a change to show on clients
+        assert!(
+            !zombie
+                .look_at(target.add(&Vector3::new(0.0, 0.0, 0.001)))
+                .await
+        );
+        assert!(zombie.look_at(Vector3::new(-10.5, 64.0, 0.5)).await);
+    }
+
+    #[tokio::test]
+    async fn walls_block_the_line_of_sight() {
+        use pumpkin_world::world::BlockFlags;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // High up in the air, away from any terrain
+        let new_zombie = |x| {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )
+        };
+        let zombie = new_zombie(0.5);
+        let target = new_zombie(10.5);
+        assert!(zombie.can_see(&target).await);
+
+        let wall = BlockPos::new(5, 301, 0);
+        world
+            .set_block_state(
+                &wall,
+                Block::STONE.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        assert_eq!(
+            world
+                .raycast_blocks(zombie.get_eye_pos(), target.get_eye_pos())
+                .await,
+            Some(wall)
+        );
+        assert!(!zombie.can_see(&target).await);
+        assert!(!target.can_see(&zombie).await);
+
+        // Glass is see-through for players, but not for mobs
+        world
+            .set_block_state(
+                &wall,
+                Block::GLASS.default_state.id,
+                BlockFlags::FORCE_STATE,
+            )
+            .await;
+        assert!(!zombie.can_see(&target).await);
+        world
+            .set_block_state(&wall, Block::AIR.default_state.id, BlockFlags::FORCE_STATE)
+            .await;
+        assert!(zombie.can_see(&target).await);
+    }
+
+    #[tokio::test]
+    async fn teleporting_resets_velocity() {
+        use std::sync::atomic::Ordering;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let start = Vector3::new(0.5, 64.0, 0.5);
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            start,
+            &EntityType::TNT,
+            false,
+        );
+        // e.g. knocked back right before
+        let knockback = Vector3::new(1.0, 0.5, 0.0);
+        entity.velocity.store(knockback);
+        entity.velocity_dirty.store(true, Ordering::SeqCst);
+
+        let target = Vector3::new(100.5, 70.0, -20.5);
+        entity
+            .teleport(target, None, None, world.clone(), false)
+            .await;
+        assert_eq!(entity.pos.load(), target);
+        assert_eq!(entity.velocity.load(), Vector3::new(0.0, 0.0, 0.0));
+        // Already sent with the position
+        assert!(!Entity::take_dirty(&entity.velocity_dirty));
+        assert!(entity.take_pos_delta().is_none());
+
+        // Portals keep the momentum
+        entity.velocity.store(knockback);
+        entity.teleport(start, None, None, world, true).await;
+        assert_eq!(entity.velocity.load(), knockback);
+    }
+
+    #[tokio::test]
+    async fn velocity_is_only_sent_when_dirty() {
+        use pumpkin_protocol::java::client::play::CEntityVelocity;
+        use pumpkin_protocol::packet::Packet;
+        use pumpkin_util::GameMode;
+        use std::sync::atomic::Ordering;
+
+        use crate::entity::{EntityBase, player::Player, r#type::spawn_from_type};
+        use crate::net::java::JavaClient;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let (_player, mut packets) = Player::for_test(&world, GameMode::Survival).await;
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        let zombie = zombie.get_entity();
+        zombie.send_velocity_if_dirty().await;
+        let mut velocity_updates = || {
+            JavaClient::queued_packet_ids(&mut packets)
+                .into_iter()
+                .filter(|&id| id == CEntityVelocity::PACKET_ID)
+                .count()
+        };
+        velocity_updates();
+
+        zombie.send_velocity_if_dirty().await;
+        assert_eq!(velocity_updates(), 0);
+
+        // e.g. knockback
+        zombie.apply_knockback(0.4, 1.0, 0.0);
+        zombie.send_velocity_if_dirty().await;
+        assert_eq!(velocity_updates(), 1);
+        assert!(!zombie.velocity_dirty.load(Ordering::SeqCst));
+        // Sent once, until it changes again
+        zombie.send_velocity_if_dirty().await;
+        assert_eq!(velocity_updates(), 0);
+
+        zombie.set_velocity(Vector3::new(0.0, 0.5, 0.0)).await;
+        assert_eq!(velocity_updates(), 1);
+        assert!(!zombie.velocity_dirty.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn tiny_movements_are_not_sent() {
+        let old = Vector3::new(0.5, 64.0, 0.5);
+        assert!(Entity::pos_delta(old, Vector3::new(0.5, 64.0001, 0.5)).is_none());
+        assert_eq!(
+            Entity::pos_delta(old, Vector3::new(0.5, 64.5, 0.5)),
+            Some(Vector3::new(0, 2048, 0))
+        );
+    }
+
+    #[tokio::test]
+    async fn naming_sends_name_and_visibility() {
+        use pumpkin_protocol::codec::var_int::VarInt;
+        use pumpkin_protocol::java::client::play::CSetEntityMetadata;
+        use pumpkin_protocol::packet::Packet;
+        use pumpkin_util::GameMode;
+
+        use crate::entity::{EntityBase, player::Player, r#type::spawn_from_type};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let (_player, mut packets) = Player::for_test(&world, GameMode::Survival).await;
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        while packets.try_recv().is_ok() {}
+
+        zombie
+            .get_entity()
+            .set_custom_name(TextComponent::text("Bob"))
+            .await;
+
+        let updates: Vec<_> = std::iter::from_fn(|| packets.try_recv().ok())
+            .filter_map(|packet| {
+                let mut buf = &packet[..];
+                (VarInt::decode(&mut buf).unwrap().0 == CSetEntityMetadata::PACKET_ID).then(|| {
+                    let entity_id = VarInt::decode(&mut buf).unwrap().0;
+                    (entity_id, buf.to_vec())
+                })
+            })
+            .collect();
+        assert_eq!(updates.len(), 1);
+        let (entity_id, metadata) = &updates[0];
+        assert_eq!(*entity_id, zombie.get_entity().entity_id);
+        // Custom name entry first
+        assert_eq!(
+            metadata[..2],
+            [2, MetaDataType::OptionalTextComponent as u8]
+        );
+        // Followed by the visibility flag, even though it didn't change
+        assert_eq!(
+            metadata[metadata.len() - 4..],
+            [3, MetaDataType::Boolean as u8, 0, 255]
+        );
+    }
+
+    #[tokio::test]
+    async fn name_visibility_is_sent_to_new_viewers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        zombie.init_tracked_data();
+
+        zombie.set_custom_name_visible(true).await;
+
+        let metadata = zombie.tracked_data_packet().unwrap().metadata;
+        assert!(
+            metadata
+                .windows(3)
+                .any(|entry| entry == [3, MetaDataType::Boolean as u8, 1])
+        );
+    }
+
+    #[tokio::test]
+    async fn hanging_entities_have_offset_bounding_box() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let position = Vector3::new(0.5, 300.5, 0.0);
+        let new_entity = |entity_type| {
+            Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                position,
+                entity_type,
+                false,
+            )
+        };
+
+        // Centered on the position vertically instead of standing on it
+        let item_frame = new_entity(&EntityType::ITEM_FRAME).bounding_box.load();
+        let center_y = f64::midpoint(item_frame.min.y, item_frame.max.y);
+        assert!((center_y - position.y).abs() < 1e-6);
+        assert!(item_frame.min.y < position.y);
+        assert!((f64::midpoint(item_frame.min.x, item_frame.max.x) - position.x).abs() < 1e-6);
+
+        let zombie = new_entity(&EntityType::ZOMBIE).bounding_box.load();
+        assert!((zombie.min.y - position.y).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn gradual_look_turns_over_multiple_ticks() {
+        use super::mob::{Mob, zombie::Zombie};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        let mob_entity = zombie.get_mob_entity();
+        let entity = &mob_entity.living_entity.entity;
+        // 45 degrees to the left, at the height of the eyes
+        let target = entity.get_eye_pos().add(&Vector3::new(-5.0, 0.0, 5.0));
+        let wanted = Entity::rotation_towards(entity.get_eye_pos(), target);
+        assert!((wanted.0 - 45.0).abs() < 1e-3);
+
+        // Zombies turn their head 10 degrees per tick
+        let look_control = mob_entity.look_control.lock().await;
+        for head_yaw in [10.0, 20.0, 30.0, 40.0] {
+            look_control.look_at_position(zombie.as_ref(), target);
+            look_control.tick(zombie.as_ref()).await;
+            assert!((entity.head_yaw.load() - head_yaw).abs() < 1e-3);
+        }
+        look_control.look_at_position(zombie.as_ref(), target);
+        look_control.tick(zombie.as_ref()).await;
+        assert!(subtract_angles(entity.head_yaw.load(), wanted.0).abs() < 1e-3);
+    }
+
+    #[tokio::test]
+    async fn zombie_keeps_burning_after_lava() {
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering::Relaxed;
+
+        use super::LAVA_DAMAGE;
+        use super::living::LivingEntity;
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        for (pos, block) in [
+            (BlockPos::new(0, 300, 0), &Block::LAVA),
+            (BlockPos::new(10, 299, 0), &Block::STONE),
+        ] {
+            world
+                .set_block_state(&pos, block.default_state.id, BlockFlags::FORCE_STATE)
+                .await;
+        }
+        let zombie = Arc::new(LivingEntity::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        )));
+        let health = zombie.health.load();
+
+        zombie.tick(zombie.clone(), &server).await;
+        let lava_ticks = (LAVA_FIRE_SECONDS * 20.0) as i32;
+        assert_eq!(zombie.entity.fire_ticks.load(Relaxed), lava_ticks);
+        assert!((health - zombie.health.load() - LAVA_DAMAGE).abs() < f32::EPSILON);
+
+        // Out of the lava, the fire keeps burning and hurting it
+        let health = zombie.health.load();
+        zombie.entity.set_pos(Vector3::new(10.5, 300.0, 0.5));
+        for _ in 0..30 {
+            zombie.tick(zombie.clone(), &server).await;
+        }
+        assert!(!zombie.entity.touching_lava.load(Relaxed));
+        assert!(zombie.entity.fire_ticks.load(Relaxed) > 0);
+        assert!(zombie.health.load() < health);
+    }
+
+    #[tokio::test]
+    async fn friction_comes_from_supporting_block() {
+        use pumpkin_world::world::BlockFlags;
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering;
+
+        use super::living::LivingEntity;
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let ice = BlockPos::new(1, 299, 0);
+        world
+            .set_block_state(&ice, Block::ICE.default_state.id, BlockFlags::FORCE_STATE)
+            .await;
+        // Standing at the edge of the ice block, with air below the zombie's center
+        let zombie = Arc::new(LivingEntity::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.9, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        )));
+        for _ in 0..3 {
+            zombie.tick(zombie.clone(), &server).await;
+        }
+        assert!(zombie.entity.on_ground.load(Ordering::SeqCst));
+        assert_eq!(zombie.entity.supporting_block_pos.load(), Some(ice));
+        assert!(
+            world
+                .get_block_state(&zombie.entity.block_pos.load())
+                .await
+                .is_air()
+        );
+
+        zombie.entity.velocity.store(Vector3::new(0.1, 0.0, 0.0));
+        zombie.tick(zombie.clone(), &server).await;
+
+        let velocity = zombie.entity.velocity.load();
+        assert!((velocity.x - 0.1 * 0.98 * 0.91).abs() < 1e-6);
+        assert!(velocity.x > 0.1 * 0.6 * 0.91);
+    }
+
+    #[tokio::test]
+    async fn fire_damage_is_credited_to_the_attacker() {
+        use std::sync::Arc;
+        use std::sync::atomic::Ordering;
+
+        use crate::entity::EntityBase;
+        use crate::entity::living::LivingEntity;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let new_zombie = |x| {
+            Arc::new(LivingEntity::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.0, 0.5),
+                &EntityType::ZOMBIE,
+                false,
+            )))
+        };
+        let attacker = new_zombie(1.5);
+        world
+            .spawn_entity(attacker.clone() as Arc<dyn EntityBase>)
+            .await;
+        let zombie = new_zombie(0.5);
+        let health = zombie.health.load();
+
+        // Fire Aspect I burns for 4 seconds
+        zombie.entity.set_on_fire_by(4.0, &attacker.entity).await;
+        while zombie.entity.fire_ticks.load(Ordering::Relaxed) > 0 {
+            assert_eq!(
+                zombie.entity.fire_attacker.load(),
+                Some(attacker.entity.entity_id)
+            );
+            zombie.entity.base_tick(zombie.clone(), &server).await;
+        }
+        assert!((health - zombie.health.load() - 4.0).abs() < f32::EPSILON);
+        assert_eq!(zombie.entity.fire_attacker.load(), None);
+    }
+
+    #[tokio::test]
+    async fn catching_fire_thaws_frozen_entities() {
+        use std::sync::atomic::Ordering;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+        zombie
+            .frozen_ticks
+            .store(MIN_FREEZE_DAMAGE_TICKS, Ordering::Relaxed);
+
+        zombie.set_on_fire_for_ticks(160).await;
+
+        assert_eq!(zombie.frozen_ticks.load(Ordering::Relaxed), 0);
+        assert_eq!(zombie.fire_ticks.load(Ordering::Relaxed), 160);
+    }
+
+    #[tokio::test]
+    async fn unchanged_flags_are_not_broadcast() {
+        use pumpkin_protocol::java::client::play::CSetEntityMetadata;
+        use pumpkin_protocol::packet::Packet;
+        use pumpkin_util::GameMode;
+
+        use crate::entity::{EntityBase, player::Player, r#type::spawn_from_type};
+        use crate::net::java::JavaClient;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let (_player, mut packets) = Player::for_test(&world, GameMode::Survival).await;
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(zombie.clone()).await;
+        let zombie = zombie.get_entity();
+        zombie.set_fall_flying(true).await;
+        JavaClient::queued_packet_ids(&mut packets);
+
+        zombie.set_fall_flying(false).await;
+        zombie.set_fall_flying(false).await;
+
+        let updates = JavaClient::queued_packet_ids(&mut packets)
+            .into_iter()
+            .filter(|&id| id == CSetEntityMetadata::PACKET_ID)
+            .count();
+        assert_eq!(updates, 1);
+    }
+
+    #[tokio::test]
+    async fn moving_entities_slow_down_until_stopped() {
+        use std::sync::Arc;
+
+        use crate::entity::EntityBase;
+        use crate::server::Server;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let world = server.worlds.read().await[0].clone();
+        let entity = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::SNOWBALL,
+            false,
+        ));
+        entity.velocity.store(Vector3::new(0.2, 0.0, -0.1));
+
+        for _ in 0..3 {
+            entity.tick(entity.clone(), &server).await;
+        }
+        // The air takes 2% of the speed every tick
+        let pos = entity.pos.load();
+        assert!((pos.x - (0.5 + 0.2 * (1.0 + 0.98 + 0.98 * 0.98))).abs() < 1.0e-9);
+        assert!((pos.z - (0.5 - 0.1 * (1.0 + 0.98 + 0.98 * 0.98))).abs() < 1.0e-9);
+        assert!((entity.velocity.load().x - 0.2 * 0.98 * 0.98 * 0.98).abs() < 1.0e-9);
+
+        // Resting entities stay where they are
+        entity.velocity.store(Vector3::default());
+        entity.tick(entity.clone(), &server).await;
+        assert_eq!(entity.pos.load(), pos);
+    }
+
+    #[tokio::test]
+    async fn mobs_cannot_walk_through_boats() {
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let spawn = async |x, entity_type| {
+            let entity = Arc::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                Vector3::new(x, 300.0, 0.5),
+                entity_type,
+                false,
+            ));
+            world.spawn_entity(entity.clone()).await;
+            entity
+        };
+        let boat = spawn(2.0, &EntityType::OAK_BOAT).await;
+        // Other mobs are pushed aside instead
+        spawn(1.5, &EntityType::ZOMBIE).await;
+        let zombie = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        );
+
+        // Walks into the side of the boat and stops there
+        let movement = zombie
+            .adjust_movement_for_collisions(Vector3::new(1.0, 0.0, 0.0))
+            .await;
+        let expected = boat.bounding_box.load().min.x - zombie.bounding_box.load().max.x;
+        assert!((movement.x - expected).abs() < 1.0e-7);
+
+        // The boat doesn't get in its own way
+        let movement = boat
+            .adjust_movement_for_collisions(Vector3::new(0.0, 0.0, 1.0))
+            .await;
+        assert!((movement.z - 1.0).abs() < 1.0e-7);
+    }
+
+    #[test]
+    fn entity_in_waterlogged_stair_touches_water() {
+        use pumpkin_data::block_properties::{BlockProperties, OakStairsLikeProperties};
+        use pumpkin_data::fluid::Fluid;
+
+        let mut props = OakStairsLikeProperties::default(&Block::OAK_STAIRS);
+        props.waterlogged = true;
+        let (fluid, state) =
+            World::fluid_and_fluid_state_from_id(props.to_state_id(&Block::OAK_STAIRS));
+        assert_eq!(fluid.id, Fluid::WATER.id);
+
+        // Standing on the bottom half of the stair
+        let bounding_box = zombie_box(0.5, 64.5, 0.5);
+        assert!(Entity::submerged_height(&state, 64, bounding_box.min.y).is_some());
+
+        props.waterlogged = false;
+        let (fluid, _) =
+            World::fluid_and_fluid_state_from_id(props.to_state_id(&Block::OAK_STAIRS));
+        assert_eq!(fluid.id, Fluid::EMPTY.id);
+    }
+
+    #[tokio::test]
+    async fn only_living_entities_have_attributes() {
+        use pumpkin_data::attributes::Attributes;
+
+        use super::EntityBase;
+        use crate::entity::mob::zombie::Zombie;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 300.0, 0.5);
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            pos,
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        let attributes = zombie.get_attribute_manager().unwrap();
+        assert!(attributes.has_attribute(&Attributes::FOLLOW_RANGE));
+        assert!((attributes.get_base(&Attributes::MAX_HEALTH) - 20.0).abs() < f64::EPSILON);
+
+        let snowball = Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            pos,
+            &EntityType::SNOWBALL,
+            false,
+        );
+        assert!(snowball.get_attribute_manager().is_none());
+    }
+}
@@ -0,0 +1,46 @@
+//! Constants shared by entity movement, matching vanilla.
+
+/// Positions in relative move packets are sent in 1/4096ths of a block.
+pub const POSITION_DELTA_SCALE: f64 = 4096.0;
+
+/// Gravity of living entities, in blocks per tick squared.
+pub const LIVING_GRAVITY: f64 = 0.08;
+
+/// Velocity kept each tick in the air, vertically and for most non-living entities.
+pub const AIR_DRAG: f64 = 0.98;
+/// Horizontal velocity kept each tick by a living entity in the air.
+pub const AIR_FRICTION: f64 = 0.91;
+/// Scales the slipperiness of the block below into the horizontal friction on ground.
+pub const GROUND_FRICTION: f64 = 0.91;
+/// Ground movement speed is `movement_speed * GROUND_SPEED_FACTOR / slipperiness³`.
+pub const GROUND_SPEED_FACTOR: f64 = 0.216;
+/// Movement speed in the air and in fluids, before modifiers.
+pub const OFF_GROUND_SPEED: f64 = 0.02;
+/// Movement input decays by this every tick.
+pub const MOVEMENT_INPUT_DECAY: f64 = 0.98;
+
+/// Vertical velocity kept each tick when swimming, or in shallow lava.
+pub const FLUID_VERTICAL_DRAG: f64 = 0.8;
+/// Velocity kept each tick in lava.
+pub const LAVA_DRAG: f64 = 0.5;
+/// Upwards velocity added each tick while swimming up.
+pub const SWIM_UP_SPEED: f64 = 0.04;
+
+/// Living entities in a fluid feel this fraction of their gravity, buoyancy carries the rest.
+pub const FLUID_GRAVITY_FACTOR: f64 = 1.0 / 16.0;
+/// Upwards velocity added each tick to items and experience orbs floating in a fluid.
+pub const FLOAT_SPEED: f64 = 5.0e-4;
+/// Floating items and experience orbs stop speeding up once rising this fast.
+pub const MAX_FLOAT_SPEED: f64 = 0.06;
+/// Upwards velocity added each tick to submerged aquatic mobs, which feel no gravity in water.
+pub const AQUATIC_BUOYANCY: f64 = 0.005;
+
+/// Speed at which flowing water pushes entities.
+pub const WATER_PUSH_SPEED: f64 = 0.014;
+/// Speed at which flowing lava pushes entities in the overworld.
+pub const LAVA_PUSH_SPEED: f64 = 0.002_333_333;
+/// Speed at which flowing lava pushes entities in the nether, where it flows faster.
+pub const NETHER_LAVA_PUSH_SPEED: f64 = 0.007;
+
+/// Entities never move faster than this many blocks per tick.
+pub const MAX_VELOCITY: f64 = 10.0;
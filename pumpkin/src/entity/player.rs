@@ -23,15 +23,17 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use pumpkin_config::{BASIC_CONFIG, advanced_config};
+use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DamageType;
-use pumpkin_data::data_component_impl::{AttributeModifiersImpl, Operation};
+use pumpkin_data::data_component_impl::AttributeModifiersImpl;
 use pumpkin_data::data_component_impl::{EquipmentSlot, EquippableImpl};
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
 use pumpkin_data::particle::Particle;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::tag::Taggable;
-use pumpkin_data::{Block, BlockState, tag};
+use pumpkin_data::world::WorldEvent;
+use pumpkin_data::{Block, BlockState, Enchantment, tag};
 use pumpkin_inventory::player::{
     player_inventory::PlayerInventory, player_screen_handler::PlayerScreenHandler,
 };
@@ -53,7 +55,7 @@ use pumpkin_protocol::java::client::play::{
     CSetContainerContent, CSetContainerProperty, CSetContainerSlot, CSetCursorItem, CSetEquipment,
     CSetExperience, CSetHealth, CSetPlayerInventory, CSetSelectedSlot, CSoundEffect, CStopSound,
     CSubtitle, CSystemChatMessage, CTitleText, CUnloadChunk, CUpdateMobEffect, CUpdateTime,
-    GameEvent, MetaDataType, Metadata, PlayerAction, PlayerInfoFlags, PreviousMessage,
+    CWorldEvent, GameEvent, MetaDataType, Metadata, PlayerAction, PlayerInfoFlags, PreviousMessage,
 };
 use pumpkin_protocol::java::server::play::SClickSlot;
 use pumpkin_registry::VanillaDimensionType;
@@ -67,6 +69,7 @@ use pumpkin_util::text::click::ClickEvent;
 use pumpkin_util::text::hover::HoverEvent;
 use pumpkin_util::{GameMode, Hand};
 use pumpkin_world::biome;
+use pumpkin_world::block::entities::jukebox::JukeboxBlockEntity;
 use pumpkin_world::cylindrical_chunk_iterator::Cylindrical;
 use pumpkin_world::entity::entity_data_flags::{
     DATA_PLAYER_MAIN_HAND, DATA_PLAYER_MODE_CUSTOMISATION, SLEEPING_POS_ID,
@@ -75,6 +78,7 @@ use pumpkin_world::item::ItemStack;
 use pumpkin_world::level::{SyncChunk, SyncEntityChunk};
 
 use crate::block::blocks::bed::BedBlock;
+use crate::block::blocks::jukebox::JukeboxBlock;
 use crate::command::client_suggestions;
 use crate::command::dispatcher::CommandDispatcher;
 use crate::data::op_data::OPERATOR_CONFIG;
@@ -89,7 +93,7 @@ use crate::{PERMISSION_MANAGER, block};
 
 use super::combat::{self, AttackType, player_attack_sound};
 use super::hunger::HungerManager;
-use super::item::ItemEntity;
+use super::item::{ItemEntity, THROWN_PICKUP_DELAY};
 use super::living::LivingEntity;
 use super::{Entity, EntityBase, NBTStorage, NBTStorageInit};
 use pumpkin_data::potion::Effect;
@@ -387,6 +391,45 @@ impl Player {
         }
     }
 
+    /// A `gamemode` player in `world`, connected from a local socket. Also returns the
+    /// queue of packets sent to it.
+    #[cfg(test)]
+    pub async fn for_test(
+        world: &Arc<World>,
+        gamemode: GameMode,
+    ) -> (Arc<Self>, tokio::sync::mpsc::Receiver<bytes::Bytes>) {
+        use crate::net::java::JavaClient;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let _connection = tokio::net::TcpStream::connect(address).await.unwrap();
+        let (stream, address) = listener.accept().await.unwrap();
+        let mut client = JavaClient::new(stream, address, 0);
+        let packets = client.take_outgoing_packets();
+        let profile = GameProfile {
+            id: uuid::Uuid::new_v4(),
+            name: "Steve".to_string(),
+            properties: Vec::new(),
+            profile_actions: None,
+        };
+        let player = Arc::new(
+            Self::new(
+                ClientPlatform::Java(Arc::new(client)),
+                profile,
+                PlayerConfig::default(),
+                world.clone(),
+                gamemode,
+            )
+            .await,
+        );
+        world
+            .players
+            .write()
+            .await
+            .insert(player.gameprofile.id, player.clone());
+        (player, packets)
+    }
+
     /// Spawns a task associated with this player-client. All tasks spawned with this method are awaited
     /// when the client. This means tasks should complete in a reasonable amount of time or select
     /// on `Self::await_close_interrupt` to cancel the task when the client is closed
@@ -452,51 +495,41 @@ impl Player {
         let inventory = self.inventory();
         let item_stack = inventory.held_item();
 
-        let base_damage = 1.0;
-        let base_attack_speed = 4.0;
-
-        let mut damage_multiplier = 1.0;
-        let mut add_damage = 0.0;
-        let mut add_speed = 0.0;
+        let attributes = &self.living_entity.attribute_manager;
+        let mut damage_modifiers = Vec::new();
+        let mut speed_modifiers = Vec::new();
 
-        // Get the attack damage
-        // TODO: this should be cached in memory, we shouldn't just use default here either
+        // Get the attack damage and speed from the held item
+        // TODO: this should be cached in memory
         if let Some(modifiers) = item_stack
             .lock()
             .await
             .get_data_component::<AttributeModifiersImpl>()
         {
             for item_mod in modifiers.attribute_modifiers.iter() {
-                if item_mod.operation == Operation::AddValue {
-                    if item_mod.id == "minecraft:base_attack_damage" {
-                        add_damage = item_mod.amount;
-                    } else if item_mod.id == "minecraft:base_attack_speed" {
-                        add_speed = item_mod.amount;
-                    }
+                if item_mod.r#type == &Attributes::ATTACK_DAMAGE {
+                    damage_modifiers.push((item_mod.amount, item_mod.operation));
+                } else if item_mod.r#type == &Attributes::ATTACK_SPEED {
+                    speed_modifiers.push((item_mod.amount, item_mod.operation));
                 }
             }
         }
 
-        let attack_speed = base_attack_speed + add_speed;
+        let attack_speed = attributes.get_modified(&Attributes::ATTACK_SPEED, speed_modifiers);
 
         let attack_cooldown_progress = self.get_attack_cooldown_progress(0.5, attack_speed);
         self.last_attacked_ticks.store(0, Ordering::Relaxed);
 
         // Only reduce attack damage if in cooldown
         // TODO: Enchantments are reduced in the same way, just without the square.
-        if attack_cooldown_progress < 1.0 {
-            damage_multiplier = 0.2 + attack_cooldown_progress.powi(2) * 0.8;
-        }
-        // Modify the added damage based on the multiplier.
-        let mut damage = base_damage + add_damage * damage_multiplier;
+        let mut damage = attributes.get_modified(&Attributes::ATTACK_DAMAGE, damage_modifiers)
+            * combat::cooldown_damage_multiplier(attack_cooldown_progress);
 
         let pos = victim_entity.pos.load();
 
         let attack_type = AttackType::new(self, attack_cooldown_progress as f32).await;
 
-        if matches!(attack_type, AttackType::Critical) {
-            damage *= 1.5;
-        }
+        damage *= attack_type.damage_multiplier();
 
         if !victim
             .damage_with_context(
@@ -522,14 +555,32 @@ impl Player {
         if victim.get_living_entity().is_some() {
             let mut knockback_strength = 1.0;
             player_attack_sound(&pos, world, attack_type).await;
+            if attack_type.spawns_crit_particles() {
+                combat::spawn_crit_particles(world, victim_entity).await;
+            }
             match attack_type {
                 AttackType::Knockback => knockback_strength += 1.0,
                 AttackType::Sweeping => {
+                    let sweeping_level = item_stack
+                        .lock()
+                        .await
+                        .get_enchantment_level(&Enchantment::SWEEPING_EDGE);
+                    combat::sweep_attack(self, world, victim_entity, damage, sweeping_level).await;
                     combat::spawn_sweep_particle(attacker_entity, world, &pos).await;
                 }
                 _ => {}
             }
-            if config.knockback {
+            let fire_aspect_level = item_stack
+                .lock()
+                .await
+                .get_enchantment_level(&Enchantment::FIRE_ASPECT);
+            if fire_aspect_level > 0 {
+                victim_entity
+                    .set_on_fire_by(4.0 * fire_aspect_level as f32, attacker_entity)
+                    .await;
+            }
+            // Weak attacks don't knock the victim back
+            if config.knockback && attack_type != AttackType::Weak {
                 combat::handle_knockback(attacker_entity, world, victim_entity, knockback_strength)
                     .await;
             }
@@ -784,6 +835,7 @@ impl Player {
                         // TODO: Can we check if we still need to send the chunk? Like if it's a fast moving
                         // player or something.
                         java_client.send_packet_now(&CChunkData(&chunk)).await;
+                        self.send_playing_jukeboxes(&chunk).await;
                     }
                     java_client
                         .send_packet_now(&CChunkBatchEnd::new(chunk_count as u16))
@@ -925,6 +977,47 @@ impl Player {
         self.client_loaded.store(loaded, Ordering::Relaxed);
     }
 
+    /// Lets the player hear the songs of jukeboxes which started playing before the chunk was sent.
+    async fn send_playing_jukeboxes(&self, chunk: &ChunkData) {
+        for block_entity in chunk.block_entities.values() {
+            let Some(jukebox) = block_entity.as_any().downcast_ref::<JukeboxBlockEntity>() else {
+                continue;
+            };
+            let Some(song) = jukebox.get_song().await else {
+                continue;
+            };
+            let Some(song) = JukeboxBlock::get_song_index(song) else {
+                continue;
+            };
+            self.client
+                .enqueue_packet(&CWorldEvent::new(
+                    WorldEvent::JukeboxStartsPlaying as i32,
+                    jukebox.position,
+                    song,
+                    false,
+                ))
+                .await;
+        }
+    }
+
+    /// Returns where a player moving from `last_pos` to `requested` should end up when movement is
+    /// server authoritative: the requested position, or `last_pos` if the player moved faster than
+    /// `max_movement` blocks on top of their velocity.
+    #[must_use]
+    pub fn check_movement_speed(
+        last_pos: Vector3<f64>,
+        requested: Vector3<f64>,
+        velocity: Vector3<f64>,
+        max_movement: f64,
+    ) -> Vector3<f64> {
+        let moved = requested.sub(&last_pos).length_squared();
+        if moved - velocity.length_squared() > max_movement * max_movement {
+            last_pos
+        } else {
+            requested
+        }
+    }
+
     pub fn get_attack_cooldown_progress(&self, base_time: f64, attack_speed: f64) -> f64 {
         let x = f64::from(self.last_attacked_ticks.load(Ordering::Acquire)) + base_time;
 
@@ -1502,8 +1595,9 @@ impl Player {
         );
 
         // TODO: Merge stacks together
-        let item_entity =
-            Arc::new(ItemEntity::new_with_velocity(entity, item_stack, velocity, 40).await);
+        let item_entity = Arc::new(
+            ItemEntity::new_with_velocity(entity, item_stack, velocity, THROWN_PICKUP_DELAY).await,
+        );
         self.world().spawn_entity(item_entity).await;
     }
 
@@ -2170,6 +2264,8 @@ impl EntityBase for Player {
         yaw: Option<f32>,
         pitch: Option<f32>,
         world: Arc<World>,
+        // The client resets its own velocity on teleports
+        _keep_velocity: bool,
     ) {
         if Arc::ptr_eq(&world, self.world()) {
             // Same world
@@ -2569,3 +2665,28 @@ impl InventoryPlayer for Player {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::Player;
+
+    #[test]
+    fn too_fast_movement_is_corrected() {
+        let last_pos = Vector3::new(0.0, 64.0, 0.0);
+        let velocity = Vector3::new(0.0, 0.0, 0.0);
+
+        let walked = Vector3::new(0.2, 64.0, 0.1);
+        assert_eq!(
+            Player::check_movement_speed(last_pos, walked, velocity, 10.0),
+            walked
+        );
+
+        let teleported = Vector3::new(50.0, 64.0, 0.0);
+        assert_eq!(
+            Player::check_movement_speed(last_pos, teleported, velocity, 10.0),
+            last_pos
+        );
+    }
+}
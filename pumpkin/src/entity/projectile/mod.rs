@@ -1,22 +1,50 @@
 use std::{
     f32::{self},
-    sync::atomic::Ordering,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use super::{Entity, EntityBase, NBTStorage, living::LivingEntity};
+use crate::server::Server;
 use async_trait::async_trait;
-use pumpkin_util::math::vector3::Vector3;
+use pumpkin_data::damage::DamageType;
+use pumpkin_data::entity::{EntityStatus, EntityType};
+use pumpkin_data::tag;
+use pumpkin_util::math::{boundingbox::BoundingBox, vector3::Vector3};
+
+/// How much larger than their hitbox entities are for projectiles
+const TARGET_MARGIN: f64 = 0.3;
+/// Knockback of a projectile hit, like a punch without knockback enchantments
+const HIT_KNOCKBACK: f64 = 0.4;
+
+/// What a projectile ran into during a tick
+pub enum ProjectileHit {
+    Block,
+    Entity(Arc<dyn EntityBase>),
+}
 
 pub struct ThrownItemEntity {
     entity: Entity,
+    /// The entity that threw it, the cause of the damage it deals
+    owner_id: i32,
+    /// Projectiles only hit their owner once they got clear of it
+    left_owner: AtomicBool,
 }
 
 impl ThrownItemEntity {
     pub fn new(entity: Entity, owner: &Entity) -> Self {
         let mut owner_pos = owner.pos.load();
         owner_pos.y = (owner_pos.y + f64::from(owner.standing_eye_height)) - 0.1;
-        entity.pos.store(owner_pos);
-        Self { entity }
+        entity.set_pos(owner_pos);
+        // The client uses the spawn data to know who threw the projectile
+        entity.data.store(owner.entity_id, Ordering::Relaxed);
+        Self {
+            entity,
+            owner_id: owner.entity_id,
+            left_owner: AtomicBool::new(false),
+        }
     }
     pub fn set_velocity_from(
         &self,
@@ -74,12 +102,200 @@ impl ThrownItemEntity {
             velocity.y.atan2(len) as f32 * 57.295_776,
         );
     }
+
+    /// Where along the segment from `from` to `to` it enters `bounding_box`, from 0 to 1
+    #[must_use]
+    pub fn segment_hit(
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        bounding_box: &BoundingBox,
+    ) -> Option<f64> {
+        let direction = to.sub(&from);
+        let mut entry: f64 = 0.0;
+        let mut exit: f64 = 1.0;
+        for (start, delta, min, max) in [
+            (from.x, direction.x, bounding_box.min.x, bounding_box.max.x),
+            (from.y, direction.y, bounding_box.min.y, bounding_box.max.y),
+            (from.z, direction.z, bounding_box.min.z, bounding_box.max.z),
+        ] {
+            if delta.abs() < 1e-8 {
+                if start < min || start > max {
+                    return None;
+                }
+                continue;
+            }
+            let near = (min - start) / delta;
+            let far = (max - start) / delta;
+            entry = entry.max(near.min(far));
+            exit = exit.min(near.max(far));
+            if exit < entry {
+                return None;
+            }
+        }
+        Some(entry)
+    }
+
+    /// Damage dealt to `target`, snowballs only hurt blazes
+    fn hit_damage(&self, target: &Entity) -> f32 {
+        if self.entity.entity_type == &EntityType::SNOWBALL
+            && target.entity_type == &EntityType::BLAZE
+        {
+            3.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Living entities, boats and minecarts are hit, spectators are passed through. The owner
+    /// only once the projectile got clear of it.
+    fn can_hit(&self, target: &dyn EntityBase) -> bool {
+        let entity = target.get_entity();
+        let entity_type = entity.entity_type;
+        entity.entity_id != self.entity.entity_id
+            && entity.is_alive()
+            && !target.is_spectator()
+            && (target.get_living_entity().is_some()
+                || tag::EntityType::MINECRAFT_BOAT.1.contains(&entity_type.id)
+                || tag::EntityType::C_MINECARTS.1.contains(&entity_type.id))
+            && (entity.entity_id != self.owner_id || self.left_owner.load(Ordering::Relaxed))
+    }
+
+    /// Finds the first block or entity between `from` and `to`
+    async fn find_hit(&self, from: Vector3<f64>, to: Vector3<f64>) -> Option<ProjectileHit> {
+        let world = &self.entity.world;
+        let block_hit = world
+            .raycast_blocks(from, to)
+            .await
+            .map(|pos| Self::segment_hit(from, to, &BoundingBox::from_block(&pos)).unwrap_or(0.0));
+
+        let area = self
+            .entity
+            .bounding_box
+            .load()
+            .stretch(to.sub(&from))
+            .expand(1.0, 1.0, 1.0);
+        let mut candidates = world.get_entities_at_box(&area).await;
+        candidates.extend(
+            world
+                .get_players_at_box(&area)
+                .await
+                .into_iter()
+                .map(|player| player as Arc<dyn EntityBase>),
+        );
+
+        let mut closest: Option<(f64, Arc<dyn EntityBase>)> = None;
+        for candidate in candidates {
+            if !self.can_hit(candidate.as_ref()) {
+                continue;
+            }
+            let bounding_box = candidate.get_entity().bounding_box.load().expand(
+                TARGET_MARGIN,
+                TARGET_MARGIN,
+                TARGET_MARGIN,
+            );
+            if let Some(time) = Self::segment_hit(from, to, &bounding_box)
+                && block_hit.is_none_or(|block_time| time < block_time)
+                && closest.as_ref().is_none_or(|(closest, _)| time < *closest)
+            {
+                closest = Some((time, candidate));
+            }
+        }
+
+        match (closest, block_hit) {
+            (Some((_, target)), _) => Some(ProjectileHit::Entity(target)),
+            (None, Some(_)) => Some(ProjectileHit::Block),
+            (None, None) => None,
+        }
+    }
+
+    /// Hurts and knocks back `target`, with the owner as the cause
+    async fn hit_entity(&self, target: &Arc<dyn EntityBase>) {
+        let owner = self.entity.world.get_entity_by_id(self.owner_id).await;
+        let velocity = self.entity.velocity.load();
+        let damaged = target
+            .damage_with_context(
+                target.clone(),
+                self.hit_damage(target.get_entity()),
+                DamageType::THROWN,
+                Some(self.entity.pos.load()),
+                Some(self as &dyn EntityBase),
+                owner.as_deref(),
+            )
+            .await;
+        if damaged {
+            // Pushed the way the projectile flew
+            target
+                .get_entity()
+                .apply_knockback(HIT_KNOCKBACK, -velocity.x, -velocity.z);
+        }
+    }
+
+    /// Moves the projectile one tick along its trajectory, slowed down by drag and pulled down by
+    /// gravity. Anything it hits on the way ends its flight, returns what that was.
+    pub async fn tick_flight(&self) -> Option<ProjectileHit> {
+        let entity = &self.entity;
+        let velocity = entity.velocity.load();
+        let from = entity.pos.load();
+        let to = from.add(&velocity);
+
+        if !self.left_owner.load(Ordering::Relaxed) {
+            let area = entity
+                .bounding_box
+                .load()
+                .stretch(velocity)
+                .expand(1.0, 1.0, 1.0);
+            let owner = entity.world.get_entity_by_id(self.owner_id).await;
+            if owner.is_none_or(|owner| !owner.get_entity().bounding_box.load().intersects(&area)) {
+                self.left_owner.store(true, Ordering::Relaxed);
+            }
+        }
+
+        if let Some(hit) = self.find_hit(from, to).await {
+            if let ProjectileHit::Entity(target) = &hit {
+                self.hit_entity(target).await;
+            }
+            entity
+                .world
+                .send_entity_status(
+                    entity,
+                    EntityStatus::PlayDeathSoundOrAddProjectileHitParticles,
+                )
+                .await;
+            entity.discard().await;
+            return Some(hit);
+        }
+
+        entity.set_pos(to);
+        entity.set_rotation(
+            velocity.x.atan2(velocity.z) as f32 * 57.295_776,
+            velocity.y.atan2(velocity.horizontal_length()) as f32 * 57.295_776,
+        );
+        let drag = if entity.touching_water.load(Ordering::Relaxed) {
+            0.8
+        } else {
+            0.99
+        };
+        let mut velocity = velocity.multiply(drag, drag, drag);
+        velocity.y = Entity::apply_gravity(velocity.y, self.get_gravity(), false);
+        entity.velocity.store(velocity);
+        None
+    }
 }
 
 impl NBTStorage for ThrownItemEntity {}
 
 #[async_trait]
 impl EntityBase for ThrownItemEntity {
+    async fn tick(&self, caller: Arc<dyn EntityBase>, server: &Server) {
+        self.entity.base_tick(caller, server).await;
+        if self.entity.is_removed() {
+            return;
+        }
+        if self.tick_flight().await.is_none() {
+            self.entity.send_pos_rot().await;
+        }
+    }
+
     fn get_entity(&self) -> &Entity {
         &self.entity
     }
@@ -96,3 +312,126 @@ impl EntityBase for ThrownItemEntity {
         0.03
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::{boundingbox::BoundingBox, vector3::Vector3};
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    use super::{ProjectileHit, ThrownItemEntity};
+    use crate::entity::{Entity, r#type::spawn_from_type};
+    use crate::world::World;
+
+    #[test]
+    fn segments_enter_boxes_at_their_near_side() {
+        let bounding_box =
+            BoundingBox::new(Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0));
+        let from = Vector3::new(0.0, 0.5, 0.5);
+        let time = ThrownItemEntity::segment_hit(from, Vector3::new(4.0, 0.5, 0.5), &bounding_box);
+        assert!((time.unwrap() - 0.5).abs() < 1e-9);
+        // Too short, or passing above
+        assert!(
+            ThrownItemEntity::segment_hit(from, Vector3::new(1.5, 0.5, 0.5), &bounding_box)
+                .is_none()
+        );
+        assert!(
+            ThrownItemEntity::segment_hit(from, Vector3::new(4.0, 1.5, 0.5), &bounding_box)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn snowball_hits_the_zombie_in_its_way() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        // High up in the air, away from any terrain
+        let thrower = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(thrower.clone()).await;
+        let target = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(3.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(target.clone()).await;
+        let living = target.get_living_entity().unwrap();
+        let health = living.health.load();
+
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.0, 0.0, 0.0),
+            &EntityType::SNOWBALL,
+            false,
+        );
+        let snowball = Arc::new(ThrownItemEntity::new(entity, thrower.get_entity()));
+        snowball.set_velocity(1.0, 0.0, 0.0, 1.5, 0.0);
+        world.spawn_entity(snowball.clone()).await;
+
+        // Flies out of the thrower without hitting it, then falls a bit
+        assert!(snowball.tick_flight().await.is_none());
+        let velocity = snowball.entity.velocity.load();
+        assert!((velocity.x - 1.5 * 0.99).abs() < 1e-9);
+        assert!(velocity.y < 0.0);
+
+        let Some(ProjectileHit::Entity(hit)) = snowball.tick_flight().await else {
+            panic!("the snowball missed");
+        };
+        assert_eq!(hit.get_entity().entity_id, target.get_entity().entity_id);
+        assert!(snowball.entity.is_removed());
+
+        // Hurt without losing health, and pushed away from the thrower
+        assert_eq!(living.hurt_cooldown.load(Relaxed), 20);
+        assert!((living.health.load() - health).abs() < f32::EPSILON);
+        assert!(target.get_entity().velocity.load().x > 0.0);
+    }
+
+    #[tokio::test]
+    async fn snowball_flies_through_spectators_into_a_boat() {
+        use pumpkin_util::GameMode;
+
+        use crate::entity::player::Player;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let thrower = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(0.5, 300.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(thrower.clone()).await;
+
+        let (spectator, _packets) = Player::for_test(&world, GameMode::Spectator).await;
+        spectator
+            .get_entity()
+            .set_pos(Vector3::new(3.5, 300.0, 0.5));
+
+        // At the height the snowball flies at
+        let boat = spawn_from_type(&EntityType::OAK_BOAT, &world, Vector3::new(6.5, 301.0, 0.5))
+            .await
+            .unwrap();
+        world.spawn_entity(boat.clone()).await;
+
+        let entity = Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(0.0, 0.0, 0.0),
+            &EntityType::SNOWBALL,
+            false,
+        );
+        let snowball = Arc::new(ThrownItemEntity::new(entity, thrower.get_entity()));
+        snowball.set_velocity(1.0, 0.0, 0.0, 1.5, 0.0);
+        world.spawn_entity(snowball.clone()).await;
+
+        let mut hit = None;
+        for _ in 0..10 {
+            hit = snowball.tick_flight().await;
+            if hit.is_some() {
+                break;
+            }
+        }
+        let Some(ProjectileHit::Entity(hit)) = hit else {
+            panic!("the snowball missed");
+        };
+        assert_eq!(hit.get_entity().entity_id, boat.get_entity().entity_id);
+    }
+}
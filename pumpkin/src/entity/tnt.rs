@@ -1,12 +1,9 @@
-use super::{Entity, EntityBase, NBTStorage, living::LivingEntity};
+use super::{Entity, EntityBase, NBTStorage, living::LivingEntity, physics::AIR_DRAG};
 use crate::server::Server;
 use async_trait::async_trait;
 use core::f32;
 use pumpkin_data::Block;
-use pumpkin_protocol::{
-    codec::var_int::VarInt,
-    java::client::play::{MetaDataType, Metadata},
-};
+use pumpkin_protocol::{codec::var_int::VarInt, java::client::play::MetaDataType};
 use pumpkin_util::math::vector3::Vector3;
 use std::{
     f64::consts::TAU,
@@ -48,7 +45,7 @@ impl EntityBase for TNTEntity {
 
         entity.move_entity(caller.clone(), velo).await;
         entity.tick_block_collisions(&caller, server).await;
-        entity.velocity.store(velo.multiply(0.98, 0.98, 0.98));
+        entity.velocity.store(velo * AIR_DRAG);
         if entity.on_ground.load(Ordering::Relaxed) {
             entity.velocity.store(velo.multiply(0.7, -0.5, 0.7));
         }
@@ -79,21 +76,18 @@ impl EntityBase for TNTEntity {
         self.entity
             .set_velocity(Vector3::new(-pos.sin() * 0.02, 0.2, -pos.cos() * 0.02))
             .await;
-        // We can merge multiple `Metadata`s into one meta packet.
-        self.entity
-            .send_meta_data(&[
-                Metadata::new(
-                    8,
-                    MetaDataType::Integer,
-                    VarInt(self.fuse.load(Relaxed) as i32),
-                ),
-                Metadata::new(
-                    9,
-                    MetaDataType::BlockState,
-                    VarInt(i32::from(Block::TNT.default_state.id)),
-                ),
-            ])
-            .await;
+        self.entity.init_tracked_data();
+        let tracker = &self.entity.data_tracker;
+        tracker.track(
+            8,
+            MetaDataType::Integer,
+            VarInt(self.fuse.load(Relaxed) as i32),
+        );
+        tracker.track(
+            9,
+            MetaDataType::BlockState,
+            VarInt(i32::from(Block::TNT.default_state.id)),
+        );
     }
 
     fn get_entity(&self) -> &Entity {
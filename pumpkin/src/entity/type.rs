@@ -1,18 +1,30 @@
 use std::sync::Arc;
 
-use pumpkin_data::entity::EntityType;
+use pumpkin_data::{Block, entity::EntityType};
+use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::item::ItemStack;
+use thiserror::Error;
 use uuid::Uuid;
 
 use crate::{
+    block::blocks::tnt::{DEFAULT_FUSE, DEFAULT_POWER},
     entity::{
         Entity, EntityBase,
         decoration::{end_crystal::EndCrystalEntity, painting::PaintingEntity},
-        mob::zombie::Zombie,
+        experience_orb::ExperienceOrbEntity,
+        falling::FallingEntity,
+        item::ItemEntity,
+        mob::{water_creature::WaterCreature, zombie::Zombie},
+        tnt::TNTEntity,
     },
     world::World,
 };
 
+#[derive(Debug, Error)]
+#[error("{} can't be summoned", .0.resource_name)]
+pub struct NotSummonable(pub &'static EntityType);
+
 pub async fn from_type(
     entity_type: &'static EntityType,
     position: Vector3<f64>,
@@ -23,7 +35,11 @@ pub async fn from_type(
 
     #[allow(clippy::single_match)]
     let mob: Arc<dyn EntityBase> = match entity_type.id {
-        id if id == EntityType::ZOMBIE.id => Zombie::make(entity).await,
+        // TODO: Drowned specific AI
+        id if id == EntityType::ZOMBIE.id || id == EntityType::DROWNED.id => {
+            Zombie::make(entity).await
+        }
+        _ if Entity::is_aquatic_type(entity_type) => WaterCreature::make(entity).await,
         id if id == EntityType::PAINTING.id => Arc::new(PaintingEntity::new(entity)),
         id if id == EntityType::END_CRYSTAL.id => Arc::new(EndCrystalEntity::new(entity)),
         // TODO
@@ -32,3 +48,119 @@ pub async fn from_type(
 
     mob
 }
+
+/// Creates a fresh entity of `entity_type` at `position`, like `/summon` and spawn eggs do. Unlike
+/// [`from_type`] this also sets up entities that are not loaded from NBT, e.g. primed TNT.
+pub async fn spawn_from_type(
+    entity_type: &'static EntityType,
+    world: &Arc<World>,
+    position: Vector3<f64>,
+) -> Result<Arc<dyn EntityBase>, NotSummonable> {
+    if !entity_type.summonable {
+        return Err(NotSummonable(entity_type));
+    }
+    let uuid = Uuid::new_v4();
+    let entity = match entity_type.id {
+        id if id == EntityType::TNT.id => {
+            let entity = Entity::new(uuid, world.clone(), position, entity_type, false);
+            Arc::new(TNTEntity::new(entity, DEFAULT_POWER, DEFAULT_FUSE))
+        }
+        id if id == EntityType::FALLING_BLOCK.id => {
+            let entity = Entity::new(uuid, world.clone(), position, entity_type, false);
+            Arc::new(FallingEntity::new(entity, Block::SAND.default_state.id))
+        }
+        id if id == EntityType::ITEM.id => {
+            let entity = Entity::new(uuid, world.clone(), position, entity_type, false);
+            // Vanilla discards items summoned without one
+            Arc::new(ItemEntity::new(entity, ItemStack::EMPTY.clone()).await)
+        }
+        id if id == EntityType::EXPERIENCE_ORB.id => {
+            let entity = Entity::new(uuid, world.clone(), position, entity_type, false);
+            Arc::new(ExperienceOrbEntity::new(entity, 1))
+        }
+        _ => from_type(entity_type, position, world, uuid).await,
+    };
+    Ok(entity)
+}
+
+/// Reads the entity type from the `id` tag of a serialized entity.
+#[must_use]
+pub fn entity_type_from_nbt(nbt: &NbtCompound) -> Option<&'static EntityType> {
+    let id = nbt.get_string("id")?;
+    EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
+}
+
+/// Recreates a saved entity, using its `id` tag to pick the type and then loading the rest of the
+/// NBT into it. Returns [`None`] if the `id` tag is missing or unknown.
+pub async fn from_nbt(
+    nbt: &NbtCompound,
+    world: &Arc<World>,
+    uuid: Uuid,
+) -> Option<Arc<dyn EntityBase>> {
+    let entity_type = entity_type_from_nbt(nbt)?;
+    // Pos is zero since it will read from nbt
+    let entity = from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), world, uuid).await;
+    entity.read_nbt_non_mut(nbt).await;
+    Some(entity)
+}
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_nbt::compound::NbtCompound;
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::{from_nbt, spawn_from_type};
+    use crate::entity::NBTStorage;
+    use crate::world::World;
+
+    #[tokio::test]
+    async fn saved_zombie_is_loaded_back() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, Vector3::new(3.5, 70.0, -2.5))
+            .await
+            .unwrap();
+        zombie.get_living_entity().unwrap().health.store(7.0);
+        let mut nbt = NbtCompound::new();
+        zombie.write_nbt(&mut nbt).await;
+
+        let uuid = uuid::Uuid::new_v4();
+        let loaded = from_nbt(&nbt, &world, uuid).await.unwrap();
+        let entity = loaded.get_entity();
+        assert_eq!(entity.entity_type.id, EntityType::ZOMBIE.id);
+        assert_eq!(entity.entity_uuid, uuid);
+        assert_eq!(entity.pos.load(), Vector3::new(3.5, 70.0, -2.5));
+        assert!((loaded.get_living_entity().unwrap().health.load() - 7.0).abs() < f32::EPSILON);
+        // Built as a zombie, not as a plain entity
+        assert!(loaded.get_mob().is_some());
+
+        nbt.put_string("id", "minecraft:not_an_entity".to_string());
+        assert!(from_nbt(&nbt, &world, uuid).await.is_none());
+        assert!(from_nbt(&NbtCompound::new(), &world, uuid).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn summoned_zombie_has_goals() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+
+        let zombie = spawn_from_type(&EntityType::ZOMBIE, &world, pos)
+            .await
+            .unwrap();
+        let mob = zombie.get_mob().expect("zombies are mobs");
+        assert!(!mob.goals_selector.is_empty().await);
+        assert!(!mob.target_selector.is_empty().await);
+
+        let tnt = spawn_from_type(&EntityType::TNT, &world, pos)
+            .await
+            .unwrap();
+        assert!(tnt.get_mob().is_none());
+        assert!(
+            spawn_from_type(&EntityType::PLAYER, &world, pos)
+                .await
+                .is_err()
+        );
+    }
+}
@@ -11,7 +11,6 @@ pub mod ignite;
 pub mod ink_sac;
 pub mod mace;
 pub mod minecart;
-pub mod name_tag;
 pub mod shovel;
 pub mod snowball;
 pub mod spawn_egg;
@@ -20,7 +19,6 @@ pub mod trident;
 
 use crate::item::items::end_crystal::EndCrystalItem;
 use crate::item::items::minecart::MinecartItem;
-use crate::item::items::name_tag::NameTagItem;
 use crate::item::items::spawn_egg::SpawnEggItem;
 
 use super::registry::ItemRegistry;
@@ -61,7 +59,6 @@ pub fn default_registry() -> Arc<ItemRegistry> {
     manager.register(EndCrystalItem);
     manager.register(MinecartItem);
     manager.register(HoneyCombItem);
-    manager.register(NameTagItem);
     manager.register(EnderEyeItem);
     manager.register(FireChargeItem);
     manager.register(DyeItem);
@@ -1,5 +1,5 @@
 use crate::entity::player::Player;
-use crate::entity::r#type::from_type;
+use crate::entity::r#type::spawn_from_type;
 use crate::item::{ItemBehaviour, ItemMetadata};
 use crate::server::Server;
 use async_trait::async_trait;
@@ -10,7 +10,6 @@ use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::math::wrap_degrees;
 use pumpkin_world::block::entities::mob_spawner::MobSpawnerBlockEntity;
 use pumpkin_world::item::ItemStack;
-use uuid::Uuid;
 
 pub struct SpawnEggItem;
 
@@ -53,7 +52,9 @@ impl ItemBehaviour for SpawnEggItem {
             // Create rotation like Vanilla
             let yaw = wrap_degrees(rand::random::<f32>() * 360.0) % 360.0;
 
-            let mob = from_type(entity_type, pos, world, Uuid::new_v4()).await;
+            let Ok(mob) = spawn_from_type(entity_type, world, pos).await else {
+                return;
+            };
 
             // Set the rotation
             mob.get_entity().set_rotation(yaw, 0.0);
@@ -443,6 +443,23 @@ impl JavaClient {
         });
     }
 
+    /// Takes the queue of packets sent to the client, so tests can look at them instead of them
+    /// being written to the network
+    #[cfg(test)]
+    pub fn take_outgoing_packets(&mut self) -> Receiver<Bytes> {
+        self.outgoing_packet_queue_recv
+            .take()
+            .expect("This was set in the new fn")
+    }
+
+    /// The IDs of the packets queued in `packets` since the last call
+    #[cfg(test)]
+    pub fn queued_packet_ids(packets: &mut Receiver<Bytes>) -> Vec<i32> {
+        std::iter::from_fn(|| packets.try_recv().ok())
+            .map(|packet| VarInt::decode(&mut &packet[..]).unwrap().0)
+            .collect()
+    }
+
     /// Closes the connection to the client.
     ///
     /// This function marks the connection as closed using an atomic flag. It's generally preferable
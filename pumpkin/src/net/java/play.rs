@@ -9,15 +9,17 @@ use std::sync::atomic::Ordering;
 use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
+use crate::PLUGIN_MANAGER;
 use crate::block::BlockHitResult;
 use crate::block::registry::BlockActionResult;
 use crate::block::{self, BlockIsReplacing};
 use crate::command::CommandSender;
-use crate::entity::EntityBase;
 use crate::entity::player::{ChatMode, ChatSession, Player};
+use crate::entity::{EntityBase, InteractionResult};
 use crate::error::PumpkinError;
 use crate::net::PlayerConfig;
 use crate::net::java::JavaClient;
+use crate::plugin::entity::entity_interact::EntityInteractEvent;
 use crate::plugin::player::player_chat::PlayerChatEvent;
 use crate::plugin::player::player_command_send::PlayerCommandSendEvent;
 use crate::plugin::player::player_interact_event::{InteractAction, PlayerInteractEvent};
@@ -26,7 +28,9 @@ use crate::server::{Server, seasonal_events};
 use crate::world::{World, chunker};
 use pumpkin_config::{BASIC_CONFIG, advanced_config};
 use pumpkin_data::block_properties::{BlockProperties, WaterLikeProperties};
-use pumpkin_data::data_component_impl::{ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl};
+use pumpkin_data::data_component_impl::{
+    BlocksAttacksImpl, ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl,
+};
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::{Block, BlockDirection, BlockState};
@@ -220,6 +224,51 @@ impl JavaClient {
         pos.clamp(-2.0E7, 2.0E7)
     }
 
+    /// Checks the movement of a player when movement is server authoritative.
+    /// Returns the position to teleport the player back to if they moved too fast or through
+    /// blocks, or `None` if the movement is fine.
+    async fn validate_movement(player: &Player, position: Vector3<f64>) -> Option<Vector3<f64>> {
+        let config = &advanced_config().movement;
+        if !config.server_authoritative || player.gamemode.load() == GameMode::Spectator {
+            return None;
+        }
+
+        let entity = &player.living_entity.entity;
+        let last_pos = entity.pos.load();
+        let expected = Player::check_movement_speed(
+            last_pos,
+            position,
+            entity.velocity.load(),
+            config.max_movement_per_tick,
+        );
+        if expected != position {
+            return Some(expected);
+        }
+        // Vanilla allows an error of 0.0625 (0.25²) between our collision checks and the client
+        let movement = entity
+            .collide_movement(position.sub(&last_pos))
+            .await
+            .movement;
+        (last_pos.add(&movement).squared_distance_to_vec(position) > 0.0625).then_some(last_pos)
+    }
+
+    /// Returns whether a movement packet has to be dropped, teleporting the player back if
+    /// [`Self::validate_movement`] rejects it.
+    async fn reject_movement(&self, player: &Arc<Player>, position: Vector3<f64>) -> bool {
+        // Ignore movement until the client accepted our last teleport
+        if advanced_config().movement.server_authoritative
+            && player.awaiting_teleport.lock().await.is_some()
+        {
+            return true;
+        }
+        let Some(expected) = Self::validate_movement(player, position).await else {
+            return false;
+        };
+        log::warn!("{} moved wrongly!", player.gameprofile.name);
+        self.force_tp(player, expected).await;
+        true
+    }
+
     pub fn handle_player_loaded(player: &Player) {
         player.set_client_loaded(true);
     }
@@ -279,6 +328,9 @@ impl JavaClient {
             Self::clamp_vertical(position.y),
             Self::clamp_horizontal(position.z),
         );
+        if self.reject_movement(player, position).await {
+            return;
+        }
 
         send_cancellable! {{
             PlayerMoveEvent {
@@ -382,6 +434,9 @@ impl JavaClient {
             Self::clamp_vertical(position.y),
             Self::clamp_horizontal(position.z),
         );
+        if self.reject_movement(player, position).await {
+            return;
+        }
 
         send_cancellable! {{
             PlayerMoveEvent::new(
@@ -1064,7 +1119,7 @@ impl JavaClient {
 
     pub async fn handle_interact(
         &self,
-        player: &Player,
+        player: &Arc<Player>,
         interact: SInteract,
         server: &Arc<Server>,
     ) {
@@ -1138,18 +1193,43 @@ impl JavaClient {
                     .await;
                 }
             }
-            ActionType::Interact | ActionType::InteractAt => {
-                // TODO: split this up
-                let entity = player.world().get_player_by_id(entity_id.0).await;
-                if let Some(entity) = entity {
-                    let held = player.inventory.held_item();
-                    let mut stack = held.lock().await;
-                    server
-                        .item_registry
-                        .use_on_entity(&mut stack, player, entity)
-                        .await;
+            ActionType::Interact => {
+                let Some(hand) = interact.hand.and_then(|hand| Hand::try_from(hand.0).ok()) else {
+                    self.kick(TextComponent::text("InvalidHand")).await;
+                    return;
+                };
+                let Some(entity) = player.world().get_entity_by_id(entity_id.0).await else {
+                    return;
+                };
+                if PLUGIN_MANAGER.has_handlers::<EntityInteractEvent>().await
+                    && PLUGIN_MANAGER
+                        .fire(EntityInteractEvent::new(player.clone(), entity.clone()))
+                        .await
+                        .cancelled
+                {
+                    return;
+                }
+                let result = entity.interact(player, hand).await;
+                if result == InteractionResult::Success {
+                    player.swing_hand(hand, false).await;
                 }
+                if result.consumes_action() {
+                    return;
+                }
+                let held = if hand == Hand::Left {
+                    player.inventory.held_item()
+                } else {
+                    player.inventory.off_hand_item().await
+                };
+                let mut stack = held.lock().await;
+                server
+                    .item_registry
+                    .use_on_entity(&mut stack, player, entity)
+                    .await;
             }
+            // The client sends this next to `Interact`, only armor stands care where they were clicked
+            // TODO: Armor stands
+            ActionType::InteractAt => {}
         }
     }
 
@@ -1603,12 +1683,14 @@ impl JavaClient {
             )
         };
         let mut held = item_in_hand.lock().await;
-        if held.get_data_component::<ConsumableImpl>().is_some() {
+        if held.get_data_component::<ConsumableImpl>().is_some()
+            || held.get_data_component::<FoodImpl>().is_some()
+        {
             // If its food we want to make sure we can actually consume it
             if let Some(food) = held.get_data_component::<FoodImpl>() {
                 if player.abilities.lock().await.invulnerable
                     || food.can_always_eat
-                    || player.hunger_manager.level.load() < 20
+                    || player.hunger_manager.is_not_full()
                 {
                     player
                         .living_entity
@@ -1621,8 +1703,21 @@ impl JavaClient {
                     .set_active_hand(hand, held.clone())
                     .await;
             }
+        } else if held.get_data_component::<BlocksAttacksImpl>().is_some()
+            && player
+                .living_entity
+                .blocking_cooldown
+                .load(Ordering::Relaxed)
+                <= 0
+        {
+            player
+                .living_entity
+                .set_active_hand(hand, held.clone())
+                .await;
         }
-        if let Some(equippable) = held.get_data_component::<EquippableImpl>() {
+        if let Some(equippable) = held.get_data_component::<EquippableImpl>()
+            && equippable.swappable
+        {
             // If it can be equipped we want to makr sure we can actually equip it
             player
                 .enqueue_equipment_change(equippable.slot, &held)
@@ -0,0 +1,58 @@
+use pumpkin_data::damage::DamageType;
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// An event that occurs before a living entity takes damage.
+///
+/// If the event is cancelled, the entity will not take any damage.
+///
+/// This event contains information about the damaged entity, the amount of damage and its type.
+/// Changing `damage` changes the damage the entity takes.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityDamageEvent {
+    /// The entity taking damage.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The amount of damage the entity takes.
+    pub damage: f32,
+
+    /// The type of the damage.
+    pub damage_type: DamageType,
+}
+
+impl EntityDamageEvent {
+    /// Creates a new instance of `EntityDamageEvent`.
+    ///
+    /// # Arguments
+    /// - `entity`: The entity taking damage.
+    /// - `damage`: The amount of damage the entity takes.
+    /// - `damage_type`: The type of the damage.
+    ///
+    /// # Returns
+    /// A new instance of `EntityDamageEvent`.
+    pub fn new(entity: Arc<dyn EntityBase>, damage: f32, damage_type: DamageType) -> Self {
+        Self {
+            entity,
+            damage,
+            damage_type,
+            cancelled: false,
+        }
+    }
+
+    /// Returns the damage to apply, or `None` if a handler cancelled the event.
+    #[must_use]
+    pub fn get_final_damage(&self) -> Option<f32> {
+        (!self.cancelled).then_some(self.damage)
+    }
+}
+
+impl EntityEvent for EntityDamageEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -0,0 +1,46 @@
+use pumpkin_data::damage::DamageType;
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// An event that occurs when a living entity would die from damage.
+///
+/// If the event is cancelled, the lethal damage is not applied and the entity stays alive.
+///
+/// This event contains information about the dying entity and the type of the lethal damage.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityDeathEvent {
+    /// The entity that is about to die.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The type of the damage killing the entity.
+    pub damage_type: DamageType,
+}
+
+impl EntityDeathEvent {
+    /// Creates a new instance of `EntityDeathEvent`.
+    ///
+    /// # Arguments
+    /// - `entity`: The entity that is about to die.
+    /// - `damage_type`: The type of the damage killing the entity.
+    ///
+    /// # Returns
+    /// A new instance of `EntityDeathEvent`.
+    pub fn new(entity: Arc<dyn EntityBase>, damage_type: DamageType) -> Self {
+        Self {
+            entity,
+            damage_type,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntityDeathEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -0,0 +1,45 @@
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::{EntityBase, player::Player};
+
+use super::EntityEvent;
+
+/// An event that occurs when a player right clicks an entity.
+///
+/// If the event is cancelled, the interaction will not happen.
+///
+/// This event contains the interacting player and the entity being interacted with.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityInteractEvent {
+    /// The player interacting with the entity.
+    pub player: Arc<Player>,
+
+    /// The entity being interacted with.
+    pub entity: Arc<dyn EntityBase>,
+}
+
+impl EntityInteractEvent {
+    /// Creates a new instance of `EntityInteractEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: The player interacting with the entity.
+    /// - `entity`: The entity being interacted with.
+    ///
+    /// # Returns
+    /// A new instance of `EntityInteractEvent`.
+    pub fn new(player: Arc<Player>, entity: Arc<dyn EntityBase>) -> Self {
+        Self {
+            player,
+            entity,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntityInteractEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -594,6 +594,17 @@ impl PluginManager {
             .push(Box::new(typed_handler));
     }
 
+    /// Returns whether any handler is registered for the event.
+    ///
+    /// Useful to skip building an event on hot paths when nobody listens to it.
+    pub async fn has_handlers<E: Payload + 'static>(&self) -> bool {
+        self.handlers
+            .read()
+            .await
+            .get(&E::get_name_static())
+            .is_some_and(|handlers| !handlers.is_empty())
+    }
+
     /// Fire an event to all registered handlers
     pub async fn fire<E: Payload + Send + Sync + 'static>(&self, mut event: E) -> E {
         if let Some(server) = self.server.read().await.as_ref() {
@@ -619,3 +630,88 @@ impl PluginManager {
         event
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    use async_trait::async_trait;
+
+    use super::{Cancellable, EventHandler, EventPriority};
+    use crate::PLUGIN_MANAGER;
+    use crate::plugin::entity::entity_damage::EntityDamageEvent;
+    use crate::server::Server;
+
+    /// Counts the damage events of one entity and cancels them while `cancel` is set
+    struct DamageListener {
+        entity_id: i32,
+        cancel: AtomicBool,
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl EventHandler<EntityDamageEvent> for DamageListener {
+        async fn handle_blocking(&self, _server: &Arc<Server>, event: &mut EntityDamageEvent) {
+            // Other tests damage their own entities with the same plugin manager
+            if event.entity.get_entity().entity_id != self.entity_id {
+                return;
+            }
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            event.set_cancelled(self.cancel.load(Ordering::Relaxed));
+        }
+    }
+
+    #[tokio::test]
+    async fn damage_listeners_can_cancel_mob_damage() {
+        use pumpkin_data::damage::DamageType;
+        use pumpkin_data::entity::EntityType;
+        use pumpkin_util::math::vector3::Vector3;
+
+        use crate::entity::mob::Mob;
+        use crate::entity::mob::zombie::Zombie;
+        use crate::entity::{Entity, EntityBase};
+        use crate::world::World;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        PLUGIN_MANAGER
+            .set_server(Server::for_test(temp_dir.path().join("server")).await)
+            .await;
+        let world = World::for_test(temp_dir.path().join("world"));
+        let zombie = Zombie::make(Entity::new(
+            uuid::Uuid::new_v4(),
+            world,
+            Vector3::new(0.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ))
+        .await;
+        let living = &zombie.get_mob_entity().living_entity;
+        let listener = Arc::new(DamageListener {
+            entity_id: living.entity.entity_id,
+            cancel: AtomicBool::new(true),
+            calls: AtomicU32::new(0),
+        });
+        PLUGIN_MANAGER
+            .register(listener.clone(), EventPriority::Highest, true)
+            .await;
+
+        let health = living.health.load();
+        assert!(
+            !zombie
+                .damage(zombie.clone(), 4.0, DamageType::GENERIC)
+                .await
+        );
+        assert_eq!(listener.calls.load(Ordering::Relaxed), 1);
+        assert!((living.health.load() - health).abs() < f32::EPSILON);
+
+        listener.cancel.store(false, Ordering::Relaxed);
+        assert!(
+            zombie
+                .damage(zombie.clone(), 4.0, DamageType::GENERIC)
+                .await
+        );
+        assert_eq!(listener.calls.load(Ordering::Relaxed), 2);
+        assert!((living.health.load() - (health - 4.0)).abs() < f32::EPSILON);
+    }
+}
@@ -30,11 +30,13 @@ use pumpkin_world::world_info::anvil::{
     AnvilLevelInfo, LEVEL_DAT_BACKUP_FILE_NAME, LEVEL_DAT_FILE_NAME,
 };
 use pumpkin_world::world_info::{LevelData, WorldInfoError, WorldInfoReader, WorldInfoWriter};
-use rand::seq::{IndexedRandom, IteratorRandom, SliceRandom};
+use rand::Rng;
+use rand::seq::{IteratorRandom, SliceRandom};
 use rsa::RsaPublicKey;
 use std::collections::HashSet;
 use std::fs;
 use std::net::IpAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU32};
 use std::{future::Future, sync::atomic::Ordering, time::Duration};
@@ -107,12 +109,21 @@ pub struct Server {
 
 impl Server {
     #[allow(clippy::new_without_default)]
-    #[allow(clippy::too_many_lines)]
     #[must_use]
     pub async fn new() -> Arc<Self> {
+        Self::load(BASIC_CONFIG.get_world_path()).await
+    }
+
+    /// A server whose worlds are saved into `root_folder`
+    #[cfg(test)]
+    pub async fn for_test(root_folder: PathBuf) -> Arc<Self> {
+        Self::load(root_folder).await
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn load(world_path: PathBuf) -> Arc<Self> {
         // First register the default commands. After that, plugins can put in their own.
         let command_dispatcher = RwLock::new(default_dispatcher().await);
-        let world_path = BASIC_CONFIG.get_world_path();
 
         let block_registry = super::block::registry::default_registry();
 
@@ -515,11 +526,14 @@ impl Server {
         players
     }
 
-    /// Returns a random player from any of the worlds, or `None` if all worlds are empty.
+    /// Returns a random player from any of the worlds, each equally likely, or `None` if all
+    /// worlds are empty.
     pub async fn get_random_player(&self) -> Option<Arc<Player>> {
-        let players = self.get_all_players().await;
-
-        players.choose(&mut rand::rng()).map(Arc::<_>::clone)
+        choose_random(
+            self.get_all_players().await,
+            |player| player.entity_id(),
+            &mut rand::rng(),
+        )
     }
 
     /// Searches for a player by their UUID across all worlds.
@@ -684,20 +698,39 @@ impl Server {
         source: Option<&CommandSender>,
     ) -> Vec<Arc<dyn EntityBase>> {
         let iter = match &target_selector.selector_type {
-            EntitySelectorType::Source
-            | EntitySelectorType::NearestEntity
-            | EntitySelectorType::NearestPlayer => {
-                // todo: command context, currently the nearest entity is the player itself
-                if let Some(sender) = source {
-                    if let Some(player) = sender.as_player() {
-                        vec![player as Arc<dyn EntityBase>].into_iter()
-                    } else {
-                        vec![].into_iter()
-                    }
+            EntitySelectorType::Source => {
+                // todo: command context
+                if let Some(player) = source.and_then(CommandSender::as_player) {
+                    vec![player as Arc<dyn EntityBase>].into_iter()
                 } else {
                     vec![].into_iter()
                 }
             }
+            EntitySelectorType::NearestEntity | EntitySelectorType::NearestPlayer => {
+                // todo: command context, console and rcon have no position to search from
+                let Some(player) = source.and_then(CommandSender::as_player) else {
+                    return vec![];
+                };
+                let world = player.world();
+                let mut entities = Vec::new();
+                entities.extend(
+                    world
+                        .players
+                        .read()
+                        .await
+                        .values()
+                        .filter(|other| other.gameprofile.id != player.gameprofile.id)
+                        .cloned()
+                        .map(|p| p as Arc<dyn EntityBase>),
+                );
+                if matches!(
+                    target_selector.selector_type,
+                    EntitySelectorType::NearestEntity
+                ) {
+                    entities.extend(world.entities.read().await.values().cloned());
+                }
+                entities.into_iter()
+            }
             EntitySelectorType::RandomPlayer => {
                 if let Some(player) = self.get_random_player().await {
                     vec![player as Arc<dyn EntityBase>].into_iter()
@@ -739,7 +772,14 @@ impl Server {
                 if let Some(player) = self.get_player_by_uuid(*uuid).await {
                     vec![player as Arc<dyn EntityBase>].into_iter()
                 } else {
-                    vec![].into_iter()
+                    let mut found = vec![];
+                    for world in self.worlds.read().await.iter() {
+                        if let Some(entity) = world.entities.read().await.get(uuid) {
+                            found.push(entity.clone());
+                            break;
+                        }
+                    }
+                    found.into_iter()
                 }
             }
         };
@@ -770,7 +810,18 @@ impl Server {
             (type_excluded.is_empty() || !type_excluded.contains(&e.get_entity().entity_type))
                 && (type_included.is_empty() || type_included.contains(&e.get_entity().entity_type))
         });
-        let iter = type_filtered;
+        let center = source.and_then(CommandSender::position).unwrap_or_default();
+        let distance = target_selector.get_distance();
+        let names: Vec<_> = target_selector.get_names().collect();
+        let iter = type_filtered.filter(|e| {
+            let entity = e.get_entity();
+            distance.is_none_or(|distance| {
+                distance.test(&entity.pos.load().squared_distance_to_vec(center).sqrt())
+            }) && (names.is_empty() || {
+                let name = e.get_name().get_text();
+                names.iter().all(|condition| condition.test(&name))
+            })
+        });
         match target_selector
             .get_sort()
             .unwrap_or(EntityFilterSort::Arbitrary)
@@ -802,11 +853,6 @@ impl Server {
                 }
                 // sort entities first
                 // todo: command context
-                let center = if let Some(source) = source {
-                    source.position().unwrap_or_default()
-                } else {
-                    Vector3::default()
-                };
                 let mut entities = iter.collect::<Vec<_>>();
                 entities.sort_by(|a, b| {
                     let a_distance = a.get_entity().pos.load().squared_distance_to_vec(center);
@@ -829,3 +875,53 @@ impl Server {
         }
     }
 }
+
+/// Picks one of `candidates` with `rng`, each equally likely. They are sorted by `key` first, so
+/// the same seed picks the same one no matter in which order they were collected.
+pub fn choose_random<T, K: Ord>(
+    mut candidates: Vec<T>,
+    key: impl FnMut(&T) -> K,
+    rng: &mut impl Rng,
+) -> Option<T> {
+    if candidates.is_empty() {
+        return None;
+    }
+    candidates.sort_by_key(key);
+    let index = rng.random_range(0..candidates.len());
+    Some(candidates.swap_remove(index))
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::choose_random;
+
+    #[test]
+    fn random_player_is_picked_uniformly_and_by_seed() {
+        let players = vec!["Steve", "Alex", "Notch", "Jeb"];
+        let pick = |players: Vec<&'static str>, seed| {
+            choose_random(players, |name| *name, &mut StdRng::seed_from_u64(seed))
+        };
+
+        let mut sorted = players.clone();
+        sorted.sort_unstable();
+        let expected = sorted[StdRng::seed_from_u64(42).random_range(0..sorted.len())];
+        assert_eq!(pick(players.clone(), 42), Some(expected));
+        // The order the worlds list their players in doesn't matter
+        let mut reversed = players.clone();
+        reversed.reverse();
+        assert_eq!(pick(reversed, 42), Some(expected));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut counts = [0; 4];
+        for _ in 0..4000 {
+            let name = choose_random(players.clone(), |name| *name, &mut rng).unwrap();
+            counts[sorted.iter().position(|other| *other == name).unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|count| (800..1200).contains(count)));
+
+        assert_eq!(pick(Vec::new(), 42), None);
+    }
+}
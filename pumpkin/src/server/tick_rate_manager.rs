@@ -224,3 +224,41 @@ impl ServerTickRateManager {
             .await;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::server::Server;
+
+    #[tokio::test]
+    async fn frozen_game_only_runs_when_stepped() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let server = Server::for_test(temp_dir.path().to_path_buf()).await;
+        let manager = &server.tick_rate_manager;
+        manager.tick();
+        assert!(manager.runs_normally());
+        // Only a frozen game can be stepped
+        assert!(!manager.step_game_if_paused(&server, 3).await);
+
+        manager.set_frozen(&server, true).await;
+        manager.tick();
+        assert!(!manager.runs_normally());
+
+        assert!(manager.step_game_if_paused(&server, 3).await);
+        for _ in 0..3 {
+            manager.tick();
+            assert!(manager.runs_normally());
+        }
+        assert!(!manager.is_stepping_forward());
+        manager.tick();
+        assert!(!manager.runs_normally());
+
+        assert!(manager.step_game_if_paused(&server, 3).await);
+        assert!(manager.stop_stepping(&server).await);
+        manager.tick();
+        assert!(!manager.runs_normally());
+
+        manager.set_frozen(&server, false).await;
+        manager.tick();
+        assert!(manager.runs_normally());
+    }
+}
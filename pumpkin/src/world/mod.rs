@@ -3,7 +3,7 @@ use std::sync::Weak;
 use std::sync::atomic::Ordering::Relaxed;
 use std::time::Duration;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{Arc, atomic::Ordering},
 };
 
@@ -22,17 +22,28 @@ use crate::{
         {OnNeighborUpdateArgs, OnScheduledTickArgs},
     },
     command::client_suggestions,
-    entity::{Entity, EntityBase, player::Player, r#type::from_type},
+    entity::{
+        Entity, EntityBase, RemovalReason,
+        player::Player,
+        r#type::{from_nbt, from_type},
+    },
     error::PumpkinError,
     net::ClientPlatform,
     plugin::{
         block::block_break::BlockBreakEvent,
+        entity::entity_spawn::EntitySpawnEvent,
         player::{player_join::PlayerJoinEvent, player_leave::PlayerLeaveEvent},
         world::{chunk_load::ChunkLoad, chunk_save::ChunkSave, chunk_send::ChunkSend},
     },
-    server::Server,
+    server::{Server, choose_random},
+};
+use crate::{
+    block::BlockEvent,
+    entity::{
+        experience_orb::ExperienceOrbEntity,
+        item::{DEFAULT_PICKUP_DELAY, ItemEntity},
+    },
 };
-use crate::{block::BlockEvent, entity::item::ItemEntity};
 use async_trait::async_trait;
 use border::Worldborder;
 use bytes::BufMut;
@@ -191,6 +202,9 @@ pub struct World {
     /// Block Behaviour
     pub block_registry: Arc<BlockRegistry>,
     pub server: Weak<Server>,
+    /// Lit nether portals known to this world, by their lowest portal block. Used to link portals
+    /// without scanning the terrain every time.
+    pub portal_cache: Mutex<HashSet<BlockPos>>,
     synced_block_event_queue: Mutex<Vec<BlockEvent>>,
     /// A map of unsent block changes, keyed by block position.
     unsent_block_changes: Mutex<HashMap<BlockPos, u16>>,
@@ -234,10 +248,29 @@ impl World {
             min_y: i32::from(generation_settings.shape.min_y),
             synced_block_event_queue: Mutex::new(Vec::new()),
             unsent_block_changes: Mutex::new(HashMap::new()),
+            portal_cache: Mutex::new(HashSet::new()),
             server,
         }
     }
 
+    /// An empty overworld for tests, which saves into `root_folder`
+    #[cfg(test)]
+    pub fn for_test(root_folder: std::path::PathBuf) -> Arc<Self> {
+        let block_registry = crate::block::registry::default_registry();
+        let level = pumpkin_world::dimension::Dimension::Overworld.into_level(
+            root_folder,
+            block_registry.clone(),
+            0,
+        );
+        Arc::new(Self::load(
+            level,
+            Arc::new(RwLock::new(LevelData::default())),
+            VanillaDimensionType::Overworld,
+            block_registry,
+            Weak::new(),
+        ))
+    }
+
     pub async fn shutdown(&self) {
         for (uuid, entity) in self.entities.read().await.iter() {
             self.save_entity(uuid, entity).await;
@@ -245,55 +278,75 @@ impl World {
         self.level.shutdown().await;
     }
 
+    /// Writes the entity into its entity chunk. Entities that shouldn't persist, e.g. discarded
+    /// ones, are dropped from the chunk instead.
     async fn save_entity(&self, uuid: &uuid::Uuid, entity: &Arc<dyn EntityBase>) {
-        // First lets see if the entity was saved on an other chunk, and if the current chunk does not match we remove it
-        // Otherwise we just update the nbt data
         let base_entity = entity.get_entity();
-        let (current_chunk_coordinate, _) = base_entity
-            .block_pos
+        self.update_entity_chunk(base_entity).await;
+        let chunk_coordinate = base_entity
+            .saved_chunk_position
             .load()
-            .chunk_and_chunk_relative_position();
-        let mut nbt = NbtCompound::new();
-        entity.write_nbt(&mut nbt).await;
-        if let Some(old_chunk) = base_entity.first_loaded_chunk_position.load() {
-            let old_chunk = old_chunk.to_vec2_i32();
-            let chunk = self.level.get_entity_chunk(old_chunk).await;
-            let mut chunk = chunk.write().await;
-            chunk.mark_dirty(true);
-            if old_chunk == current_chunk_coordinate {
-                chunk.data.insert(*uuid, nbt);
-                return;
-            }
-
-            // The chunk has changed, lets remove the entity from the old chunk
+            .unwrap_or_else(|| base_entity.chunk_pos.load());
+        let nbt = if Self::should_save_entity(entity.as_ref()) {
+            let mut nbt = NbtCompound::new();
+            entity.write_nbt(&mut nbt).await;
+            Some(nbt)
+        } else {
+            None
+        };
+        let chunk = self.level.get_entity_chunk(chunk_coordinate).await;
+        let mut chunk = chunk.write().await;
+        if let Some(nbt) = nbt {
+            chunk.data.insert(*uuid, nbt);
+        } else {
             chunk.data.remove(uuid);
         }
-        // We did not continue, so lets save data in a new chunk
-        let chunk = self.level.get_entity_chunk(current_chunk_coordinate).await;
-        let mut chunk = chunk.write().await;
-        chunk.data.insert(*uuid, nbt);
         chunk.mark_dirty(true);
     }
 
+    fn should_save_entity(entity: &dyn EntityBase) -> bool {
+        entity.should_save()
+            && entity
+                .get_entity()
+                .removal_reason
+                .load()
+                .is_none_or(|reason| reason.should_save())
+    }
+
     async fn remove_entity_data(&self, entity: &Entity) {
-        let (current_chunk_coordinate, _) =
-            entity.block_pos.load().chunk_and_chunk_relative_position();
-        if let Some(old_chunk) = entity.first_loaded_chunk_position.load() {
-            let old_chunk = old_chunk.to_vec2_i32();
-            let chunk = self.level.get_entity_chunk(old_chunk).await;
-            let mut chunk = chunk.write().await;
-            chunk.mark_dirty(true);
-            if old_chunk == current_chunk_coordinate {
-                chunk.data.remove(&entity.entity_uuid);
-                drop(chunk);
-            } else {
-                let chunk = self.level.get_entity_chunk(current_chunk_coordinate).await;
-                let mut chunk = chunk.write().await;
-                // The chunk has changed, lets remove the entity from the old chunk
-                chunk.data.remove(&entity.entity_uuid);
-                chunk.mark_dirty(true);
-            }
+        let chunk_coordinate = entity
+            .saved_chunk_position
+            .load()
+            .unwrap_or_else(|| entity.chunk_pos.load());
+        let chunk = self.level.get_entity_chunk(chunk_coordinate).await;
+        let mut chunk = chunk.write().await;
+        chunk.data.remove(&entity.entity_uuid);
+        chunk.mark_dirty(true);
+    }
+
+    /// Moves the saved data of an entity which crossed a chunk border into the entity chunk it is
+    /// in now, loading that chunk if needed.
+    pub async fn update_entity_chunk(&self, entity: &Entity) {
+        let current = entity.chunk_pos.load();
+        let Some(saved) = entity.saved_chunk_position.swap(Some(current)) else {
+            return;
+        };
+        if saved == current {
+            return;
         }
+
+        let old_chunk = self.level.get_entity_chunk(saved).await;
+        let new_chunk = self.level.get_entity_chunk(current).await;
+        // Always lock the chunks in the same order, or two entities crossing the border in
+        // opposite directions could each wait on the chunk the other one holds
+        let (mut old_chunk, mut new_chunk) = if (saved.x, saved.y) < (current.x, current.y) {
+            let old_chunk = old_chunk.write().await;
+            (old_chunk, new_chunk.write().await)
+        } else {
+            let new_chunk = new_chunk.write().await;
+            (old_chunk.write().await, new_chunk)
+        };
+        old_chunk.transfer_entity(&mut new_chunk, &entity.entity_uuid);
     }
 
     pub async fn send_entity_status(&self, entity: &Entity, status: EntityStatus) {
@@ -611,6 +664,7 @@ impl World {
         log::trace!("Ticking players");
         // player ticks
         for player in players_to_tick {
+            player.living_entity.entity.age.fetch_add(1, Relaxed);
             player.tick(server).await;
         }
 
@@ -619,8 +673,18 @@ impl World {
         log::trace!("Ticking entities");
         // Entity ticks
         for entity in entities_to_tick {
-            entity.get_entity().age.fetch_add(1, Relaxed);
+            let base_entity = entity.get_entity();
+            // Entities in unloaded chunks are frozen until the chunk is loaded again
+            if self
+                .level
+                .try_get_chunk(&base_entity.chunk_pos.load())
+                .is_none()
+            {
+                continue;
+            }
+            base_entity.age.fetch_add(1, Relaxed);
             entity.tick(entity.clone(), server).await;
+            self.update_entity_chunk(base_entity).await;
             for player in self.players.read().await.values() {
                 if player
                     .living_entity
@@ -2013,30 +2077,25 @@ impl World {
                         &position
                     );
                     let mut ids = Vec::new();
-                    // Remove all the entities from the world
-                    let mut entities = world.entities.write().await;
-                    for (uuid, entity_nbt) in &chunk.read().await.data {
-                        let Some(id) = entity_nbt.get_string("id") else {
-                            log::warn!("Entity has no ID");
-                            continue;
-                        };
-                        let Some(entity_type) =
-                            EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
-                        else {
-                            log::warn!("Entity has no valid Entity Type {id}");
-                            continue;
-                        };
-                        // Pos is zero since it will read from nbt
-                        let entity =
-                            from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid)
-                                .await;
-                        entity.read_nbt_non_mut(entity_nbt).await;
+                    // Remove all the entities from the world, saving their current state back into the chunk
+                    let uuids: Vec<_> = chunk.read().await.data.keys().copied().collect();
+                    let unloaded: Vec<_> = {
+                        let mut entities = world.entities.write().await;
+                        uuids
+                            .iter()
+                            .filter_map(|uuid| entities.remove(uuid))
+                            .collect()
+                    };
+                    for entity in unloaded {
                         let base_entity = entity.get_entity();
-
-                        entities.remove(&base_entity.entity_uuid);
                         ids.push(VarInt(base_entity.entity_id));
 
-                        world.save_entity(uuid, &entity).await;
+                        let reason = base_entity
+                            .removal_reason
+                            .load()
+                            .unwrap_or(RemovalReason::UnloadedToChunk);
+                        base_entity.removal_reason.store(Some(reason));
+                        world.save_entity(&base_entity.entity_uuid, &entity).await;
                     }
                     if !ids.is_empty() {
                         player
@@ -2054,26 +2113,27 @@ impl World {
                 let mut current_entities = world.entities.write().await;
 
                 for (uuid, entity_nbt) in &entity_chunk.data {
-                    let Some(id) = entity_nbt.get_string("id") else {
-                        log::warn!("Entity has no ID");
-                        continue;
-                    };
-                    let Some(entity_type) =
-                        EntityType::from_name(id.strip_prefix("minecraft:").unwrap_or(id))
-                    else {
-                        log::warn!("Entity has no valid Entity Type {id}");
+                    let Some(entity) = from_nbt(entity_nbt, &world, *uuid).await else {
+                        log::warn!(
+                            "Entity has no valid Entity Type {:?}",
+                            entity_nbt.get_string("id")
+                        );
                         continue;
                     };
-                    // Pos is zero since it will read from nbt
-                    let entity =
-                        from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid).await;
-                    entity.read_nbt_non_mut(entity_nbt).await;
                     let base_entity = entity.get_entity();
                     player
                         .client
                         .enqueue_packet(&base_entity.create_spawn_packet())
                         .await;
                     entity.init_data_tracker().await;
+                    if let Some(packet) = base_entity.tracked_data_packet() {
+                        player.client.enqueue_packet(&packet).await;
+                    }
+                    if let Some(living) = entity.get_living_entity()
+                        && let Some(packet) = living.equipment_packet().await
+                    {
+                        player.client.enqueue_packet(&packet).await;
+                    }
                     current_entities.insert(base_entity.entity_uuid, entity);
                 }
             }
@@ -2108,6 +2168,12 @@ impl World {
         None
     }
 
+    /// Returns a random player of this world, each equally likely, or `None` if it is empty.
+    pub async fn get_random_player(&self) -> Option<Arc<Player>> {
+        let players = self.players.read().await.values().cloned().collect();
+        choose_random(players, |player| player.entity_id(), &mut rand::rng())
+    }
+
     /// Gets a `Player` by a username
     pub async fn get_player_by_name(&self, name: &str) -> Option<Arc<Player>> {
         for player in self.players.read().await.values() {
@@ -2126,6 +2192,36 @@ impl World {
             .cloned()
             .collect()
     }
+
+    /// Like [`World::get_entities_at_box`], but only looks at the entities of the entity chunks
+    /// around `aabb` instead of every entity of the world.
+    pub async fn get_chunk_entities_at_box(&self, aabb: &BoundingBox) -> Vec<Arc<dyn EntityBase>> {
+        // Entities belong to the chunk their position is in, wide ones stick out of it
+        let area = aabb.expand(2.0, 0.0, 2.0);
+        let chunk = |coord: f64| (coord.floor() as i32) >> 4;
+        let mut uuids = Vec::new();
+        for x in chunk(area.min.x)..=chunk(area.max.x) {
+            for z in chunk(area.min.z)..=chunk(area.max.z) {
+                let Some(entity_chunk) = self
+                    .level
+                    .try_get_entity_chunk(Vector2::new(x, z))
+                    .map(|entity_chunk| entity_chunk.clone())
+                else {
+                    continue;
+                };
+                uuids.extend(entity_chunk.read().await.data.keys().copied());
+            }
+        }
+
+        let entities_guard = self.entities.read().await;
+        uuids
+            .iter()
+            .filter_map(|uuid| entities_guard.get(uuid))
+            .filter(|entity| entity.get_entity().bounding_box.load().intersects(aabb))
+            .cloned()
+            .collect()
+    }
+
     pub async fn get_players_at_box(&self, aabb: &BoundingBox) -> Vec<Arc<Player>> {
         let players_guard = self.players.read().await;
         players_guard
@@ -2378,15 +2474,31 @@ impl World {
 
     /// Adds an entity to the world.
     pub async fn spawn_entity(&self, entity: Arc<dyn EntityBase>) {
+        if PLUGIN_MANAGER.has_handlers::<EntitySpawnEvent>().await
+            && PLUGIN_MANAGER
+                .fire(EntitySpawnEvent::new(entity.clone()))
+                .await
+                .cancelled
+        {
+            return;
+        }
+
         let base_entity = entity.get_entity();
         self.broadcast_packet_all(&base_entity.create_spawn_packet())
             .await;
         entity.init_data_tracker().await;
+        // Everyone sees the new entity, so its whole baseline goes out as sent
+        base_entity.send_dirty_meta_data().await;
+        if let Some(living) = entity.get_living_entity()
+            && let Some(packet) = living.equipment_packet().await
+        {
+            self.broadcast_packet_all(&packet).await;
+        }
 
-        let (chunk_coordinate, _) = base_entity
-            .block_pos
-            .load()
-            .chunk_and_chunk_relative_position();
+        let chunk_coordinate = base_entity.chunk_pos.load();
+        base_entity
+            .saved_chunk_position
+            .store(Some(chunk_coordinate));
         let chunk = self.level.get_entity_chunk(chunk_coordinate).await;
         let mut chunk = chunk.write().await;
         let mut nbt = NbtCompound::new();
@@ -2653,6 +2765,11 @@ impl World {
         self.spawn_entity(item_entity).await;
     }
 
+    /// Spawns `amount` experience split into multiple orbs.
+    pub async fn spawn_experience_orbs(self: &Arc<Self>, pos: Vector3<f64>, amount: u32) {
+        ExperienceOrbEntity::spawn(self, pos, amount).await;
+    }
+
     /* ItemScatterer.java */
     pub async fn scatter_inventory(
         self: &Arc<Self>,
@@ -2701,7 +2818,9 @@ impl World {
                 &EntityType::ITEM,
                 false,
             );
-            let entity = Arc::new(ItemEntity::new_with_velocity(entity, item, velocity, 10).await);
+            let entity = Arc::new(
+                ItemEntity::new_with_velocity(entity, item, velocity, DEFAULT_PICKUP_DELAY).await,
+            );
             self.spawn_entity(entity).await;
         }
     }
@@ -2790,14 +2909,25 @@ impl World {
 
     pub async fn get_fluid_and_fluid_state(&self, position: &BlockPos) -> (Fluid, FluidState) {
         let id = self.get_block_state_id(position).await;
+        Self::fluid_and_fluid_state_from_id(id)
+    }
 
+    /// Gets the fluid of a block state, e.g. water for waterlogged blocks and bubble columns
+    #[must_use]
+    pub fn fluid_and_fluid_state_from_id(id: BlockStateId) -> (Fluid, FluidState) {
         let Some(fluid) = Fluid::from_state_id(id) else {
             let block = Block::from_state_id(id);
+            if block == &Block::BUBBLE_COLUMN {
+                let fluid = Fluid::WATER;
+                let state = fluid.states[0].clone();
+                return (fluid, state);
+            }
             if let Some(properties) = block.properties(id) {
                 for (name, value) in properties.to_props() {
                     if name == *"waterlogged" {
+                        // Waterlogged blocks hold a full water source, like vanilla's `Fluids.WATER.getStill(false)`
                         if value == true.to_string() {
-                            let fluid = Fluid::FLOWING_WATER;
+                            let fluid = Fluid::WATER;
 
                             let state = fluid.states[0].clone();
 
@@ -2826,6 +2956,28 @@ impl World {
         self.level.get_block_state(position).await.0
     }
 
+    /// Gets the `(sky, block)` light levels at the position.
+    pub async fn get_light_levels(&self, position: &BlockPos) -> (u8, u8) {
+        self.level.get_light_levels(position).await
+    }
+
+    /// How much the sky light is dimmed by the night and the weather, from 0 at a clear noon up to
+    /// 11, like vanilla's `World.calculateAmbientDarkness`.
+    pub async fn ambient_darkness(&self) -> u8 {
+        let sky_angle = f64::from(self.level_time.lock().await.sky_angle());
+        let weather = self.weather.lock().await;
+        let rain = f64::from(weather.rain_level);
+        let thunder = f64::from(weather.thunder_level) * rain;
+        drop(weather);
+
+        let daylight = 2.0f64.mul_add(
+            (sky_angle * std::f64::consts::TAU).cos().clamp(-0.25, 0.25),
+            0.5,
+        );
+        let clear_sky = (1.0 - rain * 5.0 / 16.0) * (1.0 - thunder * 5.0 / 16.0);
+        ((1.0 - daylight * clear_sky) * 11.0) as u8
+    }
+
     /// Gets the `BlockState` from the block registry. Returns Air if the block state was not found.
     pub async fn get_block_state(&self, position: &BlockPos) -> &'static BlockState {
         let id = self.get_block_state_id(position).await;
@@ -3101,6 +3253,19 @@ impl World {
         (false, None)
     }
 
+    /// Returns the first block with a collision shape between `start` and `end`, like vanilla's
+    /// raycasts with `ShapeType.COLLIDER`. Air, fluids and plants don't stop the ray.
+    pub async fn raycast_blocks(
+        self: &Arc<Self>,
+        start: Vector3<f64>,
+        end: Vector3<f64>,
+    ) -> Option<BlockPos> {
+        let is_solid = async |pos: &BlockPos, world: &Arc<Self>| {
+            !world.get_block_state(pos).await.collision_shapes.is_empty()
+        };
+        self.raycast(start, end, is_solid).await.map(|(pos, _)| pos)
+    }
+
     pub async fn raycast(
         self: &Arc<Self>,
         start_pos: Vector3<f64>,
@@ -3117,11 +3282,13 @@ impl World {
 
         let mut block = BlockPos::floored(from.x, from.y, from.z);
 
-        let (collision, direction) = self.ray_outline_check(&block, from, to).await;
-        if let Some(dir) = direction
-            && collision
-        {
-            return Some((block, dir));
+        if hit_check(&block, self).await {
+            let (collision, direction) = self.ray_outline_check(&block, from, to).await;
+            if let Some(dir) = direction
+                && collision
+            {
+                return Some((block, dir));
+            }
         }
 
         let difference = to.sub(&from);
@@ -3310,3 +3477,127 @@ impl BlockAccessor for World {
         self.get_block_and_state(position).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::vector3::Vector3;
+
+    use super::World;
+    use crate::entity::{Entity, EntityBase, RemovalReason};
+
+    #[tokio::test]
+    async fn discarded_entities_are_not_saved() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let pos = Vector3::new(0.5, 64.0, 0.5);
+        let new_entity = |entity_type| -> Arc<dyn EntityBase> {
+            Arc::new(Entity::new(
+                uuid::Uuid::new_v4(),
+                world.clone(),
+                pos,
+                entity_type,
+                false,
+            ))
+        };
+
+        let kept = new_entity(&EntityType::TNT);
+        let discarded = new_entity(&EntityType::TNT);
+        discarded
+            .get_entity()
+            .removal_reason
+            .store(Some(RemovalReason::Discarded));
+        // Never saved, no matter why it is removed
+        let bobber = new_entity(&EntityType::FISHING_BOBBER);
+        assert!(!bobber.should_save());
+
+        for entity in [&kept, &discarded, &bobber] {
+            world
+                .save_entity(&entity.get_entity().entity_uuid, entity)
+                .await;
+        }
+
+        let chunk = world
+            .level
+            .get_entity_chunk(kept.get_entity().chunk_pos.load())
+            .await;
+        let chunk = chunk.read().await;
+        assert!(chunk.data.contains_key(&kept.get_entity().entity_uuid));
+        assert!(!chunk.data.contains_key(&discarded.get_entity().entity_uuid));
+        assert!(!chunk.data.contains_key(&bobber.get_entity().entity_uuid));
+    }
+
+    #[tokio::test]
+    async fn entities_move_into_the_chunk_they_walk_into() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+        let zombie: Arc<dyn EntityBase> = Arc::new(Entity::new(
+            uuid::Uuid::new_v4(),
+            world.clone(),
+            Vector3::new(15.5, 300.0, 0.5),
+            &EntityType::ZOMBIE,
+            false,
+        ));
+        let entity = zombie.get_entity();
+        world.save_entity(&entity.entity_uuid, &zombie).await;
+        let old_chunk = world.level.get_entity_chunk(entity.chunk_pos.load()).await;
+
+        entity.set_pos(Vector3::new(16.5, 300.0, 0.5));
+        world.update_entity_chunk(entity).await;
+
+        let new_chunk = world.level.get_entity_chunk(entity.chunk_pos.load()).await;
+        assert!(
+            !old_chunk
+                .read()
+                .await
+                .data
+                .contains_key(&entity.entity_uuid)
+        );
+        assert!(
+            new_chunk
+                .read()
+                .await
+                .data
+                .contains_key(&entity.entity_uuid)
+        );
+
+        // And back again
+        entity.set_pos(Vector3::new(15.5, 300.0, 0.5));
+        world.update_entity_chunk(entity).await;
+        assert!(
+            old_chunk
+                .read()
+                .await
+                .data
+                .contains_key(&entity.entity_uuid)
+        );
+        assert!(
+            !new_chunk
+                .read()
+                .await
+                .data
+                .contains_key(&entity.entity_uuid)
+        );
+    }
+
+    #[tokio::test]
+    async fn nights_and_storms_darken_the_sky() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let world = World::for_test(temp_dir.path().to_path_buf());
+
+        world.level_time.lock().await.set_time(6000);
+        assert_eq!(world.ambient_darkness().await, 0);
+        world.level_time.lock().await.set_time(18000);
+        assert_eq!(world.ambient_darkness().await, 11);
+
+        // Rain darkens a clear noon a bit, a thunderstorm more
+        world.level_time.lock().await.set_time(6000);
+        world.weather.lock().await.rain_level = 1.0;
+        let rainy = world.ambient_darkness().await;
+        world.weather.lock().await.thunder_level = 1.0;
+        let stormy = world.ambient_darkness().await;
+        assert!(0 < rainy && rainy < stormy);
+    }
+}
@@ -1,5 +1,5 @@
-use crate::entity::EntityBase;
 use crate::entity::r#type::from_type;
+use crate::entity::{Entity, EntityBase};
 use crate::world::World;
 use pumpkin_data::biome::Spawner;
 use pumpkin_data::entity::{EntityType, MobCategory, SpawnLocation};
@@ -9,7 +9,7 @@ use pumpkin_data::tag::Taggable;
 use pumpkin_data::tag::WorldgenBiome::MINECRAFT_REDUCE_WATER_AMBIENT_SPAWNS;
 use pumpkin_data::{Block, BlockDirection, BlockState};
 use pumpkin_util::GameMode;
-use pumpkin_util::math::boundingbox::{BoundingBox, EntityDimensions};
+use pumpkin_util::math::boundingbox::BoundingBox;
 use pumpkin_util::math::get_section_cord;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector2::Vector2;
@@ -359,18 +359,17 @@ pub async fn spawn_category_for_position(
     // TODO StructureManager structureManager = level.structureManager();
     // TODO blockState.isRedstoneConductor(chunk, pos) is true then return
     let mut spawn_cluster_size = 0;
-    let mut new_pos = pos;
     for _ in 0..3 {
-        let mut new_x = new_pos.0.x;
-        let mut new_z = new_pos.0.z;
+        let mut new_pos = pos;
         let mut random_group_size = (rng().random::<f32>() * 4.).ceil() as i32;
+        // The spawner entry and its entity type are picked once per group, so a pack is one kind of mob
+        let mut spawner: Option<&'static Spawner> = None;
+        let mut entity_type = &EntityType::PLAYER;
         let mut inc = 0;
         #[allow(unused_variables)]
         let mut group_size = 0;
-        'outer: while inc < random_group_size {
-            new_x += rng().random_range(0..6) - rng().random_range(0..6);
-            new_z += rng().random_range(0..6) - rng().random_range(0..6);
-            new_pos = BlockPos::new(new_x, new_pos.0.y, new_z);
+        while inc < random_group_size {
+            new_pos = next_pack_pos(&new_pos);
             let new_pos_center = new_pos.to_centered_f64();
             let player_distance = get_nearest_player(&new_pos_center, world).await;
             if player_distance == f64::MAX {
@@ -387,13 +386,20 @@ pub async fn spawn_category_for_position(
                 inc += 1;
                 continue;
             }
-            let Some(spawner) = get_random_spawn_mob_at(world, category, &new_pos).await else {
-                // debug!("{new_pos:?} failed, no random spawn mob at category: {category:?}");
-                break 'outer;
-            };
-            random_group_size = rng().random_range(spawner.min_count..=spawner.max_count);
-            let entity_type =
-                &EntityType::from_name(spawner.r#type.strip_prefix("minecraft:").unwrap()).unwrap();
+            if spawner.is_none() {
+                let Some(picked) = get_random_spawn_mob_at(world, category, &new_pos).await else {
+                    // debug!("{new_pos:?} failed, no random spawn mob at category: {category:?}");
+                    break;
+                };
+                let Some(picked_type) =
+                    EntityType::from_name(picked.r#type.strip_prefix("minecraft:").unwrap())
+                else {
+                    break;
+                };
+                spawner = Some(picked);
+                entity_type = picked_type;
+                random_group_size = random_pack_size(picked, entity_type);
+            }
             if !is_valid_spawn_position_for_type(
                 world,
                 &new_pos,
@@ -432,6 +438,27 @@ pub async fn spawn_category_for_position(
     }
 }
 
+/// Rolls how many mobs of a spawner entry try to spawn together. The pack is between the entry's
+/// `min_count` and `max_count`, but never more than the type's `limit_per_chunk`.
+#[must_use]
+pub fn random_pack_size(spawner: &Spawner, entity_type: &EntityType) -> i32 {
+    let max = spawner.max_count.max(spawner.min_count);
+    let size = rng().random_range(spawner.min_count..=max);
+    if entity_type.limit_per_chunk > 0 {
+        size.min(entity_type.limit_per_chunk)
+    } else {
+        size
+    }
+}
+
+/// The next position a pack member tries to spawn at, wandering up to 5 blocks horizontally
+#[must_use]
+pub fn next_pack_pos(pos: &BlockPos) -> BlockPos {
+    let x = pos.0.x + rng().random_range(0..6) - rng().random_range(0..6);
+    let z = pos.0.z + rng().random_range(0..6) - rng().random_range(0..6);
+    BlockPos::new(x, pos.0.y, z)
+}
+
 pub async fn get_nearest_player(pos: &Vector3<f64>, world: &Arc<World>) -> f64 {
     let mut dst = f64::MAX;
     for (_uuid, player) in world.players.read().await.iter() {
@@ -528,10 +555,7 @@ pub async fn is_valid_spawn_position_for_type(
             f64::from(block_pos.0.x) + 0.5,
             f64::from(block_pos.0.y),
             f64::from(block_pos.0.z) + 0.5,
-            &EntityDimensions {
-                width: entity_type.dimension[0],
-                height: entity_type.dimension[1],
-            },
+            &Entity::default_dimensions(entity_type),
         ))
         .await
 }
@@ -577,3 +601,46 @@ pub fn is_valid_empty_spawn_block(state: &'static BlockState) -> bool {
     // TODO !entityType.isBlockDangerous(blockState);
     !Block::from_state_id(state.id).is_tagged_with_by_tag(&MINECRAFT_PREVENT_MOB_SPAWNING_INSIDE)
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_data::biome::Biome;
+    use pumpkin_data::entity::EntityType;
+    use pumpkin_util::math::position::BlockPos;
+
+    use super::{next_pack_pos, random_pack_size};
+
+    #[test]
+    fn wolf_pack_spawns_around_initial_position() {
+        let spawner = Biome::FOREST
+            .spawners
+            .creature
+            .iter()
+            .find(|spawner| spawner.r#type == "minecraft:wolf")
+            .unwrap();
+        let origin = BlockPos::new(100, 64, -100);
+
+        for _ in 0..64 {
+            let size = random_pack_size(spawner, &EntityType::WOLF);
+            assert!((spawner.min_count..=spawner.max_count).contains(&size));
+
+            let mut pos = origin;
+            for member in 1..=size {
+                pos = next_pack_pos(&pos);
+                assert_eq!(pos.0.y, origin.0.y);
+                assert!((pos.0.x - origin.0.x).abs() <= 5 * member);
+                assert!((pos.0.z - origin.0.z).abs() <= 5 * member);
+            }
+        }
+    }
+
+    #[test]
+    fn pack_size_is_capped_by_limit_per_chunk() {
+        let spawner = pumpkin_data::biome::Spawner {
+            r#type: "minecraft:ghast",
+            min_count: 4,
+            max_count: 4,
+        };
+        assert_eq!(random_pack_size(&spawner, &EntityType::GHAST), 1);
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
+use pumpkin_registry::VanillaDimensionType;
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
+use pumpkin_world::generation::settings::GenerationShapeConfig;
 
 use super::World;
 
@@ -26,6 +29,66 @@ impl PortalManager {
         }
     }
 
+    /// How many blocks in other dimensions one block of this dimension stands for
+    fn coordinate_scale(dimension: VanillaDimensionType) -> f64 {
+        match dimension {
+            VanillaDimensionType::TheNether => 8.0,
+            _ => 1.0,
+        }
+    }
+
+    /// The ratio x and z get multiplied by when travelling from `source` to `target`
+    #[must_use]
+    pub fn scale_factor(source: VanillaDimensionType, target: VanillaDimensionType) -> f64 {
+        Self::coordinate_scale(source) / Self::coordinate_scale(target)
+    }
+
+    /// Where an entity at `pos` arrives in the target dimension, kept within its build limits
+    #[must_use]
+    pub fn destination(
+        pos: Vector3<f64>,
+        source: VanillaDimensionType,
+        target: VanillaDimensionType,
+        target_shape: &GenerationShapeConfig,
+    ) -> BlockPos {
+        let scale_factor = Self::scale_factor(source, target);
+        let min_y = f64::from(target_shape.min_y);
+        let max_y = f64::from(target_shape.max_y()) - 1.0;
+        BlockPos::floored(
+            pos.x * scale_factor,
+            pos.y.clamp(min_y, max_y),
+            pos.z * scale_factor,
+        )
+    }
+
+    /// How far around the scaled destination an existing portal gets linked to, like vanilla
+    #[must_use]
+    pub fn search_radius(target: VanillaDimensionType) -> i32 {
+        if target == VanillaDimensionType::TheNether {
+            16
+        } else {
+            128
+        }
+    }
+
+    /// The portal closest to `destination` that is at most `radius` blocks away on both
+    /// horizontal axes
+    #[must_use]
+    pub fn closest_portal<'a>(
+        portals: impl IntoIterator<Item = &'a BlockPos>,
+        destination: BlockPos,
+        radius: i32,
+    ) -> Option<BlockPos> {
+        portals
+            .into_iter()
+            .filter(|pos| {
+                (pos.0.x - destination.0.x).abs() <= radius
+                    && (pos.0.z - destination.0.z).abs() <= radius
+            })
+            .min_by_key(|pos| pos.squared_distance(destination))
+            .copied()
+    }
+
     pub fn tick(&mut self) -> bool {
         if self.in_portal {
             self.in_portal = false;
@@ -41,3 +104,75 @@ impl PortalManager {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use pumpkin_registry::VanillaDimensionType;
+    use pumpkin_util::math::position::BlockPos;
+    use pumpkin_util::math::vector3::Vector3;
+    use pumpkin_world::{GENERATION_SETTINGS, GeneratorSetting};
+
+    use super::PortalManager;
+
+    #[test]
+    fn nether_travel_scales_by_eight() {
+        let to_nether = PortalManager::scale_factor(
+            VanillaDimensionType::Overworld,
+            VanillaDimensionType::TheNether,
+        );
+        assert!((to_nether - 0.125).abs() < f64::EPSILON);
+        let to_overworld = PortalManager::scale_factor(
+            VanillaDimensionType::TheNether,
+            VanillaDimensionType::Overworld,
+        );
+        assert!((to_overworld - 8.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn closest_cached_portal_within_radius_is_linked() {
+        let destination = BlockPos::new(100, 64, 100);
+        let portals = [
+            BlockPos::new(90, 70, 100),
+            BlockPos::new(105, 64, 98),
+            BlockPos::new(300, 64, 100),
+        ];
+        let radius = PortalManager::search_radius(VanillaDimensionType::TheNether);
+        assert_eq!(
+            PortalManager::closest_portal(&portals, destination, radius),
+            Some(BlockPos::new(105, 64, 98))
+        );
+        // Too far away in the nether, but close enough in the overworld
+        let far = [BlockPos::new(200, 64, 100)];
+        assert_eq!(
+            PortalManager::closest_portal(&far, destination, radius),
+            None
+        );
+        let radius = PortalManager::search_radius(VanillaDimensionType::Overworld);
+        assert_eq!(
+            PortalManager::closest_portal(&far, destination, radius),
+            Some(far[0])
+        );
+    }
+
+    #[test]
+    fn destination_stays_within_build_limits() {
+        let nether = &GENERATION_SETTINGS[&GeneratorSetting::Nether].shape;
+        let overworld = &GENERATION_SETTINGS[&GeneratorSetting::Overworld].shape;
+
+        let pos = PortalManager::destination(
+            Vector3::new(80.0, 300.0, -16.0),
+            VanillaDimensionType::Overworld,
+            VanillaDimensionType::TheNether,
+            nether,
+        );
+        assert_eq!(pos.0, Vector3::new(10, 127, -2));
+
+        let pos = PortalManager::destination(
+            Vector3::new(10.0, -100.0, -2.0),
+            VanillaDimensionType::TheNether,
+            VanillaDimensionType::Overworld,
+            overworld,
+        );
+        assert_eq!(pos.0, Vector3::new(80, -64, -16));
+    }
+}
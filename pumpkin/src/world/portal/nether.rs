@@ -7,8 +7,10 @@ use pumpkin_data::{
     tag::Taggable,
 };
 use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::world::BlockFlags;
 
+use super::PortalManager;
 use crate::world::World;
 
 pub struct NetherPortal {
@@ -30,6 +32,9 @@ impl NetherPortal {
 
     const FRAME_BLOCK: Block = Block::OBSIDIAN;
 
+    /// Scanning the terrain is expensive, so existing portals further away are only found when cached
+    const SCAN_RADIUS: i32 = 16;
+
     #[must_use]
     pub fn is_valid(&self) -> bool {
         self.width >= Self::MIN_WIDTH
@@ -63,6 +68,180 @@ impl NetherPortal {
                 )
                 .await;
         }
+        world.portal_cache.lock().await.insert(self.lower_conor);
+    }
+
+    /// Finds the portal an entity travelling to `destination` arrives at, building a new one if
+    /// there is none nearby. Returns the lowest portal block.
+    pub async fn find_or_create(
+        world: &Arc<World>,
+        destination: BlockPos,
+        radius: i32,
+    ) -> BlockPos {
+        if let Some(portal) = Self::find_portal(world, destination, radius).await {
+            return portal;
+        }
+        Self::create_near(world, destination).await
+    }
+
+    /// Finds the closest lit portal around `destination`, checking the cached portals first
+    pub async fn find_portal(
+        world: &World,
+        destination: BlockPos,
+        radius: i32,
+    ) -> Option<BlockPos> {
+        // Looking at the blocks awaits, so the cache isn't kept locked meanwhile
+        let mut cached: Vec<BlockPos> = world.portal_cache.lock().await.iter().copied().collect();
+        while let Some(portal) = PortalManager::closest_portal(&cached, destination, radius) {
+            if world.get_block(&portal).await == &Block::NETHER_PORTAL {
+                return Some(portal);
+            }
+            // The portal was broken since it was cached
+            cached.retain(|pos| *pos != portal);
+            world.portal_cache.lock().await.remove(&portal);
+        }
+
+        let (min_y, max_y) = Self::search_heights(world);
+        let radius = radius.min(Self::SCAN_RADIUS);
+        let mut closest: Option<BlockPos> = None;
+        for x in -radius..=radius {
+            for z in -radius..=radius {
+                let mut y = max_y - 1;
+                while y > min_y {
+                    let pos = BlockPos::new(destination.0.x + x, y, destination.0.z + z);
+                    if world.get_block(&pos).await != &Block::NETHER_PORTAL {
+                        y -= 1;
+                        continue;
+                    }
+                    let mut bottom = pos;
+                    while world.get_block(&bottom.down()).await == &Block::NETHER_PORTAL {
+                        bottom = bottom.down();
+                    }
+                    if closest.is_none_or(|closest| {
+                        bottom.squared_distance(destination) < closest.squared_distance(destination)
+                    }) {
+                        closest = Some(bottom);
+                    }
+                    y = bottom.0.y - 1;
+                }
+            }
+        }
+        if let Some(portal) = closest {
+            world.portal_cache.lock().await.insert(portal);
+        }
+        closest
+    }
+
+    /// The block heights portals are searched and built in, from the bottom of the world up to the
+    /// logical height of the dimension, exclusive. Like vanilla, this keeps new nether portals
+    /// below the bedrock roof.
+    fn search_heights(world: &World) -> (i32, i32) {
+        let shape = &world.generation_settings().shape;
+        let max_y = i32::from(shape.max_y()).min(world.dimension_type.logical_top_y());
+        (i32::from(shape.min_y), max_y)
+    }
+
+    /// Builds a lit portal at a safe spot around `destination`, or on a new obsidian platform if
+    /// there is none. Returns the lowest portal block.
+    pub async fn create_near(world: &Arc<World>, destination: BlockPos) -> BlockPos {
+        let (min_y, max_y) = Self::search_heights(world);
+        let base = if let Some(base) = Self::find_safe_base(world, destination, min_y, max_y).await
+        {
+            base
+        } else {
+            // Vanilla keeps these portals away from the bottom and the top of the world
+            let y = destination.0.y.clamp(min_y.max(70), max_y - 10);
+            let base = BlockPos::new(destination.0.x, y, destination.0.z);
+            let obsidian = Self::FRAME_BLOCK.default_state.id;
+            let air = Block::AIR.default_state.id;
+            for (x, y, z) in Self::footprint() {
+                let pos = base.offset(Vector3::new(x, y, z));
+                let state = if y < 0 { obsidian } else { air };
+                world
+                    .set_block_state(&pos, state, BlockFlags::NOTIFY_ALL)
+                    .await;
+            }
+            base
+        };
+
+        // Frame around two blocks of portal, extending west of the base
+        let obsidian = Self::FRAME_BLOCK.default_state.id;
+        for x in -2..=1 {
+            for y in -1..=3 {
+                if (-1..=0).contains(&x) && (0..=2).contains(&y) {
+                    continue;
+                }
+                let pos = base.offset(Vector3::new(x, y, 0));
+                world
+                    .set_block_state(&pos, obsidian, BlockFlags::NOTIFY_ALL)
+                    .await;
+            }
+        }
+        let portal = Self {
+            axis: HorizontalAxis::X,
+            found_portal_blocks: 0,
+            negative_direction: BlockDirection::West,
+            lower_conor: base,
+            width: 2,
+            height: 3,
+        };
+        portal.create(world).await;
+        base
+    }
+
+    /// Offsets of the blocks a new portal and the room in front and behind it take up, relative to
+    /// its lowest portal block. Blocks below 0 are the floor.
+    fn footprint() -> impl Iterator<Item = (i32, i32, i32)> {
+        (-2..=1).flat_map(|x| (-1..=3).flat_map(move |y| (-1..=1).map(move |z| (x, y, z))))
+    }
+
+    /// Looks for a spot on solid ground around `destination` with enough room for a new portal
+    async fn find_safe_base(
+        world: &World,
+        destination: BlockPos,
+        min_y: i32,
+        max_y: i32,
+    ) -> Option<BlockPos> {
+        let mut closest: Option<BlockPos> = None;
+        for x in -Self::SCAN_RADIUS..=Self::SCAN_RADIUS {
+            for z in -Self::SCAN_RADIUS..=Self::SCAN_RADIUS {
+                for y in (min_y + 1..max_y - 4).rev() {
+                    let base = BlockPos::new(destination.0.x + x, y, destination.0.z + z);
+                    if closest.is_some_and(|closest| {
+                        closest.squared_distance(destination) <= base.squared_distance(destination)
+                    }) {
+                        continue;
+                    }
+                    // Cheap check before looking at the whole footprint
+                    if !world.get_block_state(&base).await.is_air()
+                        || !world.get_block_state(&base.down()).await.is_solid()
+                    {
+                        continue;
+                    }
+                    if Self::is_safe_base(world, base).await {
+                        closest = Some(base);
+                    }
+                }
+            }
+        }
+        closest
+    }
+
+    async fn is_safe_base(world: &World, base: BlockPos) -> bool {
+        for (x, y, z) in Self::footprint() {
+            let state = world
+                .get_block_state(&base.offset(Vector3::new(x, y, z)))
+                .await;
+            let fits = if y < 0 {
+                state.is_solid()
+            } else {
+                state.is_air()
+            };
+            if !fits {
+                return false;
+            }
+        }
+        true
     }
 
     pub async fn get_new_portal(
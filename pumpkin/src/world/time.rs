@@ -58,6 +58,15 @@ impl LevelTime {
         self.time_of_day = time;
     }
 
+    /// How far the sun moved across the sky, from 0 at noon to 1 a day later, like vanilla's
+    /// `DimensionType.getSkyAngle`.
+    #[must_use]
+    pub fn sky_angle(&self) -> f32 {
+        let day_fraction = (self.time_of_day as f64 / 24000.0 - 0.25).rem_euclid(1.0);
+        let eased = 0.5 - (day_fraction * std::f64::consts::PI).cos() / 2.0;
+        (day_fraction.mul_add(2.0, eased) / 3.0) as f32
+    }
+
     #[must_use]
     pub const fn query_daytime(&self) -> i64 {
         self.time_of_day % 24000